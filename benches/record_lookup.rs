@@ -0,0 +1,60 @@
+//! Compares looking up fields by name in a [Record] with a linear scan
+//! of the `(name, value)` pairs, for a record with 100 fields.
+//!
+//! Run with `cargo bench --bench record_lookup`.
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use dbase::{FieldValue, Record};
+
+const NUM_FIELDS: usize = 100;
+const NUM_ITERATIONS: usize = 10_000;
+
+fn time<F: FnMut() -> usize>(mut f: F) -> Duration {
+    let start = Instant::now();
+    for _ in 0..NUM_ITERATIONS {
+        black_box(f());
+    }
+    start.elapsed()
+}
+
+fn main() {
+    let fields = (0..NUM_FIELDS)
+        .map(|i| (format!("FIELD_{}", i), FieldValue::Integer(i as i32)))
+        .collect::<Vec<_>>();
+    let mut record = Record::default();
+    for (name, value) in &fields {
+        record.insert(name.clone(), value.clone());
+    }
+    let names = fields
+        .iter()
+        .map(|(name, _)| name.as_str())
+        .collect::<Vec<_>>();
+
+    let linear = time(|| {
+        names
+            .iter()
+            .filter(|name| fields.iter().any(|(n, _)| n.as_str() == **name))
+            .count()
+    });
+    let indexed = time(|| {
+        names
+            .iter()
+            .filter(|name| record.get(black_box(name)).is_some())
+            .count()
+    });
+
+    let lookups = (NUM_ITERATIONS * NUM_FIELDS) as f64;
+    println!(
+        "linear scan:  {:>8.1} ns / lookup",
+        linear.as_nanos() as f64 / lookups
+    );
+    println!(
+        "Record::get:  {:>8.1} ns / lookup",
+        indexed.as_nanos() as f64 / lookups
+    );
+    println!(
+        "speedup:      {:>8.1}x",
+        linear.as_secs_f64() / indexed.as_secs_f64()
+    );
+}
@@ -0,0 +1,243 @@
+//! Derive macros for the `ReadableRecord` & `WritableRecord` traits of the
+//! [dbase](https://docs.rs/dbase) crate.
+//!
+//! Enable the "derive" feature of dbase to use them,
+//! they are documented there.
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Fields, Ident, LitStr, Type};
+
+/// Maximum length of the name of a column, in bytes
+const FIELD_NAME_MAX_LEN: usize = 10;
+
+/// A field of the struct, with its `#[dbase(...)]` options
+struct RecordField {
+    ident: Ident,
+    ty: Type,
+    /// Name of the column, the name of the field unless renamed
+    name: String,
+    skip: bool,
+}
+
+/// The struct to implement the traits for, with its `#[dbase(...)]` options
+struct RecordStruct {
+    by_name: bool,
+    fields: Vec<RecordField>,
+}
+
+impl RecordStruct {
+    fn parse(input: &DeriveInput) -> syn::Result<Self> {
+        let mut by_name = false;
+        for attr in input
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("dbase"))
+        {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("by_name") {
+                    by_name = true;
+                    Ok(())
+                } else {
+                    Err(meta.error("unknown dbase attribute, expected `by_name`"))
+                }
+            })?;
+        }
+
+        let named_fields = match &input.data {
+            Data::Struct(data) => match &data.fields {
+                Fields::Named(named_fields) => named_fields,
+                _ => {
+                    return Err(syn::Error::new_spanned(
+                        &input.ident,
+                        "dbase records must be structs with named fields",
+                    ))
+                }
+            },
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &input.ident,
+                    "dbase records must be structs with named fields",
+                ))
+            }
+        };
+
+        let mut fields = Vec::with_capacity(named_fields.named.len());
+        for field in &named_fields.named {
+            let ident = field.ident.clone().expect("named fields have a name");
+            let mut rename = None;
+            let mut skip = false;
+            for attr in field
+                .attrs
+                .iter()
+                .filter(|attr| attr.path().is_ident("dbase"))
+            {
+                attr.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("rename") {
+                        let name: LitStr = meta.value()?.parse()?;
+                        if name.value().len() > FIELD_NAME_MAX_LEN {
+                            return Err(syn::Error::new_spanned(
+                                &name,
+                                format!(
+                                    "the column name is {} bytes long, \
+                                     dBase names have at most {} bytes",
+                                    name.value().len(),
+                                    FIELD_NAME_MAX_LEN
+                                ),
+                            ));
+                        }
+                        rename = Some(name.value());
+                        Ok(())
+                    } else if meta.path.is_ident("skip") {
+                        skip = true;
+                        Ok(())
+                    } else {
+                        Err(meta.error("unknown dbase attribute, expected `rename` or `skip`"))
+                    }
+                })?;
+            }
+            if rename.is_some() && !by_name {
+                return Err(syn::Error::new_spanned(
+                    field,
+                    "`rename` requires `#[dbase(by_name)]` on the struct, \
+                     otherwise fields are read & written in order",
+                ));
+            }
+            fields.push(RecordField {
+                name: rename.unwrap_or_else(|| ident.to_string()),
+                ident,
+                ty: field.ty.clone(),
+                skip,
+            });
+        }
+        Ok(Self { by_name, fields })
+    }
+
+    fn columns(&self) -> impl Iterator<Item = &RecordField> {
+        self.fields.iter().filter(|field| !field.skip)
+    }
+}
+
+/// Returns the generics of the struct, with `bound` added for the type of each column
+/// when the struct is generic
+fn generics_with_bound(
+    input: &DeriveInput,
+    record: &RecordStruct,
+    bound: TokenStream2,
+) -> syn::Generics {
+    let mut generics = input.generics.clone();
+    if !generics.params.is_empty() {
+        let where_clause = generics.make_where_clause();
+        for field in record.columns() {
+            let ty = &field.ty;
+            where_clause.predicates.push(syn::parse_quote!(#ty: #bound));
+        }
+    }
+    generics
+}
+
+/// Implements `dbase::ReadableRecord`
+///
+/// The fields are read in the order in which they are declared,
+/// or by the name of their column with `#[dbase(by_name)]`.
+/// The attributes are the same as for `WritableRecord`.
+#[proc_macro_derive(ReadableRecord, attributes(dbase))]
+pub fn derive_readable_record(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let record = match RecordStruct::parse(&input) {
+        Ok(record) => record,
+        Err(error) => return error.into_compile_error().into(),
+    };
+
+    let fields = record.fields.iter().map(|field| {
+        let RecordField {
+            ident, ty, name, ..
+        } = field;
+        if field.skip {
+            quote! { #ident: ::std::default::Default::default() }
+        } else if record.by_name {
+            quote! { #ident: field_iterator.read_field_by_name::<#ty>(#name)?.value }
+        } else {
+            quote! { #ident: field_iterator.read_next_field_as::<#ty>()?.value }
+        }
+    });
+
+    let generics = generics_with_bound(
+        &input,
+        &record,
+        quote!(::std::convert::TryFrom<::dbase::FieldValue, Error = ::dbase::FieldConversionError>),
+    );
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let name = &input.ident;
+    quote! {
+        impl #impl_generics ::dbase::ReadableRecord for #name #ty_generics #where_clause {
+            fn read_using<__T>(
+                field_iterator: &mut ::dbase::FieldIterator<__T>,
+            ) -> ::std::result::Result<Self, ::dbase::FieldIOError>
+            where
+                __T: ::std::io::Read + ::std::io::Seek,
+            {
+                ::std::result::Result::Ok(Self {
+                    #(#fields),*
+                })
+            }
+        }
+    }
+    .into()
+}
+
+/// Implements `dbase::WritableRecord`
+///
+/// The fields are written in the order in which they are declared,
+/// or by the name of their column with `#[dbase(by_name)]`.
+#[proc_macro_derive(WritableRecord, attributes(dbase))]
+pub fn derive_writable_record(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let record = match RecordStruct::parse(&input) {
+        Ok(record) => record,
+        Err(error) => return error.into_compile_error().into(),
+    };
+
+    let idents = record.columns().map(|field| &field.ident);
+    let body = if record.by_name {
+        let names = record.columns().map(|field| &field.name);
+        quote! {
+            while let ::std::option::Option::Some(name) = field_writer.next_field_name() {
+                match name {
+                    #(#names => field_writer.write_next_field_value(&self.#idents)?,)*
+                    _ => {
+                        return ::std::result::Result::Err(::dbase::FieldIOError::new(
+                            ::dbase::ErrorKind::Message(::std::format!(
+                                "Could not find field named '{}' in the record",
+                                name
+                            )),
+                            ::std::option::Option::None,
+                        ))
+                    }
+                }
+            }
+        }
+    } else {
+        quote! {
+            #(field_writer.write_next_field_value(&self.#idents)?;)*
+        }
+    };
+
+    let generics = generics_with_bound(&input, &record, quote!(::dbase::WritableAsDbaseField));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let name = &input.ident;
+    quote! {
+        impl #impl_generics ::dbase::WritableRecord for #name #ty_generics #where_clause {
+            fn write_using<'__a, __W: ::std::io::Write>(
+                &self,
+                field_writer: &mut ::dbase::FieldWriter<'__a, __W>,
+            ) -> ::std::result::Result<(), ::dbase::FieldIOError> {
+                #body
+                ::std::result::Result::Ok(())
+            }
+        }
+    }
+    .into()
+}
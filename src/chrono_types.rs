@@ -0,0 +1,247 @@
+//! Conversions between the date & time types of dBase and the naive types of [chrono]
+//!
+//! dBase files have no concept of timezones, so only the `Naive*` types
+//! of chrono are supported.
+//!
+//! The year of a dBase date is stored using 4 digits, chrono dates outside of
+//! the `0..=9999` range cannot be converted.
+//! dBase times have a precision of one millisecond, sub-millisecond values of chrono times
+//! are truncated.
+use std::convert::TryFrom;
+use std::io::Write;
+
+use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime, Timelike};
+use encoding_rs::Encoding;
+
+use crate::error::ErrorKind;
+use crate::record::field::{Date, DateTime, FieldType, FieldValue, Time};
+use crate::record::{FieldConversionError, FieldInfo};
+use crate::writing::{WritableAsDbaseField, WritingOptions};
+
+impl TryFrom<Date> for NaiveDate {
+    type Error = FieldConversionError;
+
+    fn try_from(d: Date) -> Result<Self, Self::Error> {
+        let year = i32::try_from(d.year()).map_err(|_| FieldConversionError::InvalidDateTime)?;
+        NaiveDate::from_ymd_opt(year, d.month(), d.day())
+            .ok_or(FieldConversionError::InvalidDateTime)
+    }
+}
+
+impl TryFrom<NaiveDate> for Date {
+    type Error = FieldConversionError;
+
+    fn try_from(d: NaiveDate) -> Result<Self, Self::Error> {
+        if !(0..=9999).contains(&d.year()) {
+            return Err(FieldConversionError::InvalidDateTime);
+        }
+        Ok(Date::new(d.day(), d.month(), d.year() as u32))
+    }
+}
+
+impl TryFrom<Time> for NaiveTime {
+    type Error = FieldConversionError;
+
+    fn try_from(t: Time) -> Result<Self, Self::Error> {
+        NaiveTime::from_hms_milli_opt(t.hours(), t.minutes(), t.seconds(), t.milliseconds())
+            .ok_or(FieldConversionError::InvalidDateTime)
+    }
+}
+
+/// Sub-millisecond values of the `NaiveTime` are truncated,
+/// a leap second is kept as the last millisecond of the second.
+impl From<NaiveTime> for Time {
+    fn from(t: NaiveTime) -> Self {
+        let milliseconds = (t.nanosecond() / 1_000_000).min(999);
+        Time::new_with_millis(t.hour(), t.minute(), t.second(), milliseconds)
+    }
+}
+
+impl TryFrom<DateTime> for NaiveDateTime {
+    type Error = FieldConversionError;
+
+    fn try_from(dt: DateTime) -> Result<Self, Self::Error> {
+        Ok(NaiveDateTime::new(
+            NaiveDate::try_from(dt.date())?,
+            NaiveTime::try_from(dt.time())?,
+        ))
+    }
+}
+
+impl TryFrom<NaiveDateTime> for DateTime {
+    type Error = FieldConversionError;
+
+    fn try_from(dt: NaiveDateTime) -> Result<Self, Self::Error> {
+        Ok(DateTime::new(
+            Date::try_from(dt.date())?,
+            Time::from(dt.time()),
+        ))
+    }
+}
+
+impl TryFrom<FieldValue> for Option<NaiveDate> {
+    type Error = FieldConversionError;
+
+    fn try_from(value: FieldValue) -> Result<Self, Self::Error> {
+        Option::<Date>::try_from(value)?
+            .map(NaiveDate::try_from)
+            .transpose()
+    }
+}
+
+impl TryFrom<FieldValue> for NaiveDate {
+    type Error = FieldConversionError;
+
+    fn try_from(value: FieldValue) -> Result<Self, Self::Error> {
+        NaiveDate::try_from(Date::try_from(value)?)
+    }
+}
+
+impl TryFrom<FieldValue> for NaiveDateTime {
+    type Error = FieldConversionError;
+
+    fn try_from(value: FieldValue) -> Result<Self, Self::Error> {
+        NaiveDateTime::try_from(DateTime::try_from(value)?)
+    }
+}
+
+impl WritableAsDbaseField for NaiveDate {
+    fn write_as<W: Write>(
+        &self,
+        field_info: &FieldInfo,
+        dst: &mut W,
+        encoding: &'static Encoding,
+        options: WritingOptions,
+    ) -> Result<(), ErrorKind> {
+        if field_info.field_type == FieldType::Date {
+            Date::try_from(*self)?.write_as(field_info, dst, encoding, options)
+        } else {
+            Err(ErrorKind::IncompatibleType)
+        }
+    }
+}
+
+impl WritableAsDbaseField for Option<NaiveDate> {
+    fn write_as<W: Write>(
+        &self,
+        field_info: &FieldInfo,
+        dst: &mut W,
+        encoding: &'static Encoding,
+        options: WritingOptions,
+    ) -> Result<(), ErrorKind> {
+        self.map(Date::try_from)
+            .transpose()?
+            .write_as(field_info, dst, encoding, options)
+    }
+
+    fn is_null(&self) -> bool {
+        self.is_none()
+    }
+}
+
+impl WritableAsDbaseField for NaiveDateTime {
+    fn write_as<W: Write>(
+        &self,
+        field_info: &FieldInfo,
+        dst: &mut W,
+        encoding: &'static Encoding,
+        options: WritingOptions,
+    ) -> Result<(), ErrorKind> {
+        if field_info.field_type == FieldType::DateTime {
+            DateTime::try_from(*self)?.write_as(field_info, dst, encoding, options)
+        } else {
+            Err(ErrorKind::IncompatibleType)
+        }
+    }
+}
+
+/// Returns the text chrono's serde implementation expects for Date & DateTime values,
+/// `None` for values of other types.
+#[cfg(feature = "serde")]
+pub(crate) fn iso_text(value: &FieldValue) -> Result<Option<String>, FieldConversionError> {
+    match value {
+        FieldValue::Date(Some(date)) => Ok(Some(NaiveDate::try_from(*date)?.to_string())),
+        // chrono's Debug format is the one its FromStr expects
+        FieldValue::DateTime(date_time) => {
+            Ok(Some(format!("{:?}", NaiveDateTime::try_from(*date_time)?)))
+        }
+        _ => Ok(None),
+    }
+}
+
+/// Writes the value serialized by chrono's serde implementation to a Date or DateTime field.
+///
+/// Returns `None` if the next field is not one of those or if the text is not a
+/// chrono date.
+#[cfg(feature = "serde")]
+pub(crate) fn write_iso_text<W: Write>(
+    field_writer: &mut crate::FieldWriter<'_, W>,
+    text: &str,
+) -> Option<Result<(), crate::FieldIOError>> {
+    match field_writer.fields_info.peek()?.field_type {
+        FieldType::Date => {
+            let date = text.parse::<NaiveDate>().ok()?;
+            Some(field_writer.write_next_field_value(&date))
+        }
+        FieldType::DateTime => {
+            let date_time = text.parse::<NaiveDateTime>().ok()?;
+            Some(field_writer.write_next_field_value(&date_time))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn date_round_trip() {
+        let date = Date::new(29, 2, 2020);
+        let naive = NaiveDate::try_from(date).unwrap();
+        assert_eq!(naive, NaiveDate::from_ymd_opt(2020, 2, 29).unwrap());
+        assert_eq!(Date::try_from(naive).unwrap(), date);
+
+        let not_a_day = Date {
+            year: 2020,
+            month: 2,
+            day: 31,
+        };
+        assert!(NaiveDate::try_from(not_a_day).is_err());
+        assert!(Date::try_from(NaiveDate::from_ymd_opt(10000, 1, 1).unwrap()).is_err());
+        assert!(Date::try_from(NaiveDate::from_ymd_opt(-1, 1, 1).unwrap()).is_err());
+    }
+
+    #[test]
+    fn time_round_trip() {
+        let time = Time::new(23, 59, 30);
+        let naive = NaiveTime::try_from(time).unwrap();
+        assert_eq!(naive, NaiveTime::from_hms_opt(23, 59, 30).unwrap());
+        assert_eq!(Time::from(naive), time);
+
+        let with_millis = NaiveTime::from_hms_milli_opt(8, 15, 1, 999).unwrap();
+        assert_eq!(
+            Time::from(with_millis),
+            Time::new_with_millis(8, 15, 1, 999)
+        );
+        assert_eq!(
+            NaiveTime::try_from(Time::new_with_millis(8, 15, 1, 999)).unwrap(),
+            with_millis
+        );
+        assert!(NaiveTime::try_from(Time::from_word(24 * 3_600_000)).is_err());
+    }
+
+    #[test]
+    fn date_time_round_trip() {
+        let date_time = DateTime::new(Date::new(1, 12, 1999), Time::new(12, 30, 5));
+        let naive = NaiveDateTime::try_from(date_time).unwrap();
+        assert_eq!(
+            naive,
+            NaiveDate::from_ymd_opt(1999, 12, 1)
+                .unwrap()
+                .and_hms_opt(12, 30, 5)
+                .unwrap()
+        );
+        assert_eq!(DateTime::try_from(naive).unwrap(), date_time);
+    }
+}
@@ -0,0 +1,222 @@
+//! Copying a table to a new file, optionally selecting, renaming
+//! and filtering its fields and records.
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::path::Path;
+
+use crate::reading::{TableInfo, WithDeletionFlag};
+use crate::{
+    Error, ErrorKind, FieldInfo, FieldName, FieldType, Reader, Record, TableWriterBuilder,
+};
+
+/// Options of [copy]
+///
+/// With the default options, the file is copied as is.
+///
+/// # Example
+///
+/// ```
+/// use dbase::CopyOptions;
+/// use std::collections::HashMap;
+///
+/// let options = CopyOptions::new()
+///     .field_order(vec!["line".to_string(), "name".to_string()])
+///     .rename_fields(HashMap::from([("line".to_string(), "LINE".to_string())]))
+///     .predicate(|record| record.get("line").is_some());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct CopyOptions {
+    field_order: Option<Vec<String>>,
+    renames: HashMap<String, String>,
+    encoding_label: Option<String>,
+    predicate: Option<fn(&Record) -> bool>,
+}
+
+impl CopyOptions {
+    /// Creates the default options
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Selects the fields to copy, in the order in which they are written
+    pub fn field_order(mut self, names: Vec<String>) -> Self {
+        self.field_order = Some(names);
+        self
+    }
+
+    /// Renames fields, the keys are the names of the fields in the source file
+    pub fn rename_fields(mut self, renames: HashMap<String, String>) -> Self {
+        self.renames = renames;
+        self
+    }
+
+    /// Sets the encoding of the copy, the source file is decoded
+    /// with its own encoding (see [Reader::from_path])
+    pub fn encoding(mut self, label: &str) -> Self {
+        self.encoding_label = Some(label.to_string());
+        self
+    }
+
+    /// Copies only the records for which the predicate returns true,
+    /// the record has the fields of the source file, with their original names
+    pub fn predicate(mut self, predicate: fn(&Record) -> bool) -> Self {
+        self.predicate = Some(predicate);
+        self
+    }
+
+    fn is_trivial(&self) -> bool {
+        self.field_order.is_none()
+            && self.renames.is_empty()
+            && self.encoding_label.is_none()
+            && self.predicate.is_none()
+    }
+}
+
+/// Copies the table at `src` to `dest`, returns the number of records written
+///
+/// With the default [CopyOptions], the file (and its memo & `.cpg` files, if any)
+/// is copied byte for byte.
+///
+/// Otherwise, the records are read and written again one at a time,
+/// the records marked as deleted are then not copied.
+/// As the writer cannot write Memo values, tables with Memo fields
+/// can only be copied as is or without their Memo fields,
+/// selecting a Memo field returns an error before `dest` is created.
+///
+/// # Example
+///
+/// ```
+/// # fn main() -> Result<(), dbase::Error> {
+/// use dbase::CopyOptions;
+///
+/// let num_records = dbase::copy(
+///     "tests/data/stations.dbf",
+///     "blue_stations.dbf",
+///     CopyOptions::new()
+///         .field_order(vec!["name".to_string()])
+///         .predicate(|record| {
+///             record.get("line") == Some(&dbase::FieldValue::Character(Some("blue".to_string())))
+///         }),
+/// )?;
+/// assert_eq!(num_records, 4);
+/// # std::fs::remove_file("blue_stations.dbf").unwrap();
+/// # Ok(())
+/// # }
+/// ```
+pub fn copy<S: AsRef<Path>, D: AsRef<Path>>(
+    src: S,
+    dest: D,
+    options: CopyOptions,
+) -> Result<u64, Error> {
+    let (src, dest) = (src.as_ref(), dest.as_ref());
+    if options.is_trivial() {
+        return copy_files(src, dest);
+    }
+
+    let mut reader = Reader::from_path(src)?;
+    let fields = output_fields(reader.fields(), &options)?;
+    let table_info = TableInfo {
+        header: *reader.header(),
+        fields_info: fields.iter().map(|(_, info)| info.clone()).collect(),
+        encoding: reader.encoding(),
+        memo_block_size: reader.memo_block_size(),
+    };
+    let builder = match &options.encoding_label {
+        Some(label) => TableWriterBuilder::from_table_info_with_label(&table_info, label)?,
+        None => TableWriterBuilder::from_table_info(&table_info),
+    };
+    let mut writer = builder.build_with_file_dest(dest)?;
+
+    let mut num_records = 0u64;
+    for result in reader.iter_records_as::<WithDeletionFlag<Record>>() {
+        let WithDeletionFlag(is_deleted, record) = result?;
+        if is_deleted
+            || options
+                .predicate
+                .is_some_and(|predicate| !predicate(&record))
+        {
+            continue;
+        }
+        let mut output = Record::default();
+        for (source_name, info) in &fields {
+            if let Some(value) = source_name.as_deref().and_then(|name| record.get(name)) {
+                output.insert(info.name().to_owned(), value.clone());
+            }
+        }
+        writer.write_record(&output)?;
+        num_records += 1;
+    }
+    writer.finish()?;
+    Ok(num_records)
+}
+
+/// Returns the fields of the copy, along with the name of the field they are copied from
+///
+/// The `_NullFlags` column of the source file, if any, is kept after the other fields.
+/// Memo fields cannot be selected, as their values cannot be written.
+fn output_fields(
+    source_fields: &[FieldInfo],
+    options: &CopyOptions,
+) -> Result<Vec<(Option<String>, FieldInfo)>, Error> {
+    let visible_fields = source_fields.iter().filter(|info| !info.is_hidden());
+    let selected = match &options.field_order {
+        None => visible_fields.collect::<Vec<&FieldInfo>>(),
+        Some(names) => names
+            .iter()
+            .map(|name| {
+                source_fields
+                    .iter()
+                    .find(|info| !info.is_hidden() && info.name() == name)
+                    .ok_or_else(|| copy_error(format!("Could not find field named '{}'", name)))
+            })
+            .collect::<Result<Vec<&FieldInfo>, Error>>()?,
+    };
+
+    let mut fields = Vec::with_capacity(selected.len() + 1);
+    for info in selected {
+        if info.field_type() == FieldType::Memo {
+            return Err(Error {
+                record_num: 0,
+                field: Some(info.clone()),
+                kind: ErrorKind::Message(format!(
+                    "The Memo field '{}' cannot be written, it must not be selected",
+                    info.name()
+                )),
+            });
+        }
+        let mut output_info = info.clone();
+        if let Some(new_name) = options.renames.get(info.name()) {
+            let new_name = FieldName::try_from(new_name.as_str()).map_err(|error| {
+                copy_error(format!("Cannot rename '{}': {}", info.name(), error))
+            })?;
+            output_info.name = new_name.as_str().to_owned();
+        }
+        fields.push((Some(info.name().to_owned()), output_info));
+    }
+    if let Some(null_flags) = source_fields.iter().find(|info| info.is_null_flags()) {
+        fields.push((None, null_flags.clone()));
+    }
+    Ok(fields)
+}
+
+/// Copies the file, and its memo & `.cpg` files, as is
+fn copy_files(src: &Path, dest: &Path) -> Result<u64, Error> {
+    let num_records = Reader::from_path(src)?.header().num_records;
+    std::fs::copy(src, dest).map_err(|error| Error::io_error(error, 0))?;
+    for extension in ["dbt", "fpt", "cpg"] {
+        let sidecar = src.with_extension(extension);
+        if sidecar.exists() {
+            std::fs::copy(&sidecar, dest.with_extension(extension))
+                .map_err(|error| Error::io_error(error, 0))?;
+        }
+    }
+    Ok(u64::from(num_records))
+}
+
+fn copy_error(message: String) -> Error {
+    Error {
+        record_num: 0,
+        field: None,
+        kind: ErrorKind::Message(message),
+    }
+}
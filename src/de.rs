@@ -1,14 +1,64 @@
 use std::fmt::Display;
 use std::io::{Read, Seek};
 
-use serde::de::{DeserializeOwned, DeserializeSeed, IntoDeserializer, SeqAccess, Visitor};
+use serde::de::{
+    DeserializeOwned, DeserializeSeed, IntoDeserializer, MapAccess, SeqAccess, Visitor,
+};
 use serde::Deserializer;
 
+use crate::record::field::FieldValueAccess;
 use crate::{
-    ErrorKind, FieldConversionError, FieldIOError, FieldIterator, FieldValue, ReadableRecord,
+    ErrorKind, FieldIOError, FieldInfo, FieldIterator, FieldValue, FieldValueSeed, ReadableRecord,
+    Record,
 };
 
-impl<'de, 'a, 'f, R: Read + Seek> SeqAccess<'de> for &mut FieldIterator<'a, R> {
+impl<'a, R: Read + Seek> FieldIterator<'a, R> {
+    /// Skips the hidden fields (deletion flag, null flags) that come next,
+    /// and returns the name of the next field, if any
+    fn next_visible_field_name(&mut self) -> Result<Option<&'a str>, FieldIOError> {
+        while let Some(field_info) = self.fields_info.peek().copied() {
+            if !field_info.is_hidden() {
+                return Ok(Some(field_info.name()));
+            }
+            self.skip_field(field_info)?;
+            self.fields_info.next();
+        }
+        Ok(None)
+    }
+}
+
+/// Gives the fields of a record as a map of field name to value,
+/// so that struct fields are matched with the columns by name
+struct FieldMap<'i, 'a, R: Read + Seek>(&'i mut FieldIterator<'a, R>);
+
+impl<'de, 'i, 'a, R: Read + Seek> MapAccess<'de> for FieldMap<'i, 'a, R> {
+    type Error = FieldIOError;
+
+    fn next_key_seed<K>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<<K as DeserializeSeed<'de>>::Value>, Self::Error>
+    where
+        K: DeserializeSeed<'de>,
+    {
+        match self.0.next_visible_field_name()? {
+            Some(name) => seed.deserialize(name.into_deserializer()).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V>(
+        &mut self,
+        seed: V,
+    ) -> Result<<V as DeserializeSeed<'de>>::Value, Self::Error>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        seed.deserialize(&mut *self.0)
+    }
+}
+
+impl<'de, 'a, R: Read + Seek> SeqAccess<'de> for &mut FieldIterator<'a, R> {
     type Error = FieldIOError;
 
     fn next_element_seed<T>(
@@ -27,14 +77,21 @@ impl<'de, 'a, 'f, R: Read + Seek> SeqAccess<'de> for &mut FieldIterator<'a, R> {
 }
 
 //TODO maybe we can deserialize numbers other than f32 & f64 by converting using TryFrom
-impl<'de, 'a, 'f, T: Read + Seek> Deserializer<'de> for &mut FieldIterator<'a, T> {
+impl<'de, 'a, T: Read + Seek> Deserializer<'de> for &mut FieldIterator<'a, T> {
     type Error = FieldIOError;
 
-    fn deserialize_any<V>(self, _visitor: V) -> Result<<V as Visitor<'de>>::Value, Self::Error>
+    // Dates & date times are read from their bytes
+    fn is_human_readable(&self) -> bool {
+        false
+    }
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<<V as Visitor<'de>>::Value, Self::Error>
     where
         V: Visitor<'de>,
     {
-        Err(FieldConversionError::IncompatibleType.into())
+        // The value is given with its variant, which is how FieldValue is deserialized
+        let value = self.read_next_field()?.value;
+        visitor.visit_enum(FieldValueAccess::new(value))
     }
 
     fn deserialize_bool<V>(self, visitor: V) -> Result<<V as Visitor<'de>>::Value, Self::Error>
@@ -49,26 +106,16 @@ impl<'de, 'a, 'f, T: Read + Seek> Deserializer<'de> for &mut FieldIterator<'a, T
     where
         V: Visitor<'de>,
     {
-        let value = self.read_next_field_as::<i32>()?.value;
-        visitor.visit_i8(
-            value
-                .try_into()
-                .ok()
-                .ok_or(FieldConversionError::IncompatibleType)?,
-        )
+        let value = self.read_next_field_as::<i8>()?.value;
+        visitor.visit_i8(value)
     }
 
     fn deserialize_i16<V>(self, visitor: V) -> Result<<V as Visitor<'de>>::Value, Self::Error>
     where
         V: Visitor<'de>,
     {
-        let value = self.read_next_field_as::<i32>()?.value;
-        visitor.visit_i16(
-            value
-                .try_into()
-                .ok()
-                .ok_or(FieldConversionError::IncompatibleType)?,
-        )
+        let value = self.read_next_field_as::<i16>()?.value;
+        visitor.visit_i16(value)
     }
 
     fn deserialize_i32<V>(self, visitor: V) -> Result<<V as Visitor<'de>>::Value, Self::Error>
@@ -83,42 +130,40 @@ impl<'de, 'a, 'f, T: Read + Seek> Deserializer<'de> for &mut FieldIterator<'a, T
     where
         V: Visitor<'de>,
     {
-        let value = self.read_next_field_as::<i32>()?.value;
-        visitor.visit_i64(value.into())
+        let value = self.read_next_field_as::<i64>()?.value;
+        visitor.visit_i64(value)
     }
 
     fn deserialize_u8<V>(self, visitor: V) -> Result<<V as Visitor<'de>>::Value, Self::Error>
     where
         V: Visitor<'de>,
     {
-        let value = self.read_next_field_as::<i32>()?.value;
-        visitor.visit_u8(
-            value
-                .try_into()
-                .ok()
-                .ok_or(FieldConversionError::IncompatibleType)?,
-        )
+        let value = self.read_next_field_as::<u8>()?.value;
+        visitor.visit_u8(value)
     }
 
-    fn deserialize_u16<V>(self, _visitor: V) -> Result<<V as Visitor<'de>>::Value, Self::Error>
+    fn deserialize_u16<V>(self, visitor: V) -> Result<<V as Visitor<'de>>::Value, Self::Error>
     where
         V: Visitor<'de>,
     {
-        unimplemented!("DBase cannot deserialize u16")
+        let value = self.read_next_field_as::<u16>()?.value;
+        visitor.visit_u16(value)
     }
 
-    fn deserialize_u32<V>(self, _visitor: V) -> Result<<V as Visitor<'de>>::Value, Self::Error>
+    fn deserialize_u32<V>(self, visitor: V) -> Result<<V as Visitor<'de>>::Value, Self::Error>
     where
         V: Visitor<'de>,
     {
-        unimplemented!("DBase cannot deserialize u32")
+        let value = self.read_next_field_as::<u32>()?.value;
+        visitor.visit_u32(value)
     }
 
-    fn deserialize_u64<V>(self, _visitor: V) -> Result<<V as Visitor<'de>>::Value, Self::Error>
+    fn deserialize_u64<V>(self, visitor: V) -> Result<<V as Visitor<'de>>::Value, Self::Error>
     where
         V: Visitor<'de>,
     {
-        unimplemented!("DBase cannot deserialize u64")
+        let value = self.read_next_field_as::<u64>()?.value;
+        visitor.visit_u64(value)
     }
 
     fn deserialize_f32<V>(self, visitor: V) -> Result<<V as Visitor<'de>>::Value, Self::Error>
@@ -144,11 +189,17 @@ impl<'de, 'a, 'f, T: Read + Seek> Deserializer<'de> for &mut FieldIterator<'a, T
         unimplemented!("DBase cannot deserialize char")
     }
 
-    fn deserialize_str<V>(self, _visitor: V) -> Result<<V as Visitor<'de>>::Value, Self::Error>
+    fn deserialize_str<V>(self, visitor: V) -> Result<<V as Visitor<'de>>::Value, Self::Error>
     where
         V: Visitor<'de>,
     {
-        unimplemented!("DBase cannot deserialize str")
+        let value = self.read_next_field()?.value;
+        // chrono deserializes its dates from text
+        #[cfg(feature = "chrono")]
+        if let Some(text) = crate::chrono_types::iso_text(&value)? {
+            return visitor.visit_string(text);
+        }
+        visitor.visit_string(String::try_from(value)?)
     }
 
     fn deserialize_string<V>(self, visitor: V) -> Result<<V as Visitor<'de>>::Value, Self::Error>
@@ -270,11 +321,11 @@ impl<'de, 'a, 'f, T: Read + Seek> Deserializer<'de> for &mut FieldIterator<'a, T
         visitor.visit_seq(self)
     }
 
-    fn deserialize_map<V>(self, _visitor: V) -> Result<<V as Visitor<'de>>::Value, Self::Error>
+    fn deserialize_map<V>(self, visitor: V) -> Result<<V as Visitor<'de>>::Value, Self::Error>
     where
         V: Visitor<'de>,
     {
-        unimplemented!("DBase cannot deserialize map")
+        visitor.visit_map(FieldMap(self))
     }
 
     fn deserialize_struct<V>(
@@ -286,7 +337,8 @@ impl<'de, 'a, 'f, T: Read + Seek> Deserializer<'de> for &mut FieldIterator<'a, T
     where
         V: Visitor<'de>,
     {
-        visitor.visit_seq(self)
+        // Fields are matched by name, tuple structs are still read by position
+        visitor.visit_map(FieldMap(self))
     }
 
     fn deserialize_enum<V>(
@@ -298,6 +350,8 @@ impl<'de, 'a, 'f, T: Read + Seek> Deserializer<'de> for &mut FieldIterator<'a, T
     where
         V: Visitor<'de>,
     {
+        // Unit variants are stored as their name, serde gives an error
+        // listing the expected names when no variant matches
         let value = self.read_next_field_as::<String>()?.value;
         visitor.visit_enum(value.into_deserializer())
     }
@@ -314,12 +368,87 @@ impl<'de, 'a, 'f, T: Read + Seek> Deserializer<'de> for &mut FieldIterator<'a, T
 
     fn deserialize_ignored_any<V>(
         self,
-        _visitor: V,
+        visitor: V,
     ) -> Result<<V as Visitor<'de>>::Value, Self::Error>
     where
         V: Visitor<'de>,
     {
-        unimplemented!("DBase cannot deserialize ignored any")
+        // Columns without a matching struct field
+        self.skip_next_field()?;
+        visitor.visit_unit()
+    }
+}
+
+/// Deserializes a [Record] from a map of field name to plain value,
+/// such as the ones the `Serialize` implementation of `&Record` gives (e.g. JSON objects)
+///
+/// The values are deserialized with a [FieldValueSeed], using the type of the field
+/// of the same name, a name that is not one of the fields is an error.
+///
+/// # Example
+///
+/// ```
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use dbase::RecordSeed;
+/// use serde::de::DeserializeSeed;
+///
+/// let mut reader = dbase::Reader::from_path("tests/data/line.dbf")?;
+/// let records = reader.read()?;
+/// let json = serde_json::to_string(&&records[0])?;
+///
+/// let mut deserializer = serde_json::Deserializer::from_str(&json);
+/// let record = RecordSeed::new(reader.fields()).deserialize(&mut deserializer)?;
+/// assert_eq!(record, records[0]);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct RecordSeed<'a> {
+    fields: &'a [FieldInfo],
+}
+
+impl<'a> RecordSeed<'a> {
+    /// Creates a seed deserializing records with the given fields
+    pub fn new(fields: &'a [FieldInfo]) -> Self {
+        Self { fields }
+    }
+}
+
+impl<'de, 'a> DeserializeSeed<'de> for RecordSeed<'a> {
+    type Value = Record;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'de, 'a> Visitor<'de> for RecordSeed<'a> {
+    type Value = Record;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("a map of field name to value")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut record = Record::default();
+        while let Some(name) = map.next_key::<String>()? {
+            let field_info = self
+                .fields
+                .iter()
+                .find(|field_info| !field_info.is_hidden() && field_info.name() == name)
+                .ok_or_else(|| {
+                    serde::de::Error::custom(format!("Could not find field named '{}'", name))
+                })?;
+            let value = map.next_value_seed(FieldValueSeed::new(field_info.field_type()))?;
+            record.insert(name, value);
+        }
+        Ok(record)
     }
 }
 
@@ -0,0 +1,294 @@
+//! Support for reading & writing numeric values as [rust_decimal::Decimal]
+//!
+//! Numeric fields are stored as text in the file, reading them as `f64`
+//! looses precision when they have many digits, `Decimal` values are
+//! parsed directly from the text of the field.
+use std::convert::TryFrom;
+use std::io::{Read, Seek, Write};
+use std::str::FromStr;
+
+use byteorder::{LittleEndian, WriteBytesExt};
+use encoding_rs::Encoding;
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
+use rust_decimal::{Decimal, RoundingStrategy};
+
+use crate::error::{ErrorKind, FieldIOError};
+use crate::reading::{FieldIterator, NamedValue, ReadingOptions};
+use crate::record::field::{
+    numeric_text, trim_field_data, write_numeric_text, FieldType, FieldValue, CURRENCY_SCALE,
+};
+use crate::record::{FieldConversionError, FieldInfo};
+use crate::writing::{WritableAsDbaseField, WritingOptions};
+
+/// Number of decimal places the Currency type can hold
+const CURRENCY_DECIMAL_PLACES: u32 = 4;
+
+impl<'a, T: Read + Seek> FieldIterator<'a, T> {
+    /// Reads the next field as a [Decimal]
+    ///
+    /// For fields stored as text (Numeric & Float), the value is parsed
+    /// from the text of the field, so no precision is lost.
+    /// Binary fields (Integer, Double, Currency) are converted.
+    ///
+    /// An empty Numeric or Float field is returned as `None`.
+    ///
+    /// If the "DeletionFlag" field is present in the file it won't be returned
+    /// and instead go to the next field.
+    pub fn read_next_field_as_decimal(
+        &mut self,
+    ) -> Result<NamedValue<'a, Option<Decimal>>, FieldIOError> {
+        let field_info = self
+            .fields_info
+            .next()
+            .ok_or_else(FieldIOError::end_of_record)?;
+        if field_info.is_hidden() {
+            self.skip_field(field_info)?;
+            return self.read_next_field_as_decimal();
+        }
+        if self.is_null(field_info) {
+            self.skip_field(field_info)?;
+            return Ok(NamedValue {
+                name: field_info.name(),
+                value: None,
+            });
+        }
+
+        let field_bytes = &mut self.field_data_buffer[..field_info.length() as usize];
+        self.source.read_exact(field_bytes).map_err(|error| {
+            FieldIOError::new(ErrorKind::IoError(error), Some(field_info.clone()))
+        })?;
+
+        let value = match field_info.field_type {
+            FieldType::Numeric | FieldType::Float => match parse_decimal(field_bytes, self.options)
+            {
+                Ok(value) => value,
+                Err(kind) if self.recover_from_parse_error(field_info, &kind) => None,
+                Err(kind) => return Err(FieldIOError::new(kind, Some(field_info.clone()))),
+            },
+            FieldType::Currency => {
+                let mut le_bytes = [0u8; std::mem::size_of::<i64>()];
+                le_bytes.copy_from_slice(&field_bytes[..std::mem::size_of::<i64>()]);
+                Some(Decimal::new(
+                    i64::from_le_bytes(le_bytes),
+                    CURRENCY_DECIMAL_PLACES,
+                ))
+            }
+            _ => {
+                let value = FieldValue::read_from(
+                    field_bytes,
+                    self.memo_reader,
+                    field_info,
+                    self.encoding,
+                    self.options,
+                )
+                .map_err(|kind| FieldIOError::new(kind, Some(field_info.clone())))?;
+                Option::<Decimal>::try_from(value)
+                    .map_err(|e| FieldIOError::new(e.into(), Some(field_info.clone())))?
+            }
+        };
+
+        Ok(NamedValue {
+            name: field_info.name(),
+            value,
+        })
+    }
+}
+
+fn parse_decimal(
+    field_bytes: &[u8],
+    options: ReadingOptions,
+) -> Result<Option<Decimal>, ErrorKind> {
+    let value = trim_field_data(field_bytes);
+    if value.is_empty() || value.iter().all(|c| c == &b'*') {
+        return Ok(None);
+    }
+    let value_str = numeric_text(value, options);
+    let value_str = value_str.strip_prefix('+').unwrap_or(&value_str);
+    Decimal::from_str(value_str)
+        .or_else(|_| Decimal::from_scientific(value_str))
+        .map(Some)
+        .map_err(ErrorKind::ParseDecimalError)
+}
+
+fn decimal_from_f64(value: f64) -> Result<Decimal, FieldConversionError> {
+    Decimal::from_f64(value).ok_or(FieldConversionError::IncompatibleType)
+}
+
+/// Converts the value of numeric fields into a [Decimal]
+///
+/// Apart from Currency ones, the numeric values are held as floating point numbers
+/// in the `FieldValue`, thus the conversion may not be exact, use
+/// [read_next_field_as_decimal](struct.FieldIterator.html#method.read_next_field_as_decimal)
+/// to read the exact value stored in the file.
+impl TryFrom<FieldValue> for Option<Decimal> {
+    type Error = FieldConversionError;
+
+    fn try_from(value: FieldValue) -> Result<Self, Self::Error> {
+        match value {
+            FieldValue::Numeric(None) | FieldValue::Float(None) => Ok(None),
+            FieldValue::Numeric(Some(v)) | FieldValue::Double(v) => decimal_from_f64(v).map(Some),
+            FieldValue::Currency(v) => Ok(Some(Decimal::new(v.raw(), CURRENCY_DECIMAL_PLACES))),
+            FieldValue::Float(Some(v)) => decimal_from_f64(f64::from(v)).map(Some),
+            FieldValue::Integer(v) | FieldValue::AutoIncrement(v) => Ok(Some(Decimal::from(v))),
+            _ => Err(FieldConversionError::IncompatibleType),
+        }
+    }
+}
+
+impl TryFrom<FieldValue> for Decimal {
+    type Error = FieldConversionError;
+
+    fn try_from(value: FieldValue) -> Result<Self, Self::Error> {
+        Option::<Decimal>::try_from(value)?.ok_or(FieldConversionError::NoneValue)
+    }
+}
+
+impl WritableAsDbaseField for Decimal {
+    fn write_as<W: Write>(
+        &self,
+        field_info: &FieldInfo,
+        dst: &mut W,
+        _encoding: &'static Encoding,
+        options: WritingOptions,
+    ) -> Result<(), ErrorKind> {
+        match field_info.field_type {
+            FieldType::Numeric | FieldType::Float => {
+                let precision = u32::from(field_info.num_decimal_places);
+                let value =
+                    self.round_dp_with_strategy(precision, RoundingStrategy::MidpointAwayFromZero);
+                let text = format!(
+                    "{value:.precision$}",
+                    value = value,
+                    precision = precision as usize
+                );
+                write_numeric_text(&text, field_info, dst, options)
+            }
+            FieldType::Currency => {
+                let scaled = self
+                    .round_dp_with_strategy(
+                        CURRENCY_DECIMAL_PLACES,
+                        RoundingStrategy::MidpointAwayFromZero,
+                    )
+                    .checked_mul(Decimal::from(CURRENCY_SCALE))
+                    .and_then(|scaled| scaled.to_i64())
+                    .ok_or_else(|| ErrorKind::ValueDoesNotFit {
+                        field: field_info.name().to_owned(),
+                        value: self.to_string(),
+                        max: field_info.field_length as usize,
+                    })?;
+                dst.write_i64::<LittleEndian>(scaled)?;
+                Ok(())
+            }
+            _ => Err(ErrorKind::IncompatibleType),
+        }
+    }
+}
+
+impl WritableAsDbaseField for Option<Decimal> {
+    fn write_as<W: Write>(
+        &self,
+        field_info: &FieldInfo,
+        dst: &mut W,
+        encoding: &'static Encoding,
+        options: WritingOptions,
+    ) -> Result<(), ErrorKind> {
+        match (self, field_info.field_type) {
+            (Some(value), _) => value.write_as(field_info, dst, encoding, options),
+            (None, FieldType::Numeric) | (None, FieldType::Float) => Ok(()),
+            (None, _) => Err(ErrorKind::IncompatibleType),
+        }
+    }
+
+    fn is_null(&self) -> bool {
+        self.is_none()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::record::FieldName;
+    use std::io::Cursor;
+
+    fn numeric_field_info(length: u8, num_decimals: u8) -> FieldInfo {
+        let mut info = FieldInfo::new(
+            FieldName::try_from("amount").unwrap(),
+            FieldType::Numeric,
+            length,
+        )
+        .unwrap();
+        info.num_decimal_places = num_decimals;
+        info
+    }
+
+    #[test]
+    fn parse_value_not_representable_as_f64() {
+        let value = parse_decimal(b"   92233720368547758.07", ReadingOptions::default())
+            .unwrap()
+            .unwrap();
+        assert_eq!(value.to_string(), "92233720368547758.07");
+        assert_ne!(
+            decimal_from_f64(92233720368547758.07).unwrap().to_string(),
+            "92233720368547758.07"
+        );
+    }
+
+    #[test]
+    fn parse_empty_and_signed_values() {
+        assert_eq!(
+            parse_decimal(b"     ", ReadingOptions::default()).unwrap(),
+            None
+        );
+        assert_eq!(
+            parse_decimal(b"*****", ReadingOptions::default()).unwrap(),
+            None
+        );
+        assert_eq!(
+            parse_decimal(b" +12.50", ReadingOptions::default()).unwrap(),
+            Some(Decimal::from_str("12.50").unwrap())
+        );
+        assert!(matches!(
+            parse_decimal(b"12a", ReadingOptions::default()),
+            Err(ErrorKind::ParseDecimalError(_))
+        ));
+    }
+
+    #[test]
+    fn parse_comma_decimal_separator() {
+        let options = ReadingOptions::new().comma_decimal_separator(true);
+        assert_eq!(
+            parse_decimal(b"  -12,50", options).unwrap(),
+            Some(Decimal::from_str("-12.50").unwrap())
+        );
+        assert!(parse_decimal(b"  -12,50", ReadingOptions::default()).is_err());
+    }
+
+    #[test]
+    fn write_respects_precision() {
+        let field_info = numeric_field_info(20, 2);
+        let mut out = Cursor::new(Vec::<u8>::new());
+        Decimal::from_str("92233720368547758.075")
+            .unwrap()
+            .write_as(
+                &field_info,
+                &mut out,
+                encoding_rs::UTF_8,
+                WritingOptions::default(),
+            )
+            .unwrap();
+        assert_eq!(out.into_inner(), b"92233720368547758.08");
+
+        let mut out = Cursor::new(Vec::<u8>::new());
+        Decimal::from(3)
+            .write_as(
+                &field_info,
+                &mut out,
+                encoding_rs::UTF_8,
+                WritingOptions::default(),
+            )
+            .unwrap();
+        // Numeric values are right-aligned
+        assert_eq!(out.into_inner(), b"                3.00");
+    }
+}
@@ -0,0 +1,411 @@
+//! Computing the differences between two versions of a table,
+//! and applying them.
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::Path;
+
+use crate::{Error, FieldInfo, FieldValue, Reader, Record};
+
+/// Differences between two sets of records
+///
+/// Created by [diff], and applied using [apply_patch].
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Diff {
+    /// Records that are only in the new set
+    pub added: Vec<Record>,
+    /// Records that are only in the old set
+    pub removed: Vec<Record>,
+    /// Records that are in both sets but with different values, as (old, new) pairs
+    pub modified: Vec<(Record, Record)>,
+}
+
+impl Diff {
+    /// Returns true if there are no differences
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.modified.is_empty()
+    }
+}
+
+/// Value of the key field of a record, with a total order
+/// so that it can be used in a `BTreeMap`
+#[derive(Debug, Copy, Clone)]
+struct Key<'a>(&'a FieldValue);
+
+impl<'a> Key<'a> {
+    fn of(record: &'a Record, key_field: &str) -> Option<Self> {
+        record.get(key_field).map(Key)
+    }
+}
+
+fn cmp_option_by<T, F>(a: &Option<T>, b: &Option<T>, cmp: F) -> Ordering
+where
+    F: Fn(&T, &T) -> Ordering,
+{
+    match (a, b) {
+        (None, None) => Ordering::Equal,
+        (None, Some(_)) => Ordering::Less,
+        (Some(_), None) => Ordering::Greater,
+        (Some(a), Some(b)) => cmp(a, b),
+    }
+}
+
+impl<'a> Ord for Key<'a> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.0, other.0) {
+            (FieldValue::Character(a), FieldValue::Character(b)) => a.cmp(b),
+            (FieldValue::Numeric(a), FieldValue::Numeric(b)) => cmp_option_by(a, b, f64::total_cmp),
+            (FieldValue::Logical(a), FieldValue::Logical(b)) => a.cmp(b),
+            (FieldValue::Date(a), FieldValue::Date(b)) => a.cmp(b),
+            (FieldValue::Float(a), FieldValue::Float(b)) => cmp_option_by(a, b, f32::total_cmp),
+            (FieldValue::Integer(a), FieldValue::Integer(b))
+            | (FieldValue::AutoIncrement(a), FieldValue::AutoIncrement(b)) => a.cmp(b),
+            (FieldValue::Currency(a), FieldValue::Currency(b)) => a.cmp(b),
+            (FieldValue::Double(a), FieldValue::Double(b)) => a.total_cmp(b),
+            (FieldValue::DateTime(a), FieldValue::DateTime(b)) => a.cmp(b),
+            (FieldValue::Memo(a), FieldValue::Memo(b)) => a.cmp(b),
+            (FieldValue::BinaryMemo(a), FieldValue::BinaryMemo(b)) => a.cmp(b),
+            _ => self.0.variant_rank().cmp(&other.0.variant_rank()),
+        }
+    }
+}
+
+impl<'a> PartialOrd for Key<'a> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'a> PartialEq for Key<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<'a> Eq for Key<'a> {}
+
+fn index_by_key<'a>(records: &'a [Record], key_field: &str) -> BTreeMap<Key<'a>, &'a Record> {
+    records
+        .iter()
+        .filter_map(|record| Key::of(record, key_field).map(|key| (key, record)))
+        .collect()
+}
+
+/// Computes the differences between the `old` and `new` records
+///
+/// Records of both sets are matched using the value of their `key_field`,
+/// which should be unique (if it is not, the last record with a given key is used).
+/// Records that do not have the `key_field` are ignored.
+///
+/// # Example
+///
+/// ```
+/// # fn main() -> Result<(), dbase::Error> {
+/// let old = dbase::read("tests/data/stations.dbf")?;
+/// let mut new = old.clone();
+/// new.remove(0);
+///
+/// let diff = dbase::diff(&old, &new, "name");
+/// assert_eq!(diff.removed, vec![old[0].clone()]);
+/// assert!(diff.added.is_empty());
+/// assert!(diff.modified.is_empty());
+/// # Ok(())
+/// # }
+/// ```
+pub fn diff(old: &[Record], new: &[Record], key_field: &str) -> Diff {
+    let old_by_key = index_by_key(old, key_field);
+    let new_by_key = index_by_key(new, key_field);
+
+    let mut diff = Diff::default();
+    for record in new {
+        if let Some(key) = Key::of(record, key_field) {
+            match old_by_key.get(&key) {
+                None => diff.added.push(record.clone()),
+                Some(old_record) if *old_record != record => {
+                    diff.modified.push(((*old_record).clone(), record.clone()))
+                }
+                Some(_) => {}
+            }
+        }
+    }
+    for record in old {
+        if let Some(key) = Key::of(record, key_field) {
+            if !new_by_key.contains_key(&key) {
+                diff.removed.push(record.clone());
+            }
+        }
+    }
+    diff
+}
+
+/// Applies the `diff` to the `base` records and returns the result
+///
+/// Removed records are dropped, modified records are replaced by their new version
+/// (keeping their position) and added records are appended at the end.
+/// Records are matched using the value of their `key_field`.
+///
+/// # Example
+///
+/// ```
+/// # fn main() -> Result<(), dbase::Error> {
+/// let old = dbase::read("tests/data/stations.dbf")?;
+/// let mut new = old.clone();
+/// new.remove(0);
+///
+/// let diff = dbase::diff(&old, &new, "name");
+/// assert_eq!(dbase::apply_patch(old, &diff, "name"), new);
+/// # Ok(())
+/// # }
+/// ```
+pub fn apply_patch(base: Vec<Record>, diff: &Diff, key_field: &str) -> Vec<Record> {
+    let removed = index_by_key(&diff.removed, key_field);
+    let modified = diff
+        .modified
+        .iter()
+        .filter_map(|(old, new)| Key::of(old, key_field).map(|key| (key, new)))
+        .collect::<BTreeMap<Key, &Record>>();
+
+    let mut records = base
+        .into_iter()
+        .filter_map(|record| match Key::of(&record, key_field) {
+            Some(key) if removed.contains_key(&key) => None,
+            Some(key) => match modified.get(&key) {
+                Some(new) => Some((*new).clone()),
+                None => Some(record),
+            },
+            None => Some(record),
+        })
+        .collect::<Vec<Record>>();
+    records.extend(diff.added.iter().cloned());
+    records
+}
+
+/// Differences between the fields of two tables, matched by name
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct SchemaDiff {
+    /// Fields that are only in the second table
+    pub added: Vec<FieldInfo>,
+    /// Fields that are only in the first table
+    pub removed: Vec<FieldInfo>,
+    /// Fields whose type, length or number of decimal places changed, as (old, new) pairs
+    pub changed: Vec<(FieldInfo, FieldInfo)>,
+}
+
+impl SchemaDiff {
+    /// Returns true if there are no differences
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+
+    fn compute(old: &[FieldInfo], new: &[FieldInfo]) -> Self {
+        let visible = |fields: &[FieldInfo]| -> Vec<FieldInfo> {
+            fields
+                .iter()
+                .filter(|field| !field.is_hidden())
+                .cloned()
+                .collect()
+        };
+        let (old, new) = (visible(old), visible(new));
+        let find = |fields: &[FieldInfo], name: &str| -> Option<FieldInfo> {
+            fields.iter().find(|field| field.name() == name).cloned()
+        };
+
+        let mut diff = SchemaDiff::default();
+        for field in &new {
+            match find(&old, field.name()) {
+                None => diff.added.push(field.clone()),
+                Some(old_field)
+                    if old_field.field_type() != field.field_type()
+                        || old_field.length() != field.length()
+                        || old_field.num_decimal_places() != field.num_decimal_places() =>
+                {
+                    diff.changed.push((old_field, field.clone()))
+                }
+                Some(_) => {}
+            }
+        }
+        for field in &old {
+            if find(&new, field.name()).is_none() {
+                diff.removed.push(field.clone());
+            }
+        }
+        diff
+    }
+}
+
+/// Value of a field that differs between two records
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldDiff {
+    /// Name of the field
+    pub field_name: String,
+    /// Value in the record of the first table
+    pub old: FieldValue,
+    /// Value in the record of the second table
+    pub new: FieldValue,
+}
+
+/// Differences between the fields and the records of two tables
+///
+/// As DBF files have no primary key, records are matched by position:
+/// the n-th record of the first table is compared to the n-th record of the second one.
+/// To match records using the value of a field, see [diff].
+///
+/// # Example
+///
+/// ```
+/// # fn main() -> Result<(), dbase::Error> {
+/// let diff = dbase::TableDiff::compute("tests/data/stations.dbf", "tests/data/stations.dbf")?;
+/// assert!(diff.is_empty());
+/// assert_eq!(diff.to_string(), "No differences\n");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct TableDiff {
+    /// Differences between the fields of the tables
+    pub schema_diff: SchemaDiff,
+    /// Number of records of the second table minus the one of the first table
+    pub record_count_diff: i64,
+    /// Position of the records that are in both tables but with different values,
+    /// and their values that differ
+    ///
+    /// Only the fields that are in both tables are compared.
+    pub changed_records: Vec<(usize, Vec<FieldDiff>)>,
+    /// Records of the second table after the last record of the first one, with their position
+    pub added_records: Vec<(usize, Record)>,
+    /// Records of the first table after the last record of the second one, with their position
+    pub removed_records: Vec<(usize, Record)>,
+}
+
+impl TableDiff {
+    /// Reads the tables at `path_a` & `path_b` and computes their differences,
+    /// `path_a` being the old version and `path_b` the new one
+    pub fn compute<P: AsRef<Path>, Q: AsRef<Path>>(path_a: P, path_b: Q) -> Result<Self, Error> {
+        let mut reader_a = Reader::from_path(path_a)?;
+        let records_a = reader_a.read()?;
+        let mut reader_b = Reader::from_path(path_b)?;
+        let records_b = reader_b.read()?;
+        Ok(Self::from_records(
+            reader_a.fields(),
+            records_a,
+            reader_b.fields(),
+            records_b,
+        ))
+    }
+
+    fn from_records(
+        fields_a: &[FieldInfo],
+        mut records_a: Vec<Record>,
+        fields_b: &[FieldInfo],
+        mut records_b: Vec<Record>,
+    ) -> Self {
+        let record_count_diff = records_b.len() as i64 - records_a.len() as i64;
+        let num_common = records_a.len().min(records_b.len());
+        let removed_records = records_a
+            .drain(num_common..)
+            .enumerate()
+            .map(|(i, record)| (num_common + i, record))
+            .collect();
+        let added_records = records_b
+            .drain(num_common..)
+            .enumerate()
+            .map(|(i, record)| (num_common + i, record))
+            .collect();
+
+        let changed_records = records_a
+            .into_iter()
+            .zip(records_b)
+            .enumerate()
+            .filter_map(|(i, (old, new))| {
+                let field_diffs = old
+                    .into_iter()
+                    .filter_map(|(field_name, old_value)| match new.get(&field_name) {
+                        Some(new_value) if *new_value != old_value => Some(FieldDiff {
+                            field_name,
+                            old: old_value,
+                            new: new_value.clone(),
+                        }),
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>();
+                (!field_diffs.is_empty()).then_some((i, field_diffs))
+            })
+            .collect();
+
+        Self {
+            schema_diff: SchemaDiff::compute(fields_a, fields_b),
+            record_count_diff,
+            changed_records,
+            added_records,
+            removed_records,
+        }
+    }
+
+    /// Returns true if there are no differences
+    pub fn is_empty(&self) -> bool {
+        self.schema_diff.is_empty()
+            && self.record_count_diff == 0
+            && self.changed_records.is_empty()
+            && self.added_records.is_empty()
+            && self.removed_records.is_empty()
+    }
+}
+
+fn write_field(f: &mut fmt::Formatter, field: &FieldInfo) -> fmt::Result {
+    write!(
+        f,
+        "{} ({:?}, length {}",
+        field.name(),
+        field.field_type(),
+        field.length()
+    )?;
+    if field.num_decimal_places() != 0 {
+        write!(f, ", {} decimal places", field.num_decimal_places())?;
+    }
+    write!(f, ")")
+}
+
+/// Report of the differences, one line per difference
+impl fmt::Display for TableDiff {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_empty() {
+            return writeln!(f, "No differences");
+        }
+        for field in &self.schema_diff.added {
+            write!(f, "Field added: ")?;
+            write_field(f, field)?;
+            writeln!(f)?;
+        }
+        for field in &self.schema_diff.removed {
+            write!(f, "Field removed: ")?;
+            write_field(f, field)?;
+            writeln!(f)?;
+        }
+        for (old, new) in &self.schema_diff.changed {
+            write!(f, "Field changed: ")?;
+            write_field(f, old)?;
+            write!(f, " -> ")?;
+            write_field(f, new)?;
+            writeln!(f)?;
+        }
+        if self.record_count_diff != 0 {
+            writeln!(f, "Record count: {:+}", self.record_count_diff)?;
+        }
+        for (index, field_diffs) in &self.changed_records {
+            for field_diff in field_diffs {
+                writeln!(
+                    f,
+                    "Record {} changed: {}: {} -> {}",
+                    index, field_diff.field_name, field_diff.old, field_diff.new
+                )?;
+            }
+        }
+        for (index, _) in &self.added_records {
+            writeln!(f, "Record {} added", index)?;
+        }
+        for (index, _) in &self.removed_records {
+            writeln!(f, "Record {} removed", index)?;
+        }
+        Ok(())
+    }
+}
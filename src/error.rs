@@ -1,4 +1,4 @@
-use crate::{FieldConversionError, FieldInfo};
+use crate::{FieldConversionError, FieldInfo, FieldInfoError, InvalidDateError};
 
 #[derive(Debug)]
 pub enum ErrorKind {
@@ -8,6 +8,11 @@ pub enum ErrorKind {
     ParseFloatError(std::num::ParseFloatError),
     /// Wrapper to forward errors whe trying to parse an integer value from the file
     ParseIntError(std::num::ParseIntError),
+    /// Wrapper to forward errors whe trying to parse a decimal value from the file
+    #[cfg(feature = "decimal")]
+    ParseDecimalError(rust_decimal::Error),
+    /// The value of a Date field is not a valid date
+    InvalidDate(InvalidDateError),
     /// The Field as an invalid FieldType
     InvalidFieldType(char),
     /// Happens when at least one field is a Memo type
@@ -31,10 +36,39 @@ pub enum ErrorKind {
     InvalidEncoding,
     /// Cannot encode a character field value by specified encoding
     CannotEncodeFieldValue,
-    /// The field does not have enough length of field for writing string
-    NotEnoughFieldLength,
+    /// The value is longer than the field it is written to
+    ValueTooLong {
+        /// Name of the field
+        field: String,
+        /// Number of bytes of the value
+        len: usize,
+        /// Length of the field
+        max: usize,
+    },
+    /// The text of the Numeric or Float value needs more characters than the field has
+    ValueDoesNotFit {
+        /// Name of the field
+        field: String,
+        /// Text of the value
+        value: String,
+        /// Length of the field
+        max: usize,
+    },
     /// Cannot decode a bytes to string
     CannotDecode,
+    /// The file has more records than the number stored in its header
+    RecordCountMismatch {
+        /// Number of records stored in the header
+        declared: u32,
+        /// Number of records in the file
+        actual: u32,
+    },
+    /// Two fields given to the writer have the same name (ignoring the ASCII case)
+    DuplicateFieldName(String),
+    /// A field descriptor of the file is not valid (e.g. its length is 0)
+    InvalidFieldInfo(FieldInfoError),
+    /// The header of the file is truncated or not valid
+    InvalidHeader,
 }
 
 /// The error type for this crate
@@ -121,6 +155,18 @@ impl From<std::num::ParseIntError> for ErrorKind {
     }
 }
 
+impl From<InvalidDateError> for ErrorKind {
+    fn from(e: InvalidDateError) -> Self {
+        ErrorKind::InvalidDate(e)
+    }
+}
+
+impl From<FieldInfoError> for ErrorKind {
+    fn from(e: FieldInfoError) -> Self {
+        ErrorKind::InvalidFieldInfo(e)
+    }
+}
+
 impl From<FieldConversionError> for ErrorKind {
     fn from(e: FieldConversionError) -> Self {
         ErrorKind::BadConversion(e)
@@ -171,6 +217,9 @@ impl std::error::Error for FieldIOError {
             ErrorKind::IoError(_) => "An I/O error happened",
             ErrorKind::ParseFloatError(_) => "Float value could not be obtained",
             ErrorKind::ParseIntError(_) => "Float value could not be obtained",
+            #[cfg(feature = "decimal")]
+            ErrorKind::ParseDecimalError(_) => "Decimal value could not be obtained",
+            ErrorKind::InvalidDate(_) => "Date value could not be obtained",
             ErrorKind::InvalidFieldType(_) => "The FieldType code is note a valid one",
             ErrorKind::MissingMemoFile => "The memo file could not be found",
             ErrorKind::ErrorOpeningMemoFile(_) => {
@@ -188,10 +237,15 @@ impl std::error::Error for FieldIOError {
             ErrorKind::CannotEncodeFieldValue => {
                 "Cannot encode a character field value by specified encoding"
             }
-            ErrorKind::NotEnoughFieldLength => {
-                "The field does not have enough length of field for writing string"
-            }
+            ErrorKind::ValueTooLong { .. } => "The value is longer than the field",
+            ErrorKind::ValueDoesNotFit { .. } => "The number does not fit in the field",
             ErrorKind::CannotDecode => "The byte sequence was not decode to string by encoding",
+            ErrorKind::RecordCountMismatch { .. } => {
+                "The number of records differs from the one of the header"
+            }
+            ErrorKind::DuplicateFieldName(_) => "Two fields have the same name",
+            ErrorKind::InvalidFieldInfo(_) => "A field descriptor is not valid",
+            ErrorKind::InvalidHeader => "The header is truncated or not valid",
         }
     }
 }
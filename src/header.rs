@@ -1,22 +1,31 @@
 use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
+use encoding_rs::Encoding;
 
 use std::io::{Read, Write};
 
 use crate::record::field::{Date, MemoFileType};
 
 /// Known version of dBase files
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum Version {
+    /// `0x02`
     FoxBase,
+    /// `0x03`, or `0x83` with memo
     DBase3 { supports_memo: bool },
+    /// `0x30`, supports Integer, Currency, DateTime, Double & null fields
     VisualFoxPro,
+    /// `0x43` / `0x63`, or `0x8b` / `0xcb` with memo
     DBase4 { supports_memo: bool },
+    /// `0xfb`, or `0xf5` with memo
     FoxPro2 { supports_memo: bool },
+    /// Any other version byte
     Unknown(u8),
 }
 
 impl Version {
-    pub(crate) fn supported_memo_type(self) -> Option<MemoFileType> {
+    /// Returns the type of the memo file that goes with files of this version,
+    /// `None` if the version does not support Memo fields
+    pub fn supported_memo_type(self) -> Option<MemoFileType> {
         match self {
             Version::FoxBase => Some(MemoFileType::FoxBaseMemo),
             Version::DBase3 {
@@ -87,7 +96,7 @@ impl From<u8> for Version {
             },
             // Each version has different feature (varchar / autoincrement)
             // but we don't support that for now
-            0x30 | 0x31 | 0x32 => Version::VisualFoxPro,
+            0x30..=0x32 => Version::VisualFoxPro,
             // Same here these different version num means that some features are different
             0x8b | 0xcb => Version::DBase4 {
                 supports_memo: true,
@@ -123,6 +132,52 @@ impl TableFlags {
     }
 }
 
+/// Code page marks (language driver IDs) of the encodings supported by encoding_rs,
+/// the first mark of an encoding is the one written by the writer
+fn code_page_marks() -> [(u8, &'static Encoding); 23] {
+    [
+        (0x03, encoding_rs::WINDOWS_1252),
+        (0x04, encoding_rs::MACINTOSH),
+        (0x13, encoding_rs::SHIFT_JIS),
+        (0x4d, encoding_rs::GBK),
+        (0x4e, encoding_rs::EUC_KR),
+        (0x4f, encoding_rs::BIG5),
+        (0x57, encoding_rs::WINDOWS_1252),
+        (0x58, encoding_rs::WINDOWS_1252),
+        (0x59, encoding_rs::WINDOWS_1252),
+        (0x65, encoding_rs::IBM866),
+        (0x78, encoding_rs::BIG5),
+        (0x79, encoding_rs::EUC_KR),
+        (0x7a, encoding_rs::GBK),
+        (0x7b, encoding_rs::SHIFT_JIS),
+        (0x7c, encoding_rs::WINDOWS_874),
+        (0x7d, encoding_rs::WINDOWS_1255),
+        (0x7e, encoding_rs::WINDOWS_1256),
+        (0x96, encoding_rs::X_MAC_CYRILLIC),
+        (0xc8, encoding_rs::WINDOWS_1250),
+        (0xc9, encoding_rs::WINDOWS_1251),
+        (0xca, encoding_rs::WINDOWS_1254),
+        (0xcb, encoding_rs::WINDOWS_1253),
+        (0xcc, encoding_rs::WINDOWS_1257),
+    ]
+}
+
+/// Returns the encoding of the code page mark, `None` if it is 0 or not known
+pub(crate) fn encoding_for_code_page_mark(mark: u8) -> Option<&'static Encoding> {
+    code_page_marks()
+        .into_iter()
+        .find(|&(known_mark, _)| known_mark == mark)
+        .map(|(_, encoding)| encoding)
+}
+
+/// Returns the code page mark of the encoding, 0 if it has none (e.g. UTF-8)
+pub(crate) fn code_page_mark_for_encoding(encoding: &'static Encoding) -> u8 {
+    code_page_marks()
+        .into_iter()
+        .find(|&(_, known_encoding)| known_encoding == encoding)
+        .map_or(0, |(mark, _)| mark)
+}
+
 /// Definition of the header struct stored at the beginning
 /// of each dBase file
 #[derive(Debug, Copy, Clone)]
@@ -189,7 +244,7 @@ impl Header {
 
         let _reserved = source.read_u16::<LittleEndian>()?;
 
-        let is_transaction_incomplete = (source.read_u8()? != 0) as bool;
+        let is_transaction_incomplete = source.read_u8()? != 0;
         let encryption_flag = source.read_u8()?;
 
         let mut _reserved = [0u8; 12];
@@ -246,7 +301,7 @@ impl Header {
 #[cfg(test)]
 mod test {
     use std::fs::File;
-    use std::io::{Cursor, Seek, SeekFrom};
+    use std::io::{Cursor, Seek};
 
     use super::*;
 
@@ -254,7 +309,7 @@ mod test {
     fn pos_after_reading_header() {
         let mut file = File::open("tests/data/line.dbf").unwrap();
         let _hdr = Header::read_from(&mut file).unwrap();
-        let pos_after_reading = file.seek(SeekFrom::Current(0)).unwrap();
+        let pos_after_reading = file.stream_position().unwrap();
         assert_eq!(pos_after_reading, Header::SIZE as u64);
     }
 
@@ -265,7 +320,7 @@ mod test {
 
         let mut out = Cursor::new(Vec::<u8>::with_capacity(Header::SIZE));
         hdr.write_to(&mut out).unwrap();
-        let pos_after_writing = out.seek(SeekFrom::Current(0)).unwrap();
+        let pos_after_writing = out.stream_position().unwrap();
         assert_eq!(pos_after_writing, Header::SIZE as u64);
     }
 
@@ -10,6 +10,9 @@
 //! Once you have access to the records, you will have to `match` against the real
 //! [FieldValue](enum.FieldValue.html)
 //!
+//! Iterating over a [Record](struct.Record.html) gives its fields in the order
+//! they are declared in the file.
+//!
 //! ## Examples
 //!
 //! ```
@@ -104,7 +107,12 @@
 //! ```
 //!
 //! If you use the `serde` optional feature and serde_derive crate you can have the
-//! [ReadableRecord](trait.ReadableRecord.html) implemented for you
+//! [ReadableRecord](trait.ReadableRecord.html) implemented for you.
+//! The fields of structs are matched with the columns by name (`#[serde(rename = "...")]`
+//! gives the name of the column), columns without a matching field are ignored,
+//! while tuple structs are read by position.
+//! Empty values of Character, Numeric, Float, Logical and Date fields are read as
+//! `None` into `Option` fields, and `None` is written as an empty value.
 //!
 //! ```
 //! # #[cfg(feature = "serde")]
@@ -119,7 +127,9 @@
 //! #[derive(Deserialize)]
 //! struct StationRecord {
 //!     name: String,
+//!     #[serde(rename = "marker-col")]
 //!     marker_col: String,
+//!     #[serde(rename = "marker-sym")]
 //!     marker_sym: String,
 //!     line: String,
 //! }
@@ -140,6 +150,47 @@
 //! # }
 //! ```
 //!
+//! With the `derive` optional feature, [ReadableRecord](trait.ReadableRecord.html)
+//! and [WritableRecord](trait.WritableRecord.html) can be derived.
+//! The fields are read & written in the order in which they are declared,
+//! `#[dbase(by_name)]` matches them with the columns by name instead,
+//! `#[dbase(rename = "...")]` gives the name of the column of a field
+//! (names longer than 10 bytes fail to compile),
+//! and `#[dbase(skip)]` skips a field, which is then set to its default value when reading.
+//!
+//! ```
+//! # #[cfg(feature = "derive")]
+//! # fn main() -> Result<(), dbase::Error> {
+//! #[derive(dbase::ReadableRecord, dbase::WritableRecord)]
+//! #[dbase(by_name)]
+//! struct Station {
+//!     #[dbase(rename = "line")]
+//!     line_color: String,
+//!     name: String,
+//!     #[dbase(skip)]
+//!     visited: bool,
+//! }
+//!
+//! let mut reader = dbase::Reader::from_path("tests/data/stations.dbf")?;
+//! let stations = reader.read_as::<Station>()?;
+//! assert_eq!(stations[0].line_color, "blue");
+//! # Ok(())
+//! # }
+//! # #[cfg(not(feature = "derive"))]
+//! # fn main() {}
+//! ```
+//!
+//! ```compile_fail
+//! #[derive(dbase::ReadableRecord)]
+//! #[dbase(by_name)]
+//! struct Station {
+//!     // The name is longer than 10 bytes
+//!     #[dbase(rename = "marker_color")]
+//!     color: String,
+//! }
+//! # fn main() {}
+//! ```
+//!
 //!
 //! # Writing
 //!
@@ -163,6 +214,7 @@
 //!
 //! stations[0].get_mut("line").and_then(|_old| Some("Red".to_string()));
 //! writer.write_records(&stations)?;
+//! # std::fs::remove_file("stations.dbf").unwrap();
 //! # Ok(())
 //! # }
 //! ```
@@ -186,7 +238,7 @@
 //! }
 //!
 //! let mut writer = TableWriterBuilder::new()
-//!     .add_character_field(FieldName::try_from("Nick Name").unwrap(), 50)
+//!     .add_character_field(FieldName::try_from("Nick_Name").unwrap(), 50)
 //!     .add_numeric_field(FieldName::try_from("Age").unwrap(), 20, 10)
 //!     .build_with_dest(Cursor::new(Vec::<u8>::new()));
 //!
@@ -223,7 +275,7 @@
 //! }
 //!
 //! let writer = TableWriterBuilder::new()
-//!     .add_character_field(FieldName::try_from("Nick Name").unwrap(), 50)
+//!     .add_character_field(FieldName::try_from("Nick_Name").unwrap(), 50)
 //!     .add_numeric_field(FieldName::try_from("Age").unwrap(), 20, 10)
 //!     .build_with_dest(Cursor::new(Vec::<u8>::new()));
 //!
@@ -243,31 +295,78 @@
 #![deny(unstable_features)]
 
 extern crate byteorder;
+#[cfg(feature = "chrono")]
+extern crate chrono;
+#[cfg(feature = "rayon")]
+extern crate rayon;
+#[cfg(feature = "decimal")]
+extern crate rust_decimal;
 #[cfg(feature = "serde")]
 extern crate serde;
 extern crate time;
 
+#[cfg(feature = "chrono")]
+mod chrono_types;
 #[cfg(feature = "serde")]
 mod de;
+#[cfg(feature = "decimal")]
+mod decimal;
 #[cfg(feature = "serde")]
 mod ser;
 
+#[cfg(feature = "derive")]
+pub use dbase_derive::{ReadableRecord, WritableRecord};
+
+#[cfg(feature = "serde")]
+pub use crate::de::RecordSeed;
+#[cfg(feature = "serde")]
+pub use crate::record::field::FieldValueSeed;
+
+mod copy;
+mod diff;
 mod error;
 mod header;
+mod locale;
+mod progress;
 mod reading;
 mod record;
+mod sort;
+mod stats;
+mod table;
+#[cfg(feature = "test-util")]
+pub mod testing;
 mod writing;
 
 use encoding_rs::Encoding;
 
+pub use crate::copy::{copy, CopyOptions};
+pub use crate::diff::{apply_patch, diff, Diff, FieldDiff, SchemaDiff, TableDiff};
 pub use crate::error::{Error, ErrorKind, FieldIOError};
+pub use crate::header::Version;
+pub use crate::locale::{DateFormat, Locale};
 pub use crate::reading::{
-    read, read_with_label, FieldIterator, NamedValue, ReadableRecord, Reader, Record,
-    RecordIterator, TableInfo,
+    read, read_with_info, read_with_label, read_with_label_and_info, ChunkIterator, FieldIterator,
+    FilteredRecordIterator, NamedValue, RawRecordIterator, RawRecordView, ReadWarning,
+    ReadWarningKind, ReadableRecord, Reader, ReadingOptions, Record, RecordIterator, RecordRange,
+    RecordView, TableInfo,
+};
+pub use crate::record::field::{
+    CurrencyValue, Date, DateTime, FieldType, FieldValue, InvalidDateError, InvalidDateTimeError,
+    InvalidTimeError, MemoFileType, MemoReader, Time,
+};
+pub use crate::record::{
+    FieldConversionError, FieldFlags, FieldInfo, FieldInfoError, FieldName, InvalidFieldNameError,
+};
+pub use crate::sort::{
+    sort_records_by, sort_records_by_descending, sort_records_by_multiple, SortError, SortOrder,
+};
+pub use crate::stats::{column_histogram, column_stats, ColumnStats};
+pub use crate::table::Table;
+pub use crate::writing::{
+    write_records, EmptyDateStyle, FieldWriter, LogicalStyle, NumericAlignment,
+    NumericOverflowPolicy, RoundingMode, TableWriter, TableWriterBuilder, TruncationPolicy,
+    WritableAsDbaseField, WritableRecord, WritingOptions,
 };
-pub use crate::record::field::{Date, DateTime, FieldType, FieldValue, Time};
-pub use crate::record::{FieldConversionError, FieldInfo, FieldName};
-pub use crate::writing::{FieldWriter, TableWriter, TableWriterBuilder, WritableRecord};
 
 pub(crate) fn invalid_data_error(message: String) -> std::io::Error {
     std::io::Error::new(std::io::ErrorKind::InvalidData, message)
@@ -292,6 +391,14 @@ pub(crate) fn encoded_bytes(value: &str, encoding: &'static Encoding) -> std::io
 
 /// macro to define a struct that implements the ReadableRecord and WritableRecord
 ///
+/// The fields are read and written in order, the derive macros of the `derive` feature
+/// can also skip fields, or match all of them with the columns by name.
+///
+/// A field followed by `=> "NAME"` is read from the column `NAME`,
+/// wherever it is in the record, and reading continues with the column after it.
+/// When writing, the fields are still written in order, but an error is returned
+/// if the column the field is written to is not named `NAME`.
+///
 /// # Examples
 ///
 /// ```
@@ -307,19 +414,98 @@ pub(crate) fn encoded_bytes(value: &str, encoding: &'static Encoding) -> std::io
 /// );
 /// # }
 /// ```
+///
+/// The struct and its fields can be public, and have attributes (e.g. doc comments)
+///
+/// ```
+/// # #[macro_use] extern crate dbase;
+/// mod records {
+///     dbase_record! {
+///         /// A user of the application
+///         #[derive(Debug, Clone)]
+///         pub struct UserRecord {
+///             /// First name of the user
+///             pub first_name: String,
+///             #[allow(dead_code)]
+///             pub(crate) last_name: String,
+///             age: f64,
+///         }
+///     }
+/// }
+/// # fn main() {
+/// # let mut reader = dbase::Reader::from_path("tests/data/stations.dbf").unwrap();
+/// # let _ = reader.iter_records_as::<records::UserRecord>();
+/// # }
+/// ```
+///
+/// Giving the names of the columns
+///
+/// ```
+/// # #[macro_use] extern crate dbase;
+/// dbase_record! {
+///     #[derive(Debug)]
+///     struct StationRecord {
+///         line_name: Option<String> => "LINE",
+///         station_name: String => "NAME",
+///         // Read from the column after NAME
+///         marker_color: Option<String>,
+///     }
+/// }
+///
+/// # fn main() -> Result<(), dbase::Error> {
+/// let mut reader = dbase::Reader::from_path("tests/data/stations_optional.dbf")?;
+/// let stations = reader.read_as::<StationRecord>()?;
+/// assert_eq!(stations[3].station_name, "Judiciary Sq");
+/// assert_eq!(stations[3].line_name.as_deref(), Some("blue"));
+/// assert_eq!(stations[3].marker_color, None);
+/// # Ok(())
+/// # }
+/// ```
 #[macro_export]
 macro_rules! dbase_record {
+    (@read $field_iterator:ident, $field_type:ty) => {
+        $field_iterator.read_next_field_as::<$field_type>()?.value
+    };
+    (@read $field_iterator:ident, $field_type:ty, $dbase_name:literal) => {
+        $field_iterator.read_field_by_name::<$field_type>($dbase_name)?.value
+    };
+    (@write $field_writer:ident, $value:expr) => {
+        $field_writer.write_next_field_value($value)?;
+    };
+    (@write $field_writer:ident, $value:expr, $dbase_name:literal) => {
+        match $field_writer.next_field_name() {
+            Some(name) if name == $dbase_name => {
+                $field_writer.write_next_field_value($value)?;
+            }
+            Some(name) => {
+                return Err(dbase::FieldIOError::new(
+                    dbase::ErrorKind::Message(format!(
+                        "Expected to write the field '{}', but the next field is '{}'",
+                        $dbase_name, name
+                    )),
+                    None,
+                ));
+            }
+            None => return Err(dbase::FieldIOError::new(dbase::ErrorKind::TooManyFields, None)),
+        }
+    };
     (
-        $(#[derive($($derives:meta),*)])?
-        struct $name:ident {
-            $( $field_name:ident: $field_type:ty),+
+        $(#[$struct_meta:meta])*
+        $vis:vis struct $name:ident {
+            $(
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field_name:ident: $field_type:ty $(=> $dbase_name:literal)?
+            ),+
             $(,)?
         }
     ) => {
 
-        $(#[derive($($derives),*)])?
-        struct $name {
-            $($field_name: $field_type),+
+        $(#[$struct_meta])*
+        $vis struct $name {
+            $(
+                $(#[$field_meta])*
+                $field_vis $field_name: $field_type
+            ),+
         }
 
         impl dbase::ReadableRecord for $name {
@@ -328,9 +514,9 @@ macro_rules! dbase_record {
                 {
                     Ok(Self {
                         $(
-                            $field_name: field_iterator
-                                .read_next_field_as::<$field_type>()?
-                                .value
+                            $field_name: $crate::dbase_record!(
+                                @read field_iterator, $field_type $(, $dbase_name)?
+                            )
                         ),+
                     })
             }
@@ -339,10 +525,163 @@ macro_rules! dbase_record {
        impl dbase::WritableRecord for $name {
            fn write_using<'a, W: std::io::Write>(&self, field_writer: &mut dbase::FieldWriter<'a, W>) -> Result<(), dbase::FieldIOError> {
                 $(
-                    field_writer.write_next_field_value(&self.$field_name)?;
+                    $crate::dbase_record!(@write field_writer, &self.$field_name $(, $dbase_name)?);
                 )+
                 Ok(())
            }
         }
     };
 }
+
+/// macro to declare the fields of a [TableWriterBuilder](struct.TableWriterBuilder.html)
+///
+/// Each field is written as `NAME: Type` where `Type` is one of
+/// `Character(length)`, `Numeric(length, decimals)`, `Float(length, decimals)`,
+/// `Date`, `Logical`, `Integer`, `AutoIncrement`, `Double`, `Currency` or `DateTime`.
+///
+/// Field names and lengths are checked when the macro is expanded:
+/// names longer than 10 bytes, unknown field types and lengths / decimals that
+/// are out of range fail to compile.
+///
+/// # Panics
+///
+/// Panics if the `encoding` label is not known by encoding_rs.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate dbase;
+/// # fn main() {
+/// let builder = dbase_schema! {
+///     encoding: "utf-8",
+///     fields: {
+///         NAME: Character(50),
+///         POPULATION: Numeric(10, 0),
+///         FOUNDED: Date,
+///         ACTIVE: Logical,
+///         SCORE: Float(8, 3),
+///     }
+/// };
+/// let writer = builder.build_with_dest(std::io::Cursor::new(Vec::<u8>::new()));
+/// # }
+/// ```
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate dbase;
+/// # fn main() {
+/// // The name is longer than 10 bytes
+/// let builder = dbase_schema! {
+///     fields: {
+///         POPULATION_2020: Numeric(10, 0),
+///     }
+/// };
+/// # }
+/// ```
+#[macro_export]
+macro_rules! dbase_schema {
+    (
+        encoding: $encoding:literal,
+        fields: { $($fields:tt)* } $(,)?
+    ) => {{
+        let builder = $crate::TableWriterBuilder::new_with_label($encoding)
+            .expect(concat!("invalid encoding label `", $encoding, "`"));
+        $crate::dbase_schema!(@fields builder, $($fields)*)
+    }};
+    (
+        fields: { $($fields:tt)* } $(,)?
+    ) => {{
+        let builder = $crate::TableWriterBuilder::new();
+        $crate::dbase_schema!(@fields builder, $($fields)*)
+    }};
+
+    (@fields $builder:expr $(,)?) => { $builder };
+    (@fields $builder:expr, $name:ident: Character($length:literal) $(, $($rest:tt)*)?) => {{
+        const _: () = assert!(
+            $length >= 1 && $length <= 254,
+            concat!("length of field `", stringify!($name), "` must be between 1 and 254")
+        );
+        $crate::dbase_schema!(
+            @fields $builder.add_character_field($crate::dbase_schema!(@name $name), $length)
+            $(, $($rest)*)?
+        )
+    }};
+    (@fields $builder:expr, $name:ident: Numeric($length:literal, $decimals:literal) $(, $($rest:tt)*)?) => {{
+        $crate::dbase_schema!(@check_number $name, $length, $decimals);
+        $crate::dbase_schema!(
+            @fields $builder.add_numeric_field($crate::dbase_schema!(@name $name), $length, $decimals)
+            $(, $($rest)*)?
+        )
+    }};
+    (@fields $builder:expr, $name:ident: Float($length:literal, $decimals:literal) $(, $($rest:tt)*)?) => {{
+        $crate::dbase_schema!(@check_number $name, $length, $decimals);
+        $crate::dbase_schema!(
+            @fields $builder.add_float_field($crate::dbase_schema!(@name $name), $length, $decimals)
+            $(, $($rest)*)?
+        )
+    }};
+    (@fields $builder:expr, $name:ident: Date $(, $($rest:tt)*)?) => {
+        $crate::dbase_schema!(
+            @fields $builder.add_date_field($crate::dbase_schema!(@name $name))
+            $(, $($rest)*)?
+        )
+    };
+    (@fields $builder:expr, $name:ident: Logical $(, $($rest:tt)*)?) => {
+        $crate::dbase_schema!(
+            @fields $builder.add_logical_field($crate::dbase_schema!(@name $name))
+            $(, $($rest)*)?
+        )
+    };
+    (@fields $builder:expr, $name:ident: Integer $(, $($rest:tt)*)?) => {
+        $crate::dbase_schema!(
+            @fields $builder.add_integer_field($crate::dbase_schema!(@name $name))
+            $(, $($rest)*)?
+        )
+    };
+    (@fields $builder:expr, $name:ident: AutoIncrement $(, $($rest:tt)*)?) => {
+        $crate::dbase_schema!(
+            @fields $builder.add_autoincrement_field($crate::dbase_schema!(@name $name))
+            $(, $($rest)*)?
+        )
+    };
+    (@fields $builder:expr, $name:ident: Double $(, $($rest:tt)*)?) => {
+        $crate::dbase_schema!(
+            @fields $builder.add_double_field($crate::dbase_schema!(@name $name))
+            $(, $($rest)*)?
+        )
+    };
+    (@fields $builder:expr, $name:ident: Currency $(, $($rest:tt)*)?) => {
+        $crate::dbase_schema!(
+            @fields $builder.add_currency_field($crate::dbase_schema!(@name $name))
+            $(, $($rest)*)?
+        )
+    };
+    (@fields $builder:expr, $name:ident: DateTime $(, $($rest:tt)*)?) => {
+        $crate::dbase_schema!(
+            @fields $builder.add_datetime_field($crate::dbase_schema!(@name $name))
+            $(, $($rest)*)?
+        )
+    };
+
+    (@name $name:ident) => {{
+        const _: () = assert!(
+            stringify!($name).len() <= 10,
+            concat!("field name `", stringify!($name), "` cannot exceed 10 bytes")
+        );
+        <$crate::FieldName as ::std::convert::TryFrom<&str>>::try_from(stringify!($name))
+            .expect("field name was checked at compile time")
+    }};
+    (@check_number $name:ident, $length:literal, $decimals:literal) => {
+        const _: () = assert!(
+            $length >= 1 && $length <= 20,
+            concat!("length of field `", stringify!($name), "` must be between 1 and 20")
+        );
+        const _: () = assert!(
+            $decimals == 0 || $decimals + 2 <= $length,
+            concat!(
+                "decimals of field `",
+                stringify!($name),
+                "` must leave room for the integer part and the decimal separator"
+            )
+        );
+    };
+}
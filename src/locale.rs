@@ -0,0 +1,259 @@
+//! Formatting of field values for display, following the conventions of a [Locale].
+use crate::{Date, DateTime, FieldValue};
+
+/// Order of the parts of a date, and the separator between them
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DateFormat {
+    /// `2024-01-31`
+    Iso,
+    /// `01/31/2024`
+    MonthDayYear,
+    /// `31.01.2024`
+    DayMonthYear,
+}
+
+impl DateFormat {
+    fn format(self, date: &Date) -> String {
+        let (year, month, day) = (date.year(), date.month(), date.day());
+        match self {
+            DateFormat::Iso => format!("{:04}-{:02}-{:02}", year, month, day),
+            DateFormat::MonthDayYear => format!("{:02}/{:02}/{:04}", month, day, year),
+            DateFormat::DayMonthYear => format!("{:02}.{:02}.{:04}", day, month, year),
+        }
+    }
+}
+
+/// Conventions used by [FieldValue::to_display_string]
+///
+/// # Example
+///
+/// ```
+/// use dbase::{FieldValue, Locale};
+///
+/// let locale = Locale::default().null_string("N/A");
+/// assert_eq!(FieldValue::Numeric(None).to_display_string(&locale), "N/A");
+/// assert_eq!(
+///     FieldValue::Numeric(Some(-1234.5)).to_display_string(&locale),
+///     "-1,234.5"
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Locale {
+    decimal_separator: char,
+    thousands_separator: Option<char>,
+    date_format: DateFormat,
+    null_string: String,
+}
+
+impl Locale {
+    /// European conventions: `,` as the decimal separator,
+    /// `.` as the thousands separator and `31.01.2024` dates
+    pub fn european() -> Self {
+        Self {
+            decimal_separator: ',',
+            thousands_separator: Some('.'),
+            date_format: DateFormat::DayMonthYear,
+            null_string: String::new(),
+        }
+    }
+
+    /// Sets the character between the integer and the fractional part of numbers
+    pub fn decimal_separator(mut self, separator: char) -> Self {
+        self.decimal_separator = separator;
+        self
+    }
+
+    /// Sets the character between groups of thousands, `None` to not group digits
+    pub fn thousands_separator(mut self, separator: Option<char>) -> Self {
+        self.thousands_separator = separator;
+        self
+    }
+
+    /// Sets how dates are formatted
+    pub fn date_format(mut self, format: DateFormat) -> Self {
+        self.date_format = format;
+        self
+    }
+
+    /// Sets the text of null values (e.g. `""`, `"NULL"`, `"N/A"`)
+    pub fn null_string<S: Into<String>>(mut self, null_string: S) -> Self {
+        self.null_string = null_string.into();
+        self
+    }
+
+    fn format_integer_part(&self, digits: &str, out: &mut String) {
+        for (i, digit) in digits.chars().enumerate() {
+            if i > 0 && (digits.len() - i).is_multiple_of(3) {
+                if let Some(separator) = self.thousands_separator {
+                    out.push(separator);
+                }
+            }
+            out.push(digit);
+        }
+    }
+
+    fn format_number<N: ToString>(&self, value: N) -> String {
+        let text = value.to_string();
+        let (sign, unsigned) = match text.strip_prefix('-') {
+            Some(unsigned) => ("-", unsigned),
+            None => ("", text.as_str()),
+        };
+        // inf & NaN
+        if !unsigned.starts_with(|c: char| c.is_ascii_digit()) {
+            return text;
+        }
+        let (integer_part, fractional_part) = match unsigned.split_once('.') {
+            Some((integer_part, fractional_part)) => (integer_part, Some(fractional_part)),
+            None => (unsigned, None),
+        };
+        let mut out = String::with_capacity(text.len() + integer_part.len() / 3);
+        out.push_str(sign);
+        self.format_integer_part(integer_part, &mut out);
+        if let Some(fractional_part) = fractional_part {
+            out.push(self.decimal_separator);
+            out.push_str(fractional_part);
+        }
+        out
+    }
+
+    fn format_date_time(&self, date_time: &DateTime) -> String {
+        let time = date_time.time();
+        format!(
+            "{} {:02}:{:02}:{:02}",
+            self.date_format.format(&date_time.date()),
+            time.hours(),
+            time.minutes(),
+            time.seconds()
+        )
+    }
+}
+
+/// US English conventions: `.` as the decimal separator,
+/// `,` as the thousands separator and `01/31/2024` dates
+impl Default for Locale {
+    fn default() -> Self {
+        Self {
+            decimal_separator: '.',
+            thousands_separator: Some(','),
+            date_format: DateFormat::MonthDayYear,
+            null_string: String::new(),
+        }
+    }
+}
+
+impl FieldValue {
+    /// Formats the value for display, following the conventions of the `locale`
+    ///
+    /// Unlike the `Display` implementation, which shows the variant,
+    /// only the value is formatted.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dbase::{Date, FieldValue, Locale};
+    ///
+    /// let locale = Locale::european();
+    /// assert_eq!(
+    ///     FieldValue::Numeric(Some(1234567.89)).to_display_string(&locale),
+    ///     "1.234.567,89"
+    /// );
+    /// assert_eq!(
+    ///     FieldValue::Date(Some(Date::new(31, 1, 2024))).to_display_string(&locale),
+    ///     "31.01.2024"
+    /// );
+    /// ```
+    pub fn to_display_string(&self, locale: &Locale) -> String {
+        let null = || locale.null_string.clone();
+        match self {
+            FieldValue::Character(value) => value.clone().unwrap_or_else(null),
+            FieldValue::Memo(value) => value.clone(),
+            FieldValue::BinaryMemo(value) => String::from_utf8_lossy(value).into_owned(),
+            FieldValue::Numeric(value) => value.map_or_else(null, |v| locale.format_number(v)),
+            FieldValue::Float(value) => value.map_or_else(null, |v| locale.format_number(v)),
+            FieldValue::Logical(value) => value.map_or_else(null, |v| v.to_string()),
+            FieldValue::Date(value) => value
+                .as_ref()
+                .map_or_else(null, |v| locale.date_format.format(v)),
+            FieldValue::Integer(value) | FieldValue::AutoIncrement(value) => {
+                locale.format_number(value)
+            }
+            FieldValue::Currency(value) => locale.format_number(value),
+            FieldValue::Double(value) => locale.format_number(value),
+            FieldValue::DateTime(value) => locale.format_date_time(value),
+        }
+    }
+
+    /// Formats the value as plain ASCII text, for quick logging
+    ///
+    /// Numbers have no thousands separator and use `.` as the decimal separator,
+    /// dates are formatted as `2024-01-31`, null values are empty,
+    /// and non-ASCII characters are replaced by `?`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dbase::FieldValue;
+    ///
+    /// assert_eq!(FieldValue::Numeric(Some(1234567.89)).to_string_lossy(), "1234567.89");
+    /// assert_eq!(FieldValue::Character(Some("Café".to_string())).to_string_lossy(), "Caf?");
+    /// ```
+    pub fn to_string_lossy(&self) -> String {
+        let locale = Locale::default()
+            .thousands_separator(None)
+            .date_format(DateFormat::Iso);
+        self.to_display_string(&locale)
+            .chars()
+            .map(|c| if c.is_ascii() { c } else { '?' })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Time;
+
+    #[test]
+    fn test_european_locale() {
+        let locale = Locale::european();
+        assert_eq!(
+            FieldValue::Numeric(Some(1234567.89)).to_display_string(&locale),
+            "1.234.567,89"
+        );
+        assert_eq!(
+            FieldValue::Integer(-1234).to_display_string(&locale),
+            "-1.234"
+        );
+        assert_eq!(
+            FieldValue::Float(Some(0.5)).to_display_string(&locale),
+            "0,5"
+        );
+        assert_eq!(FieldValue::Float(None).to_display_string(&locale), "");
+    }
+
+    #[test]
+    fn test_default_locale() {
+        let locale = Locale::default().null_string("NULL");
+        assert_eq!(
+            FieldValue::Numeric(Some(1234567.89)).to_display_string(&locale),
+            "1,234,567.89"
+        );
+        assert_eq!(FieldValue::Double(123.0).to_display_string(&locale), "123");
+        assert_eq!(FieldValue::Logical(None).to_display_string(&locale), "NULL");
+        assert_eq!(
+            FieldValue::DateTime(DateTime::new(Date::new(31, 1, 2024), Time::new(8, 5, 0)))
+                .to_display_string(&locale),
+            "01/31/2024 08:05:00"
+        );
+    }
+
+    #[test]
+    fn test_to_string_lossy() {
+        assert_eq!(FieldValue::Integer(1234567).to_string_lossy(), "1234567");
+        assert_eq!(
+            FieldValue::Date(Some(Date::new(31, 1, 2024))).to_string_lossy(),
+            "2024-01-31"
+        );
+        assert_eq!(FieldValue::Character(None).to_string_lossy(), "");
+    }
+}
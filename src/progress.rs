@@ -0,0 +1,64 @@
+use std::sync::Arc;
+
+/// Number of records processed between two calls of the progress callback, by default
+const DEFAULT_PROGRESS_INTERVAL: usize = 1000;
+
+/// Callback reporting progress, given the number of records processed
+/// and the total number of records
+pub(crate) type ProgressCallback = Arc<dyn Fn(u64, u64) + Send + Sync>;
+
+/// Progress reporting state shared by the reader and the writer
+#[derive(Clone)]
+pub(crate) struct Progress {
+    callback: Option<ProgressCallback>,
+    interval: usize,
+}
+
+impl Progress {
+    pub(crate) fn set_callback(&mut self, callback: ProgressCallback) {
+        self.callback = Some(callback);
+    }
+
+    pub(crate) fn set_interval(&mut self, interval: usize) {
+        self.interval = interval.max(1);
+    }
+
+    /// Calls the callback if `processed` is a multiple of the interval,
+    /// or if it is the last record
+    #[inline]
+    pub(crate) fn report(&self, processed: usize, total: usize) {
+        if let Some(callback) = &self.callback {
+            if processed.is_multiple_of(self.interval) || processed == total {
+                callback(processed as u64, total as u64);
+            }
+        }
+    }
+
+    /// Calls the callback, unless it was already called for `processed`
+    #[inline]
+    pub(crate) fn report_end(&self, processed: usize, total: usize) {
+        if let Some(callback) = &self.callback {
+            if !processed.is_multiple_of(self.interval) && processed != total {
+                callback(processed as u64, total as u64);
+            }
+        }
+    }
+}
+
+impl Default for Progress {
+    fn default() -> Self {
+        Self {
+            callback: None,
+            interval: DEFAULT_PROGRESS_INTERVAL,
+        }
+    }
+}
+
+impl std::fmt::Debug for Progress {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Progress")
+            .field("callback", &self.callback.as_ref().map(|_| "Fn(u64, u64)"))
+            .field("interval", &self.interval)
+            .finish()
+    }
+}
@@ -1,6 +1,5 @@
 //! Module with the definition of fn's and struct's to read .dbf files
 
-use byteorder::ReadBytesExt;
 use encoding_rs::Encoding;
 
 use std::collections::hash_map::RandomState;
@@ -9,18 +8,27 @@ use std::convert::TryFrom;
 use std::fs::File;
 use std::io::{BufReader, Read, Seek, SeekFrom};
 use std::iter::FusedIterator;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
 use crate::error::{Error, ErrorKind, FieldIOError};
-use crate::header::Header;
-use crate::record::field::{FieldType, FieldValue, MemoFileType, MemoReader};
-use crate::record::FieldInfo;
+use crate::header::{encoding_for_code_page_mark, Header, Version};
+use crate::progress::Progress;
+use crate::record::field::{trim_field_data, FieldType, FieldValue, MemoFileType, MemoReader};
+use crate::record::{assign_null_flag_bits, null_flag_is_set, FieldInfo};
 use crate::FieldConversionError;
 
 /// Value of the byte between the last RecordFieldInfo and the first record
 pub(crate) const TERMINATOR_VALUE: u8 = 0x0D;
 
-const BACKLINK_SIZE: u16 = 263;
+/// Value of the first byte of a record that is marked as deleted
+const DELETED_RECORD_FLAG: u8 = b'*';
+
+/// A dbase file ends with this byte (end of file marker)
+pub(crate) const FILE_TERMINATOR: u8 = 0x1A;
+
+/// Size of the area after the fields of Visual FoxPro files, where the path of the database is stored
+pub(crate) const BACKLINK_SIZE: u16 = 263;
 
 /// Trait to be implemented by structs that represent records read from a
 /// dBase file.
@@ -39,9 +47,43 @@ pub trait ReadableRecord: Sized {
 
 /// Type definition of a generic record.
 /// A .dbf file is composed of many records
-#[derive(Debug, PartialEq, Clone, Default)]
+///
+/// The fields are kept in the order they were inserted (for records read from a file,
+/// the order of the fields in the file), and are indexed by name so that
+/// [Record::get] does not have to scan all the fields.
+///
+/// The records read from the same file share their field names & index,
+/// they are copied when a field is added to or removed from a record.
+#[derive(Clone, Default)]
 pub struct Record {
-    map: HashMap<String, FieldValue>,
+    names: Arc<RecordNames>,
+    values: Vec<FieldValue>,
+}
+
+/// Names of the fields of a [Record], in order, indexed by name
+#[derive(Clone, Default)]
+pub(crate) struct RecordNames {
+    names: Vec<String>,
+    index: HashMap<String, usize>,
+}
+
+impl RecordNames {
+    /// Returns the names of the fields read by a [FieldIterator],
+    /// or `None` if two fields have the same name
+    pub(crate) fn of_fields(fields_info: &[FieldInfo]) -> Option<Arc<Self>> {
+        let mut record_names = Self::default();
+        for field_info in fields_info.iter().filter(|info| !info.is_hidden()) {
+            let name = field_info.name().to_owned();
+            if record_names.index.contains_key(&name) {
+                return None;
+            }
+            record_names
+                .index
+                .insert(name.clone(), record_names.names.len());
+            record_names.names.push(name);
+        }
+        Some(Arc::new(record_names))
+    }
 }
 
 impl ReadableRecord for Record {
@@ -49,18 +91,37 @@ impl ReadableRecord for Record {
     where
         T: Read + Seek,
     {
-        let mut map = HashMap::<String, FieldValue>::new();
-        for result in field_iterator {
-            let NamedValue { name, value } = result?;
-            map.insert(name.to_owned(), value);
+        let mut record = Self::default();
+        match field_iterator.record_names {
+            Some(names) => {
+                record.names = Arc::clone(names);
+                record.values.reserve_exact(names.names.len());
+                for result in field_iterator {
+                    record.values.push(result?.value);
+                }
+            }
+            None => {
+                for result in field_iterator {
+                    let NamedValue { name, value } = result?;
+                    record.insert(name.to_owned(), value);
+                }
+            }
         }
-        Ok(Self { map })
+        Ok(record)
     }
 }
 
 impl Record {
+    /// Creates an empty record, to be filled with [insert](#method.insert)
+    pub fn new() -> Self {
+        Self::default()
+    }
+
     /// Inserts a new value in the record, returning the old one if there was any
     ///
+    /// A new field is added after the existing ones, replacing the value of
+    /// an existing field keeps its position.
+    ///
     /// # Example
     ///
     /// ```
@@ -69,55 +130,250 @@ impl Record {
     /// record.insert("FirstName".to_owned(), dbase::FieldValue::Character(Some("Yoshi".to_owned())));
     /// ```
     pub fn insert(&mut self, field_name: String, value: FieldValue) -> Option<FieldValue> {
-        self.map.insert(field_name, value)
+        match self.names.index.get(&field_name) {
+            Some(&i) => Some(std::mem::replace(&mut self.values[i], value)),
+            None => {
+                let names = Arc::make_mut(&mut self.names);
+                names.index.insert(field_name.clone(), self.values.len());
+                names.names.push(field_name);
+                self.values.push(value);
+                None
+            }
+        }
     }
 
     /// Returns the [FieldValue](enum.FieldValue.html) for the given field name
     pub fn get(&self, field_name: &str) -> Option<&FieldValue> {
-        self.map.get(field_name)
+        self.names.index.get(field_name).map(|&i| &self.values[i])
+    }
+
+    /// Returns the [FieldValue](enum.FieldValue.html) for the given field name,
+    /// ignoring the ASCII case of the names
+    ///
+    /// dBase programs write field names in uppercase while other programs may not,
+    /// if several fields match, the one with the exact name is returned, otherwise the first one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), dbase::Error> {
+    /// let records = dbase::read("tests/data/stations.dbf")?;
+    /// assert_eq!(records[0].get("NAME"), None);
+    /// assert_eq!(records[0].get_case_insensitive("NAME"), records[0].get("name"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_case_insensitive(&self, field_name: &str) -> Option<&FieldValue> {
+        self.get(field_name).or_else(|| {
+            self.iter()
+                .find(|(name, _)| name.eq_ignore_ascii_case(field_name))
+                .map(|(_, value)| value)
+        })
     }
 
     /// Returns the mutable [FieldValue](enum.FieldValue.html) for the given field name
     pub fn get_mut(&mut self, field_name: &str) -> Option<&mut FieldValue> {
-        self.map.get_mut(field_name)
+        let i = *self.names.index.get(field_name)?;
+        Some(&mut self.values[i])
+    }
+
+    /// Returns the value of the given field converted into the requested type
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), dbase::Error> {
+    /// let records = dbase::read("tests/data/stations.dbf")?;
+    /// let line: String = records[0].get_as("line").unwrap();
+    /// assert_eq!(line, "blue");
+    /// assert!(records[0].get_as::<f64>("line").is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_as<F>(&self, field_name: &str) -> Result<F, FieldConversionError>
+    where
+        F: TryFrom<FieldValue, Error = FieldConversionError>,
+    {
+        let value = self
+            .get(field_name)
+            .ok_or_else(|| FieldConversionError::MissingField(field_name.to_owned()))?;
+        F::try_from(value.clone())
+    }
+
+    /// Returns the name and [FieldValue](enum.FieldValue.html) of the field at the given position
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), dbase::Error> {
+    /// let records = dbase::read("tests/data/line.dbf")?;
+    /// let (name, value) = records[0].get_at(0).unwrap();
+    /// assert_eq!(name, "name");
+    /// assert_eq!(value, &dbase::FieldValue::Character(Some("linestring1".to_owned())));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_at(&self, index: usize) -> Option<(&str, &FieldValue)> {
+        Some((
+            self.names.names.get(index)?.as_str(),
+            self.values.get(index)?,
+        ))
+    }
+
+    /// Returns the name and mutable [FieldValue](enum.FieldValue.html) of the field at the given position
+    pub fn get_at_mut(&mut self, index: usize) -> Option<(&str, &mut FieldValue)> {
+        Some((
+            self.names.names.get(index)?.as_str(),
+            self.values.get_mut(index)?,
+        ))
+    }
+
+    /// Returns an iterator over the names and values of the fields, in order
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &FieldValue)> {
+        self.into_iter()
+    }
+
+    /// Returns an iterator over the names and mutable values of the fields, in order
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (&str, &mut FieldValue)> {
+        self.into_iter()
+    }
+
+    /// Returns the number of fields in the record
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns true if the record has no fields
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Returns true if the record has a field with the given name
+    pub fn contains(&self, field_name: &str) -> bool {
+        self.names.index.contains_key(field_name)
+    }
+
+    /// Returns an iterator over the names of the fields, in order
+    pub fn field_names(&self) -> impl Iterator<Item = &str> {
+        self.names.names.iter().map(String::as_str)
     }
 
     /// Removes the [FieldValue](enum.FieldValue.html) for the given field name
+    ///
+    /// The fields after the removed one keep their order.
     pub fn remove(&mut self, field_name: &str) -> Option<FieldValue> {
-        self.map.remove(field_name)
+        let i = *self.names.index.get(field_name)?;
+        let names = Arc::make_mut(&mut self.names);
+        names.index.remove(field_name);
+        names.names.remove(i);
+        for name in &names.names[i..] {
+            if let Some(position) = names.index.get_mut(name) {
+                *position -= 1;
+            }
+        }
+        Some(self.values.remove(i))
     }
 }
 
+/// Records are equal if they have the same fields with the same values,
+/// regardless of the order of the fields.
+impl PartialEq for Record {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .all(|(name, value)| other.get(name) == Some(value))
+    }
+}
+
+impl std::fmt::Debug for Record {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+/// Iterates over the `(name, value)` pairs in the order of the fields
 impl IntoIterator for Record {
     type Item = (String, FieldValue);
-    type IntoIter = std::collections::hash_map::IntoIter<String, FieldValue>;
+    type IntoIter = std::vec::IntoIter<(String, FieldValue)>;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.map.into_iter()
+        let names = Arc::try_unwrap(self.names)
+            .map_or_else(|names| names.names.clone(), |names| names.names);
+        names
+            .into_iter()
+            .zip(self.values)
+            .collect::<Vec<_>>()
+            .into_iter()
     }
 }
 
-impl From<HashMap<String, FieldValue>> for Record {
-    fn from(map: HashMap<String, FieldValue, RandomState>) -> Self {
-        Self { map }
+/// Iterates over the `(name, value)` pairs in the order of the fields
+impl<'a> IntoIterator for &'a Record {
+    type Item = (&'a str, &'a FieldValue);
+    type IntoIter = std::iter::Map<
+        std::iter::Zip<std::slice::Iter<'a, String>, std::slice::Iter<'a, FieldValue>>,
+        fn((&'a String, &'a FieldValue)) -> (&'a str, &'a FieldValue),
+    >;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.names
+            .names
+            .iter()
+            .zip(self.values.iter())
+            .map(|(name, value)| (name.as_str(), value))
     }
 }
 
-impl From<Record> for HashMap<String, FieldValue> {
-    fn from(record: Record) -> HashMap<String, FieldValue> {
-        record.map
+/// Iterates over the `(name, value)` pairs in the order of the fields
+impl<'a> IntoIterator for &'a mut Record {
+    type Item = (&'a str, &'a mut FieldValue);
+    type IntoIter = std::iter::Map<
+        std::iter::Zip<std::slice::Iter<'a, String>, std::slice::IterMut<'a, FieldValue>>,
+        fn((&'a String, &'a mut FieldValue)) -> (&'a str, &'a mut FieldValue),
+    >;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.names
+            .names
+            .iter()
+            .zip(self.values.iter_mut())
+            .map(|(name, value)| (name.as_str(), value))
     }
 }
 
-impl AsRef<HashMap<String, FieldValue>> for Record {
-    fn as_ref(&self) -> &HashMap<String, FieldValue> {
-        &self.map
+/// Collects the `(name, value)` pairs in order, as with [Record::insert]:
+/// a name that comes again replaces the value without moving the field
+impl FromIterator<(String, FieldValue)> for Record {
+    fn from_iter<I: IntoIterator<Item = (String, FieldValue)>>(iter: I) -> Self {
+        let mut record = Self::default();
+        record.extend(iter);
+        record
     }
 }
 
-impl AsMut<HashMap<String, FieldValue>> for Record {
-    fn as_mut(&mut self) -> &mut HashMap<String, FieldValue> {
-        &mut self.map
+/// Inserts the `(name, value)` pairs in order, as with [Record::insert]
+impl Extend<(String, FieldValue)> for Record {
+    fn extend<I: IntoIterator<Item = (String, FieldValue)>>(&mut self, iter: I) {
+        for (name, value) in iter {
+            self.insert(name, value);
+        }
+    }
+}
+
+impl From<HashMap<String, FieldValue>> for Record {
+    fn from(map: HashMap<String, FieldValue, RandomState>) -> Self {
+        let mut record = Self::default();
+        for (name, value) in map {
+            record.insert(name, value);
+        }
+        record
+    }
+}
+
+impl From<Record> for HashMap<String, FieldValue> {
+    fn from(record: Record) -> HashMap<String, FieldValue> {
+        record.into_iter().collect()
     }
 }
 
@@ -130,21 +386,217 @@ impl AsMut<HashMap<String, FieldValue>> for Record {
 pub struct TableInfo {
     pub(crate) header: Header,
     pub(crate) fields_info: Vec<FieldInfo>,
+    pub(crate) encoding: &'static Encoding,
+    /// Size of the blocks of the memo file, if known
+    pub(crate) memo_block_size: Option<u32>,
+}
+
+impl TableInfo {
+    /// Returns the fields of the table
+    pub fn fields(&self) -> &[FieldInfo] {
+        &self.fields_info
+    }
+
+    /// Returns the encoding of the table
+    pub fn encoding(&self) -> &'static Encoding {
+        self.encoding
+    }
+
+    /// Returns the number of records of the table, as stored in the header
+    pub fn num_records(&self) -> u32 {
+        self.header.num_records
+    }
+
+    /// Returns the size in bytes of a record in the file, deletion flag included,
+    /// as stored in the header
+    ///
+    /// The record `i` starts at `header_size() + i * record_size()` bytes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), dbase::Error> {
+    /// let table_info =
+    ///     dbase::Reader::from_path("tests/data/stations_optional.dbf")?.into_table_info();
+    /// let record_size: u16 = table_info.fields().iter().map(|field| u16::from(field.length())).sum();
+    /// assert_eq!(table_info.record_size(), record_size);
+    /// assert_eq!(table_info.header_size(), 161);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn record_size(&self) -> u16 {
+        self.header.size_of_record
+    }
+
+    /// Returns the size in bytes of the header of the file, as stored in the header:
+    /// the offset of the first record, after the field descriptors
+    /// (and after the backlink of Visual FoxPro files)
+    pub fn header_size(&self) -> u16 {
+        self.header.offset_to_first_record
+    }
+}
+
+/// Options that change the way field values are read
+///
+/// # Example
+///
+/// ```
+/// # fn main() -> Result<(), dbase::Error> {
+/// let mut reader = dbase::Reader::from_path("tests/data/line.dbf")?;
+/// reader.set_options(dbase::ReadingOptions::new().character_like_numerics(true));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct ReadingOptions {
+    pub(crate) character_like_numerics: bool,
+    pub(crate) lenient: bool,
+    pub(crate) comma_decimal_separator: bool,
+    pub(crate) binary_memos: bool,
+}
+
+impl ReadingOptions {
+    /// Creates the default options
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// When enabled, the values of Numeric and Float fields are not parsed,
+    /// they are returned as [FieldValue::Character] holding the trimmed text
+    /// exactly as it is stored in the file.
+    ///
+    /// Useful when the values have more significant digits than a `f64` can hold,
+    /// or when leading zeros or sign are meaningful.
+    pub fn character_like_numerics(mut self, enabled: bool) -> Self {
+        self.character_like_numerics = enabled;
+        self
+    }
+
+    /// When enabled, Numeric, Float and Date values that cannot be parsed
+    /// are read as null values (e.g. `FieldValue::Numeric(None)`) instead of
+    /// making the reading of the record fail.
+    ///
+    /// A [ReadWarning] is recorded by the reader for each of these values,
+    /// and when the number of records differs from the one of the header,
+    /// see [Reader::set_lenient].
+    pub fn lenient(mut self, enabled: bool) -> Self {
+        self.lenient = enabled;
+        self
+    }
+
+    /// When enabled, a `,` in the value of Numeric and Float fields
+    /// is treated as the decimal separator (e.g. `1,25` is read as `1.25`)
+    ///
+    /// Values containing both `.` and `,`, or more than one `,`, are ambiguous
+    /// (the `,` could be a thousands separator) and still fail to parse.
+    pub fn comma_decimal_separator(mut self, enabled: bool) -> Self {
+        self.comma_decimal_separator = enabled;
+        self
+    }
+
+    /// When enabled, the values of all Memo fields are read as their raw bytes
+    /// ([FieldValue::BinaryMemo]) instead of text, which would replace
+    /// the bytes that are not valid UTF-8.
+    ///
+    /// Memo fields flagged as binary ([FieldFlags::is_binary](crate::FieldFlags::is_binary))
+    /// are always read as bytes.
+    pub fn binary_memos(mut self, enabled: bool) -> Self {
+        self.binary_memos = enabled;
+        self
+    }
+}
+
+/// What a [ReadWarning] is about
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ReadWarningKind {
+    /// The value of a field could not be parsed, it was read as null
+    InvalidValue,
+    /// The label of the `.cpg` file is not a known encoding
+    UnknownEncoding,
+    /// The number of records in the file differs from the number stored in its header
+    RecordCountMismatch {
+        /// Number of records stored in the header
+        declared: u32,
+        /// Number of records read
+        actual: u32,
+    },
+    /// The file is shorter than the size of its header and declared records
+    TruncatedFile {
+        /// Size in bytes of the header and declared records
+        expected_size: u64,
+        /// Size in bytes of the file
+        actual_size: u64,
+    },
+}
+
+/// Warning recorded in lenient mode when the value of a field could not be parsed,
+/// or when the file does not match its header
+///
+/// See [ReadingOptions::lenient].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReadWarning {
+    pub(crate) record_num: usize,
+    pub(crate) field: Option<FieldInfo>,
+    pub(crate) raw_bytes: Vec<u8>,
+    pub(crate) message: String,
+    pub(crate) kind: ReadWarningKind,
+}
+
+impl ReadWarning {
+    /// Returns what the warning is about
+    pub fn kind(&self) -> ReadWarningKind {
+        self.kind
+    }
+
+    /// Returns the index of the record for which the warning was emitted
+    pub fn record_num(&self) -> usize {
+        self.record_num
+    }
+
+    /// Returns the information of the field for which the warning was emitted,
+    /// `None` if the warning is not about a field (e.g. an unknown encoding in the `.cpg` file)
+    pub fn field(&self) -> Option<&FieldInfo> {
+        self.field.as_ref()
+    }
+
+    /// Returns the bytes of the field (or of the `.cpg` file), as stored in the file
+    pub fn raw_bytes(&self) -> &[u8] {
+        &self.raw_bytes
+    }
+
+    /// Returns the bytes of the field without the padding
+    /// (spaces and null bytes) that surrounds the value,
+    /// this is what the reader tried to parse.
+    pub fn trimmed_bytes(&self) -> &[u8] {
+        trim_field_data(&self.raw_bytes)
+    }
+
+    /// Returns the description of the parsing error
+    pub fn message(&self) -> &str {
+        &self.message
+    }
 }
 
 #[derive(Clone, Debug)]
 struct Inner {
     pub(crate) encoding: &'static Encoding,
+    pub(crate) options: ReadingOptions,
 }
 
-impl<'a> Inner {
-    pub(crate) fn encoding(&self) -> &'a Encoding {
+impl Inner {
+    pub(crate) fn encoding(&self) -> &'static Encoding {
         self.encoding
     }
 }
 
 /// Struct with the handle to the source .dbf file
 /// Responsible for reading the content
+///
+/// The number of records in the header tells how many records are read,
+/// bytes after the last record (the end of file marker, or anything
+/// written by buggy programs) are ignored.
+/// If the file has fewer records than its header says,
+/// the records stop at the end of file marker (`0x1A`) or at the end of the file.
 #[derive(Clone, Debug)]
 pub struct Reader<T: Read + Seek> {
     /// Where the data is read from
@@ -153,35 +605,56 @@ pub struct Reader<T: Read + Seek> {
     header: Header,
     fields_info: Vec<FieldInfo>,
     inner: Inner,
+    warnings: Vec<ReadWarning>,
+    /// Path of the file, when the reader was created from one
+    path: Option<PathBuf>,
+    progress: Progress,
+    /// Size of the file in bytes, measured when the reader is created
+    file_size: u64,
+    /// Number of records after the ones declared in the header,
+    /// measured when the reader is created
+    num_extra_records: u32,
 }
 
 impl<T: Read + Seek> Reader<T> {
     fn _new(mut source: T, label: Option<&str>) -> Result<Self, Error> {
-        let label = label.unwrap_or("utf-8");
-        let encoding = Encoding::for_label(label.as_bytes());
-        if encoding.is_none() {
+        let encoding = match label {
+            Some(label) => Encoding::for_label(label.as_bytes()),
+            None => None,
+        };
+        if label.is_some() && encoding.is_none() {
             return Err(Error {
                 record_num: 0,
                 field: None,
                 kind: ErrorKind::InvalidEncoding,
             });
         }
-        let encoding = encoding.unwrap();
-
-        let header = Header::read_from(&mut source).map_err(|error| Error::io_error(error, 0))?;
-
-        let offset = if header.file_type.is_visual_fox_pro() {
-            header.offset_to_first_record - BACKLINK_SIZE
-        } else {
-            header.offset_to_first_record
-        };
-        let num_fields =
-            (offset as usize - Header::SIZE - std::mem::size_of::<u8>()) / FieldInfo::SIZE;
 
-        let mut fields_info = Vec::<FieldInfo>::with_capacity(num_fields as usize + 1);
+        let header = Header::read_from(&mut source).map_err(header_error)?;
+        // The header is followed by at least the terminator of the field descriptors
+        if (header.offset_to_first_record as usize) <= Header::SIZE {
+            return Err(header_error(std::io::ErrorKind::UnexpectedEof.into()));
+        }
+        // Without a label, the encoding is the one of the code page mark of the header
+        let encoding = encoding
+            .or_else(|| encoding_for_code_page_mark(header.code_page_mark))
+            .unwrap_or(encoding_rs::UTF_8);
+
+        // The field descriptors are followed by the terminator, which can itself be followed
+        // by other data (e.g. the backlink of Visual FoxPro files),
+        // so the descriptors are read until the terminator and the first record is
+        // located using the offset stored in the header.
+        let descriptors_size =
+            (header.offset_to_first_record as usize).saturating_sub(Header::SIZE);
+        let mut descriptors = vec![0u8; descriptors_size];
+        source.read_exact(&mut descriptors).map_err(header_error)?;
+
+        let mut fields_info =
+            Vec::<FieldInfo>::with_capacity(descriptors_size / FieldInfo::SIZE + 1);
         fields_info.push(FieldInfo::new_deletion_flag());
-        for _ in 0..num_fields {
-            let info = FieldInfo::read_from(&mut source, encoding).map_err(|error| Error {
+        let mut descriptors = descriptors.as_slice();
+        while descriptors.len() >= FieldInfo::SIZE && descriptors[0] != TERMINATOR_VALUE {
+            let info = FieldInfo::read_from(&mut descriptors, encoding).map_err(|error| Error {
                 record_num: 0,
                 field: None,
                 kind: error,
@@ -189,12 +662,11 @@ impl<T: Read + Seek> Reader<T> {
             fields_info.push(info);
         }
 
-        let terminator = source
-            .read_u8()
-            .map_err(|error| Error::io_error(error, 0))?;
-
-        debug_assert_eq!(terminator, TERMINATOR_VALUE);
+        assign_null_flag_bits(&mut fields_info);
 
+        let record_size = fields_info.iter().map(|i| i.field_length as usize).sum();
+        let (file_size, num_extra_records) = measure_records_end(&mut source, &header, record_size)
+            .map_err(|error| Error::io_error(error, 0))?;
         source
             .seek(SeekFrom::Start(u64::from(header.offset_to_first_record)))
             .map_err(|error| Error::io_error(error, 0))?;
@@ -204,7 +676,15 @@ impl<T: Read + Seek> Reader<T> {
             memo_reader: None,
             header,
             fields_info,
-            inner: Inner { encoding },
+            inner: Inner {
+                encoding,
+                options: ReadingOptions::default(),
+            },
+            warnings: Vec::new(),
+            path: None,
+            progress: Progress::default(),
+            file_size,
+            num_extra_records,
         })
     }
 
@@ -215,6 +695,9 @@ impl<T: Read + Seek> Reader<T> {
     /// Creating a reader from a file path using the [from_path](struct.Reader.html#method.from_path) is the prefered
     /// way of doing it as it wraps the file in a BufReader for performance.
     ///
+    /// The file is decoded with the encoding of the code page mark of its header,
+    /// or as UTF-8 if it has none.
+    ///
     /// # Example
     ///
     /// ```
@@ -242,6 +725,39 @@ impl<T: Read + Seek> Reader<T> {
         Self::_new(source, Some(label))
     }
 
+    /// Creates a new reader from the source of the dbase file and the source of its memo file
+    ///
+    /// Unlike [from_path](#method.from_path), which opens the memo file next to the dbase file,
+    /// the memo file is given explicitly, which allows reading Memo fields
+    /// from any source (e.g. bytes in memory, an entry of an archive).
+    ///
+    /// Both sources must be of the same type, `memo_type` is the structure of the memo file.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dbase::{FieldValue, MemoFileType, Reader};
+    /// use std::io::Cursor;
+    /// # fn main() -> Result<(), dbase::Error> {
+    /// let dbf = Cursor::new(std::fs::read("tests/data/memo.dbf").unwrap());
+    /// let dbt = Cursor::new(std::fs::read("tests/data/memo.dbt").unwrap());
+    /// let mut reader = Reader::new_with_memo(dbf, dbt, MemoFileType::DbaseMemo)?;
+    /// let records = reader.read()?;
+    /// assert_eq!(
+    ///     records[1].get("NOTES"),
+    ///     Some(&FieldValue::Memo("Another note".to_string()))
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new_with_memo(source: T, memo: T, memo_type: MemoFileType) -> Result<Self, Error> {
+        let mut reader = Self::new(source)?;
+        let memo_reader =
+            MemoReader::new(memo_type, memo).map_err(|error| Error::io_error(error, 0))?;
+        reader.memo_reader = Some(memo_reader);
+        Ok(reader)
+    }
+
     /// Returns the header of the file
     pub fn header(&self) -> &Header {
         &self.header
@@ -256,29 +772,297 @@ impl<T: Read + Seek> Reader<T> {
         self.inner.encoding()
     }
 
+    /// Returns the options used when reading field values
+    pub fn options(&self) -> ReadingOptions {
+        self.inner.options
+    }
+
+    /// Sets the options used when reading field values
+    pub fn set_options(&mut self, options: ReadingOptions) {
+        self.inner.options = options;
+    }
+
+    /// Enables or disables the lenient mode
+    ///
+    /// In lenient mode, reading never fails because a Numeric, Float or Date
+    /// value could not be parsed, a null value is returned instead and
+    /// a [ReadWarning] is recorded.
+    ///
+    /// Once all the records are read, a warning is also recorded when the file has
+    /// fewer or more records than its header declares (see [ReadWarningKind]).
+    /// Outside of lenient mode, more records than declared are an
+    /// [ErrorKind::RecordCountMismatch] error, given after the last declared record,
+    /// while fewer records are silently ignored. Bytes after an end of file marker
+    /// (`0x1A`) are not records.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), dbase::Error> {
+    /// let mut reader = dbase::Reader::from_path("tests/data/line.dbf")?;
+    /// reader.set_lenient(true);
+    /// let records = reader.read()?;
+    /// for warning in reader.warnings() {
+    ///     println!("record {}: {}", warning.record_num(), warning.message());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_lenient(&mut self, lenient: bool) {
+        self.inner.options.lenient = lenient;
+    }
+
+    /// Returns the warnings recorded so far in lenient mode
+    pub fn warnings(&self) -> &[ReadWarning] {
+        &self.warnings
+    }
+
+    /// Returns the warnings recorded so far in lenient mode,
+    /// and clears them from the reader
+    pub fn take_warnings(&mut self) -> Vec<ReadWarning> {
+        std::mem::take(&mut self.warnings)
+    }
+
+    /// Returns the version of the file, as stored in its header
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), dbase::Error> {
+    /// let reader = dbase::Reader::from_path("tests/data/stations.dbf")?;
+    /// assert_eq!(reader.version(), dbase::Version::DBase3 { supports_memo: false });
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn version(&self) -> Version {
+        self.header.file_type
+    }
+
+    /// Returns the type of the memo file,
+    /// `None` if the file has no memo file opened
+    pub fn memo_file_type(&self) -> Option<MemoFileType> {
+        self.memo_reader.as_ref().map(MemoReader::memo_file_type)
+    }
+
+    /// Returns the size of the blocks of the memo file,
+    /// `None` if the file has no memo file opened
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), dbase::Error> {
+    /// let reader = dbase::Reader::from_path("tests/data/stations.dbf")?;
+    /// assert_eq!(reader.memo_block_size(), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn memo_block_size(&self) -> Option<u32> {
+        self.memo_reader.as_ref().map(MemoReader::block_size)
+    }
+
+    /// Sets a callback called while iterating over the records,
+    /// with the number of records read and the total number of records to read.
+    ///
+    /// The callback is called every [progress interval](#method.set_progress_interval)
+    /// records, and after the last one.
+    ///
+    /// The callback must be `Send` & `Sync` so that it can, for example,
+    /// update a progress bar displayed by another thread.
+    ///
+    /// Readers of [slices](#method.slice) do not report their progress.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), dbase::Error> {
+    /// use std::sync::atomic::{AtomicU64, Ordering};
+    /// use std::sync::Arc;
+    ///
+    /// let num_read = Arc::new(AtomicU64::new(0));
+    /// let mut reader = dbase::Reader::from_path("tests/data/stations.dbf")?;
+    /// let progress = Arc::clone(&num_read);
+    /// reader.set_progress_callback(move |processed, _total| {
+    ///     progress.store(processed, Ordering::Relaxed);
+    /// });
+    /// reader.read()?;
+    /// assert_eq!(num_read.load(Ordering::Relaxed), 6);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_progress_callback<F>(&mut self, callback: F)
+    where
+        F: Fn(u64, u64) + Send + Sync + 'static,
+    {
+        self.progress.set_callback(Arc::new(callback));
+    }
+
+    /// Sets the number of records read between two calls of the
+    /// [progress callback](#method.set_progress_callback), 1000 by default.
+    ///
+    /// An interval of 0 is treated as 1.
+    pub fn set_progress_interval(&mut self, n: usize) {
+        self.progress.set_interval(n);
+    }
+
     /// Creates an iterator of records of the type you want
-    pub fn iter_records_as<R: ReadableRecord>(&mut self) -> RecordIterator<T, R> {
-        let record_size: usize = self
-            .fields_info
+    ///
+    /// The iteration starts at the current position, e.g. the one set by
+    /// [skip_records](#method.skip_records).
+    /// Once all the records were read, use [rewind](#method.rewind)
+    /// to iterate over them again.
+    pub fn iter_records_as<R: ReadableRecord>(&mut self) -> RecordIterator<'_, T, R> {
+        let end_record = self.header.num_records as usize;
+        let start_record = self.current_record().unwrap_or(0).min(end_record);
+        RecordIterator::new(ReaderRef::Borrowed(self), start_record, end_record)
+    }
+
+    /// Creates an iterator over the records `start..end`, of the type you want
+    ///
+    /// The reader seeks to the record `start` first.
+    /// `end` is clamped to the number of records of the file,
+    /// and `start` to `end`, so that out of bounds ranges give fewer (or no) records.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), dbase::Error> {
+    /// let mut reader = dbase::Reader::from_path("tests/data/stations.dbf")?;
+    /// let page = reader
+    ///     .iter_records_range_as::<dbase::Record>(4, 10)?
+    ///     .collect::<Result<Vec<_>, _>>()?;
+    /// assert_eq!(page.len(), 2);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn iter_records_range_as<R: ReadableRecord>(
+        &mut self,
+        start: usize,
+        end: usize,
+    ) -> Result<RecordIterator<'_, T, R>, Error> {
+        let end = end.min(self.header.num_records as usize);
+        let start = start.min(end);
+        self.seek(start)?;
+        Ok(RecordIterator::new(ReaderRef::Borrowed(self), start, end))
+    }
+
+    /// Shortcut function to get an iterator over the [Records](struct.Record.html)
+    /// `start..end`, see [iter_records_range_as](#method.iter_records_range_as)
+    pub fn iter_records_range(
+        &mut self,
+        start: usize,
+        end: usize,
+    ) -> Result<RecordIterator<'_, T, Record>, Error> {
+        self.iter_records_range_as::<Record>(start, end)
+    }
+
+    /// Creates an iterator over the [Records](struct.Record.html) for which
+    /// the `predicate` returns true
+    ///
+    /// Errors are always returned, so that they are not silently ignored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), dbase::Error> {
+    /// use dbase::FieldValue;
+    ///
+    /// let mut reader = dbase::Reader::from_path("tests/data/stations.dbf")?;
+    /// let blue_line_stations = reader
+    ///     .filter(|record| {
+    ///         record.get("line") == Some(&FieldValue::Character(Some("blue".to_string())))
+    ///     })
+    ///     .collect::<Result<Vec<_>, _>>()?;
+    /// assert_eq!(blue_line_stations.len(), 4);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn filter<F>(&mut self, predicate: F) -> FilteredRecordIterator<'_, T, Record, F>
+    where
+        F: FnMut(&Record) -> bool,
+    {
+        self.filter_as::<Record, F>(predicate)
+    }
+
+    /// Creates an iterator over the records of the type you want for which
+    /// the `predicate` returns true, see [filter](#method.filter)
+    pub fn filter_as<R, F>(&mut self, predicate: F) -> FilteredRecordIterator<'_, T, R, F>
+    where
+        R: ReadableRecord,
+        F: FnMut(&R) -> bool,
+    {
+        FilteredRecordIterator {
+            records: self.iter_records_as::<R>(),
+            predicate,
+        }
+    }
+
+    /// Size in bytes of a record, deletion flag included
+    fn record_size(&self) -> usize {
+        self.fields_info
             .iter()
             .map(|i| i.field_length as usize)
-            .sum();
-        let encoding = self.inner.encoding();
-        RecordIterator {
-            reader: self,
-            record_type: std::marker::PhantomData,
-            current_record: 0,
-            record_data_buffer: std::io::Cursor::new(vec![0u8; record_size]),
-            field_data_buffer: [0u8; 255],
-            encoding,
+            .sum()
+    }
+
+    /// Divides the records of the file in `n` ranges of roughly the same size
+    ///
+    /// Less than `n` ranges are returned when the file has less than `n` records,
+    /// so that no range is empty.
+    ///
+    /// Each range can then be read independently using [slice](#method.slice),
+    /// for example from different threads.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), dbase::Error> {
+    /// let reader = dbase::Reader::from_path("tests/data/stations.dbf")?;
+    /// let ranges = reader.split(4)?;
+    /// assert_eq!(ranges.len(), 4);
+    /// assert_eq!(ranges[0], dbase::RecordRange { start: 0, end: 2 });
+    /// assert_eq!(ranges[3], dbase::RecordRange { start: 5, end: 6 });
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn split(&self, n: usize) -> Result<Vec<RecordRange>, Error> {
+        if n == 0 {
+            return Err(Error {
+                record_num: 0,
+                field: None,
+                kind: ErrorKind::Message("Cannot split the records in 0 ranges".to_string()),
+            });
         }
+        let num_records = self.header.num_records as usize;
+        let num_ranges = n.min(num_records);
+        let mut ranges = Vec::with_capacity(num_ranges);
+        let mut start = 0;
+        for i in 0..num_ranges {
+            let len = num_records / num_ranges + usize::from(i < num_records % num_ranges);
+            ranges.push(RecordRange {
+                start,
+                end: start + len,
+            });
+            start += len;
+        }
+        Ok(ranges)
     }
 
     /// Shortcut function to get an iterator over the [Records](struct.Record.html) in the file
-    pub fn iter_records(&mut self) -> RecordIterator<T, Record> {
+    ///
+    /// Once all the records were read, use [rewind](#method.rewind)
+    /// to iterate over them again.
+    pub fn iter_records(&mut self) -> RecordIterator<'_, T, Record> {
         self.iter_records_as::<Record>()
     }
 
+    /// Creates an iterator over the bytes of the records, without decoding them
+    ///
+    /// As with [iter_records_as](#method.iter_records_as), the iteration starts
+    /// at the record the reader is at. See [RawRecordIterator::next_raw].
+    pub fn iter_raw_records(&mut self) -> RawRecordIterator<'_, T> {
+        RawRecordIterator::new(self)
+    }
+
     /// Reads all the records of the file inside a `Vec`
     pub fn read_as<R: ReadableRecord>(&mut self) -> Result<Vec<R>, Error> {
         // We don't read the file terminator
@@ -286,6 +1070,29 @@ impl<T: Read + Seek> Reader<T> {
             .collect::<Result<Vec<R>, Error>>()
     }
 
+    /// Reads all the records of the file inside a `Vec`,
+    /// each paired with whether it is marked as deleted
+    ///
+    /// Works with any [ReadableRecord], including the types that implement
+    /// `Deserialize` when the `serde` feature is enabled.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), dbase::Error> {
+    /// let mut reader = dbase::Reader::from_path("tests/data/stations.dbf")?;
+    /// let records = reader.read_as_with_meta::<dbase::Record>()?;
+    /// let num_deleted = records.iter().filter(|(is_deleted, _)| *is_deleted).count();
+    /// assert_eq!(num_deleted, 0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn read_as_with_meta<R: ReadableRecord>(&mut self) -> Result<Vec<(bool, R)>, Error> {
+        self.iter_records_as::<WithDeletionFlag<R>>()
+            .map(|result| result.map(|WithDeletionFlag(is_deleted, record)| (is_deleted, record)))
+            .collect()
+    }
+
     /// Make the `Reader` read the [Records](struct.Record.html)
     ///
     /// # Examples
@@ -304,10 +1111,171 @@ impl<T: Read + Seek> Reader<T> {
         self.iter_records().collect::<Result<Vec<Record>, Error>>()
     }
 
+    /// Reads up to `n` [Records](struct.Record.html) from the current position,
+    /// an empty `Vec` is returned when there are no more records
+    ///
+    /// The current position is the one left by the previous read,
+    /// or the one set by [seek](#method.seek).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), dbase::Error> {
+    /// let mut reader = dbase::Reader::from_path("tests/data/stations.dbf")?;
+    /// assert_eq!(reader.read_chunk(4)?.len(), 4);
+    /// assert_eq!(reader.read_chunk(4)?.len(), 2);
+    /// assert!(reader.read_chunk(4)?.is_empty());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn read_chunk(&mut self, n: usize) -> Result<Vec<Record>, Error> {
+        let start = self.current_record()?;
+        let end = start
+            .saturating_add(n)
+            .min(self.header.num_records as usize);
+        let mut records = Vec::with_capacity(n.min(end.saturating_sub(start)));
+        for record in RecordIterator::new(ReaderRef::Borrowed(self), start, end) {
+            records.push(record?);
+        }
+        Ok(records)
+    }
+
+    /// Creates an iterator over chunks of `n` [Records](struct.Record.html),
+    /// read from the current position with [read_chunk](#method.read_chunk)
+    ///
+    /// The last chunk has fewer records when the number of records is not
+    /// a multiple of `n`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), dbase::Error> {
+    /// let mut reader = dbase::Reader::from_path("tests/data/stations.dbf")?;
+    /// let sizes = reader
+    ///     .iter_chunks(4)
+    ///     .map(|chunk| chunk.map(|records| records.len()))
+    ///     .collect::<Result<Vec<_>, _>>()?;
+    /// assert_eq!(sizes, vec![4, 2]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn iter_chunks(&mut self, n: usize) -> ChunkIterator<'_, T> {
+        assert!(n != 0, "The size of the chunks cannot be 0");
+        // An error to get the position is returned by the first read
+        let start = self.current_record().unwrap_or(0);
+        ChunkIterator {
+            remaining: (self.header.num_records as usize).saturating_sub(start),
+            chunk_size: n,
+            reader: self,
+        }
+    }
+
+    /// Returns true if the Character values of all the records
+    /// are valid in the encoding of the reader
+    fn decodes_character_values(&mut self) -> Result<bool, Error> {
+        self.seek(0)?;
+        let encoding = self.inner.encoding();
+        let character_fields = self
+            .fields_info
+            .iter()
+            .filter(|field| !field.is_hidden())
+            .enumerate()
+            .filter(|(_, field)| field.field_type() == FieldType::Character)
+            .map(|(index, _)| index)
+            .collect::<Vec<_>>();
+        let mut records = self.iter_raw_records();
+        while let Some(record) = records.next_raw() {
+            let record = record?;
+            let decodes = character_fields.iter().all(|&index| {
+                record.field_bytes(index).is_none_or(|bytes| {
+                    encoding
+                        .decode_without_bom_handling_and_without_replacement(bytes)
+                        .is_some()
+                })
+            });
+            if !decodes {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    /// Index of the record at which the source is
+    fn current_record(&mut self) -> Result<usize, Error> {
+        let position = self
+            .source
+            .stream_position()
+            .map_err(|err| Error::io_error(err, 0))? as usize;
+        let offset = position.saturating_sub(self.header.offset_to_first_record as usize);
+        Ok(offset / self.record_size().max(1))
+    }
+
+    /// Seeks back to the first record, so that the records can be iterated again
+    /// without creating a new reader
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), dbase::Error> {
+    /// let mut reader = dbase::Reader::from_path("tests/data/stations.dbf")?;
+    /// let first_read = reader.read()?;
+    /// reader.rewind()?;
+    /// assert_eq!(reader.read()?, first_read);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn rewind(&mut self) -> Result<(), Error> {
+        self.seek(0)
+    }
+
+    /// Skips the next `n` records without reading them
+    ///
+    /// The source is moved forward by `n` records with a single seek, which
+    /// is much faster than reading the records, e.g. to resume the processing
+    /// of a large file from a checkpoint.
+    ///
+    /// Fails if there are fewer than `n` records left according to the header,
+    /// the position is then unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), dbase::Error> {
+    /// let mut reader = dbase::Reader::from_path("tests/data/stations.dbf")?;
+    /// reader.skip_records(4)?;
+    /// let records = reader.read()?;
+    /// assert_eq!(records.len(), 2);
+    /// assert!(reader.skip_records(1).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn skip_records(&mut self, n: usize) -> Result<(), Error> {
+        let current_record = self.current_record()?;
+        let remaining = (self.header.num_records as usize).saturating_sub(current_record);
+        if n > remaining {
+            return Err(Error {
+                record_num: current_record,
+                field: None,
+                kind: ErrorKind::Message(format!(
+                    "Cannot skip {} records, only {} are left",
+                    n, remaining
+                )),
+            });
+        }
+        let offset = (n * self.record_size()) as i64;
+        self.source
+            .seek(SeekFrom::Current(offset))
+            .map_err(|err| Error::io_error(err, current_record))?;
+        Ok(())
+    }
+
     /// Seek to the start of the record at `index`
     pub fn seek(&mut self, index: usize) -> Result<(), Error> {
-        let offset = self.header.offset_to_first_record as usize
-            + (index * self.header.size_of_record as usize);
+        let offset = self.header.offset_to_first_record as usize + (index * self.record_size());
         self.source
             .seek(SeekFrom::Start(offset as u64))
             .map_err(|err| Error::io_error(err, 0))?;
@@ -333,30 +1301,50 @@ impl<T: Read + Seek> Reader<T> {
     ///
     /// ```
     pub fn into_table_info(self) -> TableInfo {
+        let memo_block_size = self.memo_block_size();
         TableInfo {
             header: self.header,
             fields_info: self.fields_info,
+            encoding: self.inner.encoding,
+            memo_block_size,
         }
     }
 }
 
 impl Reader<BufReader<File>> {
-    pub fn _from_path<P: AsRef<Path>>(path: P, label: Option<&str>) -> Result<Self, Error> {
+    pub fn _from_path<P: AsRef<Path>>(
+        path: P,
+        label: Option<&str>,
+        options: ReadingOptions,
+    ) -> Result<Self, Error> {
         let p = path.as_ref().to_owned();
+        let mut warnings = Vec::new();
+        let cpg_encoding = match label {
+            Some(_) => None,
+            None => read_cpg_encoding(&p, options.lenient, &mut warnings)?,
+        };
         let bufreader =
             BufReader::new(File::open(path).map_err(|error| Error::io_error(error, 0))?);
-        let mut reader = if label.is_none() {
-            Reader::new(bufreader)?
-        } else {
-            Reader::new_with_label(bufreader, label.unwrap())?
+        let mut reader = match label.or(cpg_encoding.map(Encoding::name)) {
+            None => Reader::new(bufreader)?,
+            Some(label) => Reader::new_with_label(bufreader, label)?,
         };
-        let at_least_one_field_is_memo = reader
+        reader.inner.options = options;
+        reader.warnings = warnings;
+        reader.path = Some(p);
+        reader.open_memo_file()?;
+        Ok(reader)
+    }
+
+    /// Opens the memo file that goes with the file, if there is a memo field
+    fn open_memo_file(&mut self) -> Result<(), Error> {
+        let at_least_one_field_is_memo = self
             .fields_info
             .iter()
             .any(|f_info| f_info.field_type == FieldType::Memo);
 
-        if at_least_one_field_is_memo {
-            let memo_type = dbg!(reader.header.file_type.supported_memo_type());
+        if let (true, Some(p)) = (at_least_one_field_is_memo, &self.path) {
+            let memo_type = self.header.file_type.supported_memo_type();
             if let Some(mt) = memo_type {
                 let memo_path = match mt {
                     MemoFileType::DbaseMemo | MemoFileType::DbaseMemo4 => p.with_extension("dbt"),
@@ -371,10 +1359,78 @@ impl Reader<BufReader<File>> {
 
                 let memo_reader = MemoReader::new(mt, BufReader::new(memo_file))
                     .map_err(|error| Error::io_error(error, 0))?;
-                reader.memo_reader = Some(memo_reader);
+                self.memo_reader = Some(memo_reader);
             }
         }
-        Ok(reader)
+        Ok(())
+    }
+
+    /// Creates an iterator over the records in the `range`
+    ///
+    /// The iterator does not borrow this reader, it reads from its own handle
+    /// to the file (and memo file), so it can be sent to another thread.
+    ///
+    /// Only readers created from a path (e.g. [from_path](#method.from_path))
+    /// can be sliced.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), dbase::Error> {
+    /// let reader = dbase::Reader::from_path("tests/data/stations.dbf")?;
+    /// let handles = reader
+    ///     .split(2)?
+    ///     .into_iter()
+    ///     .map(|range| {
+    ///         let records = reader.slice::<dbase::Record>(range)?;
+    ///         Ok(std::thread::spawn(move || records.count()))
+    ///     })
+    ///     .collect::<Result<Vec<_>, dbase::Error>>()?;
+    /// let num_records: usize = handles.into_iter().map(|h| h.join().unwrap()).sum();
+    /// assert_eq!(num_records, 6);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn slice<R: ReadableRecord>(
+        &self,
+        range: RecordRange,
+    ) -> Result<RecordIterator<'static, BufReader<File>, R>, Error> {
+        let path = self.path.as_ref().ok_or_else(|| Error {
+            record_num: 0,
+            field: None,
+            kind: ErrorKind::Message("Only readers created from a path can be sliced".to_string()),
+        })?;
+        if range.start > range.end || range.end > self.header.num_records as usize {
+            return Err(Error {
+                record_num: range.start,
+                field: None,
+                kind: ErrorKind::Message(format!(
+                    "The range {}..{} is not within the {} records of the file",
+                    range.start, range.end, self.header.num_records
+                )),
+            });
+        }
+
+        let source = BufReader::new(File::open(path).map_err(|error| Error::io_error(error, 0))?);
+        let mut reader = Reader {
+            source,
+            memo_reader: None,
+            header: self.header,
+            fields_info: self.fields_info.clone(),
+            inner: self.inner.clone(),
+            warnings: Vec::new(),
+            path: Some(path.clone()),
+            progress: Progress::default(),
+            file_size: self.file_size,
+            num_extra_records: self.num_extra_records,
+        };
+        reader.open_memo_file()?;
+        reader.seek(range.start)?;
+        Ok(RecordIterator::new(
+            ReaderRef::Owned(Box::new(reader)),
+            range.start,
+            range.end,
+        ))
     }
 
     /// Creates a new dbase Reader from a path
@@ -387,12 +1443,147 @@ impl Reader<BufReader<File>> {
     /// # Ok(())
     /// # }
     /// ```
+    ///
+    /// If there is a `.cpg` file next to the file (e.g. `myfile.cpg` for `myfile.dbf`),
+    /// as ESRI tools write, the encoding label it contains is used to decode the file
+    /// (labels such as `1252`, `ANSI 1252` or `88591` are understood),
+    /// a label that is not known is an [ErrorKind::InvalidEncoding] error.
+    /// Otherwise, the encoding is given by the code page mark of the header,
+    /// files without one are decoded as UTF-8.
     pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
-        Self::_from_path(path, None)
+        Self::_from_path(path, None, ReadingOptions::default())
     }
 
+    /// Creates a new dbase Reader from a path, decoding the file with the
+    /// encoding of the given label, any `.cpg` file is ignored.
     pub fn from_path_with_label<P: AsRef<Path>>(path: P, label: &str) -> Result<Self, Error> {
-        Self::_from_path(path, Some(label))
+        Self::_from_path(path, Some(label), ReadingOptions::default())
+    }
+
+    /// Creates a new dbase Reader from a path, using the given options
+    ///
+    /// The encoding is chosen as in [from_path](#method.from_path), except that
+    /// in lenient mode an unknown label in the `.cpg` file is recorded as a [ReadWarning]
+    /// and the encoding is then given by the code page mark of the header (or UTF-8).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), dbase::Error> {
+    /// let options = dbase::ReadingOptions::new().lenient(true);
+    /// let reader = dbase::Reader::from_path_with_options("tests/data/cp1252.dbf", options)?;
+    /// assert_eq!(reader.encoding(), encoding_rs::WINDOWS_1252);
+    /// assert!(reader.warnings().is_empty());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_path_with_options<P: AsRef<Path>>(
+        path: P,
+        options: ReadingOptions,
+    ) -> Result<Self, Error> {
+        Self::_from_path(path, None, options)
+    }
+
+    /// Creates a new dbase Reader from a path, detecting the encoding of the file,
+    /// returns the reader along with the detected encoding
+    ///
+    /// The encoding is the first one found among:
+    ///
+    /// 1. the encoding of the code page mark of the header
+    /// 2. the encoding of the label of the `.cpg` file, if there is one
+    ///    (an unknown label is recorded as a [ReadWarning])
+    /// 3. the first of UTF-8, windows-1252 & ISO-8859-2 that decodes
+    ///    the field names and all the Character values of the file without errors
+    ///
+    /// The detection may be wrong for files whose bytes are valid in several encodings:
+    /// every byte is a character in windows-1252, so files that are not valid UTF-8
+    /// are decoded as windows-1252, even if they were written in ISO-8859-2.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), dbase::Error> {
+    /// let (reader, encoding) =
+    ///     dbase::Reader::from_path_with_encoding_detection("tests/data/stations.dbf")?;
+    /// assert_eq!(encoding, encoding_rs::UTF_8);
+    /// assert_eq!(reader.encoding(), encoding);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_path_with_encoding_detection<P: AsRef<Path>>(
+        path: P,
+    ) -> Result<(Self, &'static Encoding), Error> {
+        let path = path.as_ref();
+        let mut file = File::open(path).map_err(|error| Error::io_error(error, 0))?;
+        let header = Header::read_from(&mut file).map_err(|error| Error::io_error(error, 0))?;
+        if let Some(encoding) = encoding_for_code_page_mark(header.code_page_mark) {
+            let reader = Self::from_path_with_label(path, encoding.name())?;
+            return Ok((reader, encoding));
+        }
+
+        let mut warnings = Vec::new();
+        if let Some(encoding) = read_cpg_encoding(path, true, &mut warnings)? {
+            let reader = Self::from_path_with_label(path, encoding.name())?;
+            return Ok((reader, encoding));
+        }
+
+        for encoding in [
+            encoding_rs::UTF_8,
+            encoding_rs::WINDOWS_1252,
+            encoding_rs::ISO_8859_2,
+        ] {
+            let mut reader = match Self::from_path_with_label(path, encoding.name()) {
+                Ok(reader) => reader,
+                // A field name could not be decoded
+                Err(error) if matches!(error.kind, ErrorKind::CannotDecode) => continue,
+                Err(error) => return Err(error),
+            };
+            if reader.decodes_character_values()? {
+                reader.seek(0)?;
+                reader.warnings = warnings;
+                return Ok((reader, encoding));
+            }
+        }
+        Err(Error {
+            record_num: 0,
+            field: None,
+            kind: ErrorKind::CannotDecode,
+        })
+    }
+
+    /// Reads all the records of the file at the given path using multiple threads
+    ///
+    /// The records are split in as many chunks as there are threads in the
+    /// rayon thread pool, each chunk being read by its own `Reader`
+    /// (and thus its own file handles, including the memo file if any).
+    ///
+    /// The records are returned in the same order as they are in the file.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), dbase::Error> {
+    /// let records = dbase::Reader::par_read_as::<dbase::Record, _>("tests/data/stations.dbf")?;
+    /// assert_eq!(records.len(), 6);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "rayon")]
+    pub fn par_read_as<R, P>(path: P) -> Result<Vec<R>, Error>
+    where
+        R: ReadableRecord + Send,
+        P: AsRef<Path>,
+    {
+        use rayon::prelude::*;
+
+        let reader = Reader::from_path(path)?;
+        let chunks = reader
+            .split(rayon::current_num_threads().max(1))?
+            .into_par_iter()
+            .map(|range| reader.slice::<R>(range)?.collect::<Result<Vec<R>, Error>>())
+            .collect::<Result<Vec<Vec<R>>, Error>>()?;
+
+        Ok(chunks.into_iter().flatten().collect())
     }
 }
 
@@ -416,14 +1607,35 @@ pub struct FieldIterator<'a, T: Read + Seek> {
     pub(crate) source: &'a mut std::io::Cursor<Vec<u8>>,
     /// The fields that make the record
     pub(crate) fields_info: std::iter::Peekable<std::slice::Iter<'a, FieldInfo>>,
+    /// All the fields of the record, used to find a field by its name
+    pub(crate) record_fields: &'a [FieldInfo],
     /// The source where the Memo field data is read
     pub(crate) memo_reader: &'a mut Option<MemoReader<T>>,
     /// Buffer where field data is stored
-    field_data_buffer: &'a mut [u8; 255],
+    pub(crate) field_data_buffer: &'a mut [u8; 255],
     pub(crate) encoding: &'static Encoding,
+    pub(crate) options: ReadingOptions,
+    /// Where warnings are recorded in lenient mode
+    pub(crate) warnings: &'a mut Vec<ReadWarning>,
+    /// Bytes of the `_NullFlags` column of the record, empty if there is none
+    pub(crate) null_flags: &'a [u8],
+    /// Index of the record being read
+    pub(crate) record_num: usize,
+    /// Whether the record is marked as deleted
+    pub(crate) is_deleted: bool,
+    /// Names of the fields shared by the [Record]s read, `None` if they cannot be shared
+    pub(crate) record_names: Option<&'a Arc<RecordNames>>,
 }
 
 impl<'a, T: Read + Seek> FieldIterator<'a, T> {
+    /// Returns true if the record being read is marked as deleted
+    ///
+    /// Deleted records are still stored in the file until it is packed,
+    /// and are read like any other record.
+    pub fn is_deleted(&self) -> bool {
+        self.is_deleted
+    }
+
     /// Reads the next field and returns its name and value
     ///
     /// If the "DeletionFlag" field is present in the file it won't be returned
@@ -433,7 +1645,7 @@ impl<'a, T: Read + Seek> FieldIterator<'a, T> {
             .fields_info
             .next()
             .ok_or_else(FieldIOError::end_of_record)?;
-        if field_info.is_deletion_flag() {
+        if field_info.is_hidden() {
             if let Err(e) = self.skip_field(field_info) {
                 Err(e)
             } else {
@@ -459,6 +1671,10 @@ impl<'a, T: Read + Seek> FieldIterator<'a, T> {
     /// Reads the next field and tries to convert into the requested type
     /// using [TryFrom]
     ///
+    /// The conversion is done from the [FieldValue] of the field, where Numeric & Float
+    /// values are floating point numbers. With the `decimal` feature, use
+    /// `read_next_field_as_decimal` to read a `Decimal` parsed from the text of the field.
+    ///
     /// If the "DeletionFlag" field is present in the file it won't be returned
     /// and instead go to the next field.
     pub fn read_next_field_as<F>(&mut self) -> Result<NamedValue<'a, F>, FieldIOError>
@@ -475,6 +1691,76 @@ impl<'a, T: Read + Seek> FieldIterator<'a, T> {
             })
     }
 
+    /// Reads the field with the given name and tries to convert it into the requested type
+    /// using [TryFrom]
+    ///
+    /// The field can be anywhere in the record, before or after the fields already read.
+    /// Reading continues with the field that follows the named one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dbase::{FieldIOError, FieldIterator, ReadableRecord};
+    /// use std::io::{Read, Seek};
+    ///
+    /// struct StationName(String);
+    ///
+    /// impl ReadableRecord for StationName {
+    ///     fn read_using<T>(field_iterator: &mut FieldIterator<T>) -> Result<Self, FieldIOError>
+    ///     where
+    ///         T: Read + Seek,
+    ///     {
+    ///         Ok(Self(field_iterator.read_field_by_name("name")?.value))
+    ///     }
+    /// }
+    /// # fn main() -> Result<(), dbase::Error> {
+    /// let mut reader = dbase::Reader::from_path("tests/data/stations.dbf")?;
+    /// let names = reader.read_as::<StationName>()?;
+    /// assert_eq!(names[0].0, "Van Dorn Street");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn read_field_by_name<F>(&mut self, name: &str) -> Result<NamedValue<'a, F>, FieldIOError>
+    where
+        F: TryFrom<FieldValue, Error = FieldConversionError>,
+    {
+        let (field_info, value) = self.read_field_value_by_name(name)?;
+        match F::try_from(value) {
+            Ok(v) => Ok(NamedValue {
+                name: field_info.name(),
+                value: v,
+            }),
+            Err(e) => Err(FieldIOError::new(e.into(), Some(field_info.to_owned()))),
+        }
+    }
+
+    /// Reads the value of the field named `name`, see [read_field_by_name](#method.read_field_by_name)
+    pub(crate) fn read_field_value_by_name(
+        &mut self,
+        name: &str,
+    ) -> Result<(&'a FieldInfo, FieldValue), FieldIOError> {
+        let fields = self.record_fields;
+        let index = fields
+            .iter()
+            .position(|field_info| !field_info.is_hidden() && field_info.name() == name)
+            .ok_or_else(|| {
+                FieldIOError::new(
+                    ErrorKind::Message(format!("Could not find field named '{}'", name)),
+                    None,
+                )
+            })?;
+        let field_info = &fields[index];
+        let offset = fields[..index]
+            .iter()
+            .map(|field_info| u64::from(field_info.field_length))
+            .sum();
+        self.source.set_position(offset);
+        self.fields_info = fields[index + 1..].iter().peekable();
+
+        let value = self.read_field(field_info)?;
+        Ok((field_info, value))
+    }
+
     /// Skips the next field of the record, useful if the field does not interest you
     /// but the ones after do.
     ///
@@ -506,7 +1792,7 @@ impl<'a, T: Read + Seek> FieldIterator<'a, T> {
             .fields_info
             .next()
             .ok_or(FieldIOError::end_of_record())?;
-        if field_info.is_deletion_flag() {
+        if field_info.is_hidden() {
             self.skip_field(field_info)?;
             self.read_next_field_raw()
         } else {
@@ -524,7 +1810,7 @@ impl<'a, T: Read + Seek> FieldIterator<'a, T> {
             field: None,
             kind: ErrorKind::EndOfRecord,
         })?;
-        if field_info.is_deletion_flag() {
+        if field_info.is_hidden() {
             self.skip_field(field_info)?;
             self.fields_info.next().unwrap();
             field_info = self
@@ -532,7 +1818,10 @@ impl<'a, T: Read + Seek> FieldIterator<'a, T> {
                 .peek()
                 .ok_or(FieldIOError::end_of_record())?;
         }
+        // The field will be read again, so warnings must not be recorded twice
+        let num_warnings = self.warnings.len();
         let value = self.read_field(field_info)?;
+        self.warnings.truncate(num_warnings);
         self.source
             .seek(SeekFrom::Current(-i64::from(field_info.field_length)))
             .map_err(|error| {
@@ -546,7 +1835,7 @@ impl<'a, T: Read + Seek> FieldIterator<'a, T> {
     }
 
     /// Advance the source to skip the field
-    fn skip_field(&mut self, field_info: &FieldInfo) -> Result<(), FieldIOError> {
+    pub(crate) fn skip_field(&mut self, field_info: &FieldInfo) -> Result<(), FieldIOError> {
         self.source
             .seek(SeekFrom::Current(i64::from(field_info.field_length)))
             .map_err(|error| {
@@ -557,21 +1846,90 @@ impl<'a, T: Read + Seek> FieldIterator<'a, T> {
 
     /// read the next field using the given info
     fn read_field(&mut self, field_info: &'a FieldInfo) -> Result<FieldValue, FieldIOError> {
+        if self.is_null(field_info) {
+            let null_value = match field_info.field_type {
+                FieldType::Character => Some(FieldValue::Character(None)),
+                FieldType::Numeric => Some(FieldValue::Numeric(None)),
+                FieldType::Float => Some(FieldValue::Float(None)),
+                FieldType::Logical => Some(FieldValue::Logical(None)),
+                FieldType::Date => Some(FieldValue::Date(None)),
+                _ => None,
+            };
+            if let Some(value) = null_value {
+                self.skip_field(field_info)?;
+                return Ok(value);
+            }
+        }
         let field_data_buffer = &mut self.field_data_buffer[..field_info.length() as usize];
-        self.source.read_exact(field_data_buffer).unwrap();
+        self.source
+            .read_exact(field_data_buffer)
+            .map_err(|error| FieldIOError::new(error.into(), Some(field_info.to_owned())))?;
         match FieldValue::read_from(
             field_data_buffer,
             self.memo_reader,
             field_info,
             self.encoding,
+            self.options,
         ) {
             Ok(value) => Ok(value),
+            Err(kind) if self.recover_from_parse_error(field_info, &kind) => {
+                Ok(match field_info.field_type {
+                    FieldType::Float => FieldValue::Float(None),
+                    FieldType::Date => FieldValue::Date(None),
+                    _ => FieldValue::Numeric(None),
+                })
+            }
             Err(kind) => Err(FieldIOError {
                 field: Some(field_info.clone()),
                 kind,
             }),
         }
     }
+
+    /// Returns true if the field is marked as null in the `_NullFlags` column
+    ///
+    /// Types that have no `None` value (e.g. Integer) are read as stored.
+    pub(crate) fn is_null(&self, field_info: &FieldInfo) -> bool {
+        field_info
+            .null_flag_bit
+            .is_some_and(|bit| null_flag_is_set(self.null_flags, bit))
+    }
+
+    /// Returns whether the error that happened while parsing the value of the field,
+    /// which is still in the `field_data_buffer`, can be ignored.
+    ///
+    /// That is the case in lenient mode, for a value of a Numeric, Float or Date field
+    /// that could not be parsed, a warning is then recorded.
+    pub(crate) fn recover_from_parse_error(
+        &mut self,
+        field_info: &FieldInfo,
+        kind: &ErrorKind,
+    ) -> bool {
+        let is_parse_error = match kind {
+            ErrorKind::ParseFloatError(_)
+            | ErrorKind::ParseIntError(_)
+            | ErrorKind::InvalidDate(_) => true,
+            #[cfg(feature = "decimal")]
+            ErrorKind::ParseDecimalError(_) => true,
+            _ => false,
+        };
+        let can_recover = self.options.lenient
+            && is_parse_error
+            && matches!(
+                field_info.field_type,
+                FieldType::Numeric | FieldType::Float | FieldType::Date
+            );
+        if can_recover {
+            self.warnings.push(ReadWarning {
+                record_num: self.record_num,
+                field: Some(field_info.clone()),
+                raw_bytes: self.field_data_buffer[..field_info.length() as usize].to_vec(),
+                message: format!("{:?}", kind),
+                kind: ReadWarningKind::InvalidValue,
+            });
+        }
+        can_recover
+    }
 }
 
 impl<'a, T: Read + Seek> Iterator for FieldIterator<'a, T> {
@@ -590,46 +1948,757 @@ impl<'a, T: Read + Seek> Iterator for FieldIterator<'a, T> {
 
 impl<'a, T: Read + Seek> FusedIterator for FieldIterator<'a, T> {}
 
+/// Error of the reading of the header, [ErrorKind::InvalidHeader] if it is truncated
+fn header_error(error: std::io::Error) -> Error {
+    match error.kind() {
+        std::io::ErrorKind::UnexpectedEof => Error {
+            record_num: 0,
+            field: None,
+            kind: ErrorKind::InvalidHeader,
+        },
+        _ => Error::io_error(error, 0),
+    }
+}
+
+/// Returns the size of the file and the number of records after the ones
+/// declared in the header, bytes after an end of file marker (`0x1A`) are not records
+fn measure_records_end<T: Read + Seek>(
+    source: &mut T,
+    header: &Header,
+    record_size: usize,
+) -> std::io::Result<(u64, u32)> {
+    let file_size = source.seek(SeekFrom::End(0))?;
+    let records_end = u64::from(header.offset_to_first_record)
+        + u64::from(header.num_records) * record_size as u64;
+    if record_size == 0 || file_size <= records_end {
+        return Ok((file_size, 0));
+    }
+    source.seek(SeekFrom::Start(records_end))?;
+    let mut first_byte = [0u8; 1];
+    source.read_exact(&mut first_byte)?;
+    if first_byte[0] == FILE_TERMINATOR {
+        return Ok((file_size, 0));
+    }
+    let num_extra_records = (file_size - records_end) / record_size as u64;
+    Ok((
+        file_size,
+        u32::try_from(num_extra_records).unwrap_or(u32::MAX),
+    ))
+}
+
+/// Reads the encoding of the `.cpg` file that goes with the file at `path`, if any
+///
+/// Returns `None` if there is no `.cpg` file. If its label is unknown, an
+/// [ErrorKind::InvalidEncoding] error is returned, or in lenient mode `None`
+/// with a warning added to `warnings`.
+fn read_cpg_encoding(
+    path: &Path,
+    lenient: bool,
+    warnings: &mut Vec<ReadWarning>,
+) -> Result<Option<&'static Encoding>, Error> {
+    let cpg_path = path.with_extension("cpg");
+    let content = match std::fs::read(&cpg_path) {
+        Ok(content) => content,
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(error) => return Err(Error::io_error(error, 0)),
+    };
+    let label = String::from_utf8_lossy(&content);
+    let label = label.trim_start_matches('\u{feff}').trim();
+    if let Some(encoding) = encoding_for_cpg_label(label) {
+        return Ok(Some(encoding));
+    }
+    if !lenient {
+        return Err(Error {
+            record_num: 0,
+            field: None,
+            kind: ErrorKind::InvalidEncoding,
+        });
+    }
+    warnings.push(ReadWarning {
+        record_num: 0,
+        field: None,
+        message: format!(
+            "Unknown encoding label '{}' in {}, the file is decoded with the encoding \
+             of the code page mark of the header, or as UTF-8 if it has none",
+            label,
+            cpg_path.display()
+        ),
+        raw_bytes: content,
+        kind: ReadWarningKind::UnknownEncoding,
+    });
+    Ok(None)
+}
+
+/// Returns the encoding of the label of a `.cpg` file
+///
+/// Besides the labels known by encoding_rs, ESRI tools write the number
+/// of the code page, optionally prefixed by `ANSI` or `OEM` (e.g. `1252`, `ANSI 1252`,
+/// `OEM 866`), and `8859N` for `ISO-8859-N` (e.g. `88591`).
+fn encoding_for_cpg_label(label: &str) -> Option<&'static Encoding> {
+    if let Some(encoding) = Encoding::for_label(label.as_bytes()) {
+        return Some(encoding);
+    }
+    let label = label.to_ascii_uppercase();
+    let number = label
+        .strip_prefix("ANSI")
+        .or_else(|| label.strip_prefix("OEM"))
+        .unwrap_or(&label)
+        .trim();
+    if let Some(part) = number
+        .strip_prefix("8859")
+        .or_else(|| number.strip_prefix("2859"))
+    {
+        return Encoding::for_label(format!("iso-8859-{}", part).as_bytes());
+    }
+    let encoding = match number {
+        "65001" => encoding_rs::UTF_8,
+        "866" => encoding_rs::IBM866,
+        "874" => encoding_rs::WINDOWS_874,
+        "932" => encoding_rs::SHIFT_JIS,
+        "936" => encoding_rs::GBK,
+        "949" => encoding_rs::EUC_KR,
+        "950" => encoding_rs::BIG5,
+        "20866" => encoding_rs::KOI8_R,
+        "21866" => encoding_rs::KOI8_U,
+        "54936" => encoding_rs::GB18030,
+        // 1250 to 1258
+        _ => return Encoding::for_label(format!("windows-{}", number).as_bytes()),
+    };
+    Some(encoding)
+}
+
+/// A record read along with its deletion flag, see [Reader::read_as_with_meta]
+pub(crate) struct WithDeletionFlag<R>(pub(crate) bool, pub(crate) R);
+
+impl<R: ReadableRecord> ReadableRecord for WithDeletionFlag<R> {
+    fn read_using<T>(field_iterator: &mut FieldIterator<T>) -> Result<Self, FieldIOError>
+    where
+        T: Read + Seek,
+    {
+        let is_deleted = field_iterator.is_deleted();
+        Ok(Self(is_deleted, R::read_using(field_iterator)?))
+    }
+}
+
+/// Range of records, `start` included and `end` excluded
+///
+/// See [Reader::split] & [Reader::slice]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct RecordRange {
+    /// Index of the first record of the range
+    pub start: usize,
+    /// Index of the record after the last record of the range
+    pub end: usize,
+}
+
+impl RecordRange {
+    /// Returns the number of records in the range
+    pub fn len(&self) -> usize {
+        self.end.saturating_sub(self.start)
+    }
+
+    /// Returns true if the range has no records
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// The reader a [RecordIterator] reads from,
+/// either borrowed or owned when the iterator comes from [Reader::slice]
+enum ReaderRef<'a, T: Read + Seek> {
+    Borrowed(&'a mut Reader<T>),
+    Owned(Box<Reader<T>>),
+}
+
+impl<'a, T: Read + Seek> std::ops::Deref for ReaderRef<'a, T> {
+    type Target = Reader<T>;
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            ReaderRef::Borrowed(reader) => reader,
+            ReaderRef::Owned(reader) => reader,
+        }
+    }
+}
+
+impl<'a, T: Read + Seek> std::ops::DerefMut for ReaderRef<'a, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        match self {
+            ReaderRef::Borrowed(reader) => reader,
+            ReaderRef::Owned(reader) => reader,
+        }
+    }
+}
+
 /// Iterator over records contained in the dBase
 pub struct RecordIterator<'a, T: Read + Seek, R: ReadableRecord> {
-    reader: &'a mut Reader<T>,
+    reader: ReaderRef<'a, T>,
     record_type: std::marker::PhantomData<R>,
-    current_record: u32,
+    /// Index of the record at which the iteration started
+    start_record: usize,
+    current_record: usize,
+    /// Index of the record at which the iteration stops (excluded)
+    end_record: usize,
     record_data_buffer: std::io::Cursor<Vec<u8>>,
     /// Non-Memo field length is stored on a u8,
     /// so fields cannot exceed 255 bytes
     field_data_buffer: [u8; 255],
     encoding: &'static Encoding,
+    /// Buffer reused by [next_borrowed](#method.next_borrowed)
+    scratch: Vec<(String, FieldValue)>,
+    /// Position of the `_NullFlags` column in the record, if there is one
+    null_flags_range: Option<std::ops::Range<usize>>,
+    /// Bytes of the `_NullFlags` column of the current record
+    null_flags: Vec<u8>,
+    /// Whether the number of records of the file was compared to the one of the header
+    end_checked: bool,
+    /// Names of the fields shared by the [Record]s read
+    record_names: Option<Arc<RecordNames>>,
+}
+
+impl<'a, T: Read + Seek, R: ReadableRecord> RecordIterator<'a, T, R> {
+    /// Creates an iterator over the records `start..end`,
+    /// the source of the reader must be at the start of the record `start`
+    fn new(reader: ReaderRef<'a, T>, start: usize, end: usize) -> Self {
+        let record_size = reader.record_size();
+        let encoding = reader.inner.encoding();
+        let mut offset = 0;
+        let mut null_flags_range = None;
+        for field_info in &reader.fields_info {
+            let length = field_info.length() as usize;
+            if field_info.is_null_flags() {
+                null_flags_range = Some(offset..offset + length);
+            }
+            offset += length;
+        }
+        let record_names = RecordNames::of_fields(&reader.fields_info);
+        Self {
+            reader,
+            record_type: std::marker::PhantomData,
+            start_record: start,
+            current_record: start,
+            end_record: end,
+            record_data_buffer: std::io::Cursor::new(vec![0u8; record_size]),
+            field_data_buffer: [0u8; 255],
+            encoding,
+            scratch: Vec::new(),
+            null_flags_range,
+            null_flags: Vec::new(),
+            end_checked: false,
+            record_names,
+        }
+    }
+
+    /// Returns the reader the records are read from
+    ///
+    /// Useful to get the [warnings](struct.Reader.html#method.warnings)
+    /// of an iterator created with [Reader::slice].
+    pub fn reader(&self) -> &Reader<T> {
+        &self.reader
+    }
+
+    /// Reads the next record using the `read` function,
+    /// returns None if there are no more records
+    fn read_next_with<V, F>(&mut self, read: F) -> Option<Result<V, FieldIOError>>
+    where
+        F: FnOnce(&mut FieldIterator<'_, T>) -> Result<V, FieldIOError>,
+    {
+        if self.current_record >= self.end_record {
+            return self.check_no_records_remain().map(Err);
+        }
+        let reader = &mut *self.reader;
+        if reader
+            .source
+            .read_exact(self.record_data_buffer.get_mut())
+            .is_err()
+        {
+            self.stop_before_end();
+            return None;
+        }
+        // The file has fewer records than its header says
+        if self.record_data_buffer.get_ref().first() == Some(&FILE_TERMINATOR) {
+            self.stop_before_end();
+            return None;
+        }
+        let reader = &mut *self.reader;
+        self.record_data_buffer.set_position(0);
+        if let Some(range) = self.null_flags_range.clone() {
+            self.null_flags.clear();
+            self.null_flags
+                .extend_from_slice(&self.record_data_buffer.get_ref()[range]);
+        }
+        let is_deleted = self.record_data_buffer.get_ref().first() == Some(&DELETED_RECORD_FLAG);
+
+        let mut iter = FieldIterator {
+            source: &mut self.record_data_buffer,
+            fields_info: reader.fields_info.iter().peekable(),
+            record_fields: &reader.fields_info,
+            memo_reader: &mut reader.memo_reader,
+            field_data_buffer: &mut self.field_data_buffer,
+            encoding: self.encoding,
+            options: reader.inner.options,
+            warnings: &mut reader.warnings,
+            null_flags: &self.null_flags,
+            record_num: self.current_record,
+            is_deleted,
+            record_names: self.record_names.as_ref(),
+        };
+
+        let value = read(&mut iter).and_then(|value| iter.skip_remaining_fields().and(Ok(value)));
+
+        self.current_record += 1;
+        reader.progress.report(
+            self.current_record - self.start_record,
+            self.end_record - self.start_record,
+        );
+        Some(value)
+    }
+
+    /// Returns true if the iteration goes up to the last record declared in the header
+    fn ends_at_last_record(&self) -> bool {
+        self.end_record == self.reader.header.num_records as usize
+    }
+
+    /// Stops the iteration as there are no more records before the end of the iteration
+    ///
+    /// In lenient mode, warnings are recorded as the file does not have all the records
+    /// declared in its header.
+    fn stop_before_end(&mut self) {
+        let num_records_read = self.current_record as u32;
+        self.current_record = self.end_record;
+        if self.end_checked || !self.ends_at_last_record() {
+            return;
+        }
+        self.end_checked = true;
+        let reader = &mut *self.reader;
+        if !reader.inner.options.lenient {
+            return;
+        }
+
+        let declared = reader.header.num_records;
+        reader.warnings.push(ReadWarning {
+            record_num: num_records_read as usize,
+            field: None,
+            raw_bytes: Vec::new(),
+            message: format!(
+                "The header declares {} records, the file has {}",
+                declared, num_records_read
+            ),
+            kind: ReadWarningKind::RecordCountMismatch {
+                declared,
+                actual: num_records_read,
+            },
+        });
+        let expected_size = u64::from(reader.header.offset_to_first_record)
+            + u64::from(declared) * reader.record_size() as u64;
+        let actual_size = reader.file_size;
+        if actual_size < expected_size {
+            reader.warnings.push(ReadWarning {
+                record_num: num_records_read as usize,
+                field: None,
+                raw_bytes: Vec::new(),
+                message: format!(
+                    "The file has {} bytes, its header & records need {} bytes",
+                    actual_size, expected_size
+                ),
+                kind: ReadWarningKind::TruncatedFile {
+                    expected_size,
+                    actual_size,
+                },
+            });
+        }
+    }
+
+    /// Checks, once the last record declared in the header is read,
+    /// that there are no more records before the end of file (marker)
+    ///
+    /// The records after the declared ones are counted when the reader is created,
+    /// if there are some an error is returned, or a warning is recorded in lenient mode.
+    fn check_no_records_remain(&mut self) -> Option<FieldIOError> {
+        if self.end_checked || !self.ends_at_last_record() {
+            return None;
+        }
+        self.end_checked = true;
+        let reader = &mut *self.reader;
+        if reader.num_extra_records == 0 {
+            return None;
+        }
+
+        let declared = reader.header.num_records;
+        let actual = declared.saturating_add(reader.num_extra_records);
+        if !reader.inner.options.lenient {
+            return Some(FieldIOError::new(
+                ErrorKind::RecordCountMismatch { declared, actual },
+                None,
+            ));
+        }
+        reader.warnings.push(ReadWarning {
+            record_num: self.current_record,
+            field: None,
+            raw_bytes: Vec::new(),
+            message: format!(
+                "The header declares {} records, the file has {}",
+                declared, actual
+            ),
+            kind: ReadWarningKind::RecordCountMismatch { declared, actual },
+        });
+        None
+    }
+
+    /// Reads the value of the field named `field_name` of the next record,
+    /// or `None` if the record is marked as deleted
+    pub(crate) fn next_field_value(
+        &mut self,
+        field_name: &str,
+    ) -> Option<Result<Option<FieldValue>, Error>> {
+        let record_num = self.current_record;
+        self.read_next_with(|field_iterator| {
+            if field_iterator.is_deleted() {
+                return Ok(None);
+            }
+            field_iterator
+                .read_field_value_by_name(field_name)
+                .map(|(_, value)| Some(value))
+        })
+        .map(|result| result.map_err(|error| Error::new(error, record_num)))
+    }
+
+    /// Reads the next record into a buffer owned by the iterator
+    /// and returns a view over its fields.
+    ///
+    /// Unlike [next](#method.next), the buffer is reused from one record
+    /// to the next, which avoids allocating a new `Record` each time.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut reader = dbase::Reader::from_path("tests/data/stations.dbf")?;
+    /// let mut records = reader.iter_records();
+    /// let mut num_blue_line_stations = 0;
+    /// while let Some(view) = records.next_borrowed() {
+    ///     let view = view?;
+    ///     if let Some(dbase::FieldValue::Character(Some(line))) = view.get("line") {
+    ///         if line == "blue" {
+    ///             num_blue_line_stations += 1;
+    ///         }
+    ///     }
+    /// }
+    /// assert_eq!(num_blue_line_stations, 4);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn next_borrowed(&mut self) -> Option<Result<RecordView<'_>, FieldIOError>> {
+        let mut scratch = std::mem::take(&mut self.scratch);
+        let result = self.read_next_with(|field_iterator| {
+            let mut num_fields = 0;
+            for result in field_iterator {
+                let NamedValue { name, value } = result?;
+                // Reuse the allocated names
+                if let Some((scratch_name, scratch_value)) = scratch.get_mut(num_fields) {
+                    scratch_name.clear();
+                    scratch_name.push_str(name);
+                    *scratch_value = value;
+                } else {
+                    scratch.push((name.to_owned(), value));
+                }
+                num_fields += 1;
+            }
+            scratch.truncate(num_fields);
+            Ok(())
+        });
+        self.scratch = scratch;
+
+        match result? {
+            Ok(()) => Some(Ok(RecordView {
+                fields: &self.scratch,
+            })),
+            Err(error) => Some(Err(error)),
+        }
+    }
 }
 
 impl<'a, T: Read + Seek, R: ReadableRecord> Iterator for RecordIterator<'a, T, R> {
     type Item = Result<R, Error>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.current_record >= self.reader.header.num_records {
-            None
-        } else {
-            self.reader
-                .source
-                .read_exact(self.record_data_buffer.get_mut())
-                .ok()?;
-            self.record_data_buffer.set_position(0);
-
-            let mut iter = FieldIterator {
-                source: &mut self.record_data_buffer,
-                fields_info: self.reader.fields_info.iter().peekable(),
-                memo_reader: &mut self.reader.memo_reader,
-                field_data_buffer: &mut self.field_data_buffer,
-                encoding: self.encoding,
-            };
+        let record_num = self.current_record;
+        self.read_next_with(R::read_using)
+            .map(|result| result.map_err(|error| Error::new(error, record_num)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.end_record.saturating_sub(self.current_record);
+        (remaining, Some(remaining))
+    }
+
+    /// Seeks over the `n` records before the returned one instead of reading them,
+    /// which also makes [Iterator::skip] fast
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let n = n.min(self.end_record.saturating_sub(self.current_record));
+        if n > 0 {
+            let offset = (n * self.reader.record_size()) as i64;
+            if let Err(error) = self.reader.source.seek(SeekFrom::Current(offset)) {
+                return Some(Err(Error::io_error(error, self.current_record)));
+            }
+            self.current_record += n;
+        }
+        self.next()
+    }
+}
+
+/// The length is the number of records left to read, according to the header,
+/// which makes it easy to show the progress of the reading (e.g. with `indicatif`).
+///
+/// The length is only wrong for files that do not have as many records as their
+/// header declares: the iteration stops early when records are missing, and when
+/// there are more, outside of lenient mode, an [ErrorKind::RecordCountMismatch] error
+/// is yielded after the last declared record, which the length does not count.
+///
+/// # Example
+///
+/// ```
+/// # fn main() -> Result<(), dbase::Error> {
+/// let mut reader = dbase::Reader::from_path("tests/data/stations.dbf")?;
+/// let mut records = reader.iter_records();
+/// assert_eq!(records.len(), 6);
+/// records.next().unwrap()?;
+/// assert_eq!(records.len(), 5);
+/// # Ok(())
+/// # }
+/// ```
+impl<'a, T: Read + Seek, R: ReadableRecord> ExactSizeIterator for RecordIterator<'a, T, R> {}
+
+/// Iterator over the records for which a predicate returns true,
+/// created by [Reader::filter] or [Reader::filter_as]
+pub struct FilteredRecordIterator<'a, T: Read + Seek, R: ReadableRecord, F> {
+    records: RecordIterator<'a, T, R>,
+    predicate: F,
+}
+
+impl<'a, T, R, F> Iterator for FilteredRecordIterator<'a, T, R, F>
+where
+    T: Read + Seek,
+    R: ReadableRecord,
+    F: FnMut(&R) -> bool,
+{
+    type Item = Result<R, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let predicate = &mut self.predicate;
+        self.records.find(|result| match result {
+            Ok(record) => predicate(record),
+            Err(_) => true,
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // The predicate may reject all the remaining records
+        let remaining = self
+            .records
+            .end_record
+            .saturating_sub(self.records.current_record);
+        (0, Some(remaining))
+    }
+}
+
+/// Iterator over chunks of records, created by [Reader::iter_chunks]
+pub struct ChunkIterator<'a, T: Read + Seek> {
+    reader: &'a mut Reader<T>,
+    chunk_size: usize,
+    /// Number of records left to read, according to the header
+    remaining: usize,
+}
+
+impl<'a, T: Read + Seek> Iterator for ChunkIterator<'a, T> {
+    type Item = Result<Vec<Record>, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        match self.reader.read_chunk(self.chunk_size) {
+            Ok(records) if records.is_empty() => {
+                self.remaining = 0;
+                None
+            }
+            Ok(records) => {
+                self.remaining = self.remaining.saturating_sub(records.len());
+                Some(Ok(records))
+            }
+            Err(error) => {
+                // No more records can be read
+                self.remaining = 0;
+                Some(Err(error))
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let num_chunks = self.remaining.div_ceil(self.chunk_size);
+        (num_chunks, Some(num_chunks))
+    }
+}
+
+/// View over the fields of a record, returned by [RecordIterator::next_borrowed]
+#[derive(Debug, Clone, Copy)]
+pub struct RecordView<'a> {
+    fields: &'a [(String, FieldValue)],
+}
+
+impl<'a> RecordView<'a> {
+    /// Returns the [FieldValue](enum.FieldValue.html) for the given field name
+    pub fn get(&self, field_name: &str) -> Option<&'a FieldValue> {
+        self.fields
+            .iter()
+            .find(|(name, _)| name == field_name)
+            .map(|(_, value)| value)
+    }
 
-            let record = R::read_using(&mut iter)
-                .and_then(|record| iter.skip_remaining_fields().and(Ok(record)))
-                .map_err(|error| Error::new(error, self.current_record as usize));
+    /// Returns an iterator over the names and values of the fields,
+    /// in the order they are in the file
+    pub fn iter(&self) -> impl Iterator<Item = (&'a str, &'a FieldValue)> {
+        self.fields
+            .iter()
+            .map(|(name, value)| (name.as_str(), value))
+    }
+
+    /// Returns the number of fields in the record
+    pub fn len(&self) -> usize {
+        self.fields.len()
+    }
+
+    /// Returns true if the record has no fields
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+}
+
+impl<'a> IntoIterator for RecordView<'a> {
+    type Item = (&'a str, &'a FieldValue);
+    type IntoIter = std::iter::Map<
+        std::slice::Iter<'a, (String, FieldValue)>,
+        fn(&'a (String, FieldValue)) -> (&'a str, &'a FieldValue),
+    >;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.fields
+            .iter()
+            .map(|(name, value)| (name.as_str(), value))
+    }
+}
+
+/// Iterator over the bytes of the records, created by [Reader::iter_raw_records]
+pub struct RawRecordIterator<'a, T: Read + Seek> {
+    reader: &'a mut Reader<T>,
+    current_record: usize,
+    end_record: usize,
+    /// Bytes of the current record, reused from one record to the next
+    record_data_buffer: Vec<u8>,
+    /// Position of each field in the record, the deletion flag
+    /// & the `_NullFlags` column excluded
+    field_ranges: Vec<std::ops::Range<usize>>,
+}
 
-            self.current_record += 1;
-            Some(record)
+impl<'a, T: Read + Seek> RawRecordIterator<'a, T> {
+    /// Creates an iterator over the records that follow the current position of the reader
+    fn new(reader: &'a mut Reader<T>) -> Self {
+        let end_record = reader.header.num_records as usize;
+        let start_record = reader.current_record().unwrap_or(0).min(end_record);
+        let mut offset = 0;
+        let mut field_ranges = Vec::with_capacity(reader.fields_info.len());
+        for field_info in &reader.fields_info {
+            let length = field_info.length() as usize;
+            if !field_info.is_hidden() {
+                field_ranges.push(offset..offset + length);
+            }
+            offset += length;
+        }
+        Self {
+            current_record: start_record,
+            end_record,
+            record_data_buffer: vec![0u8; reader.record_size()],
+            field_ranges,
+            reader,
+        }
+    }
+
+    /// Reads the next record into a buffer owned by the iterator
+    /// and returns a view over the bytes of its fields,
+    /// returns None if there are no more records.
+    ///
+    /// The bytes are not decoded, they are as stored in the file
+    /// (e.g. padded with spaces, or the index of the block for Memo fields).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), dbase::Error> {
+    /// let mut reader = dbase::Reader::from_path("tests/data/stations.dbf")?;
+    /// let mut records = reader.iter_raw_records();
+    /// let mut num_blue_line_stations = 0;
+    /// while let Some(record) = records.next_raw() {
+    ///     // The "line" field is the 4th one, its value is padded with spaces
+    ///     if record?.field_bytes(3).map(<[u8]>::trim_ascii_end) == Some(b"blue") {
+    ///         num_blue_line_stations += 1;
+    ///     }
+    /// }
+    /// assert_eq!(num_blue_line_stations, 4);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn next_raw(&mut self) -> Option<Result<RawRecordView<'_>, Error>> {
+        if self.current_record >= self.end_record {
+            return None;
+        }
+        let record_num = self.current_record;
+        self.current_record += 1;
+        if let Err(error) = self.reader.source.read_exact(&mut self.record_data_buffer) {
+            // No more records can be read
+            self.current_record = self.end_record;
+            return Some(Err(Error::io_error(error, record_num)));
+        }
+        // The file has fewer records than its header says
+        if self.record_data_buffer.first() == Some(&FILE_TERMINATOR) {
+            self.current_record = self.end_record;
+            return None;
         }
+        Some(Ok(RawRecordView {
+            bytes: &self.record_data_buffer,
+            field_ranges: &self.field_ranges,
+        }))
+    }
+}
+
+/// View over the bytes of a record, returned by [RawRecordIterator::next_raw]
+#[derive(Debug, Clone, Copy)]
+pub struct RawRecordView<'a> {
+    bytes: &'a [u8],
+    field_ranges: &'a [std::ops::Range<usize>],
+}
+
+impl<'a> RawRecordView<'a> {
+    /// Returns the bytes of the field at `index`, in the order of the fields of the file
+    /// (the same as [Reader::fields] without the deletion flag & `_NullFlags` column)
+    pub fn field_bytes(&self, index: usize) -> Option<&'a [u8]> {
+        self.field_ranges
+            .get(index)
+            .map(|range| &self.bytes[range.clone()])
+    }
+
+    /// Returns true if the record is marked as deleted
+    pub fn is_deleted(&self) -> bool {
+        self.bytes.first() == Some(&DELETED_RECORD_FLAG)
+    }
+
+    /// Returns the number of fields in the record
+    pub fn len(&self) -> usize {
+        self.field_ranges.len()
+    }
+
+    /// Returns true if the record has no fields
+    pub fn is_empty(&self) -> bool {
+        self.field_ranges.is_empty()
     }
 }
 
@@ -642,7 +2711,7 @@ impl<'a, T: Read + Seek, R: ReadableRecord> Iterator for RecordIterator<'a, T, R
 /// assert_eq!(records.len(), 1);
 /// ```
 pub fn read<P: AsRef<Path>>(path: P) -> Result<Vec<Record>, Error> {
-    let mut reader = Reader::from_path(path).unwrap();
+    let mut reader = Reader::from_path(path)?;
     reader.read()
 }
 
@@ -653,22 +2722,88 @@ pub fn read<P: AsRef<Path>>(path: P) -> Result<Vec<Record>, Error> {
 /// assert_eq!(records.len(), 4);
 /// ```
 pub fn read_with_label<P: AsRef<Path>>(path: P, label: &str) -> Result<Vec<Record>, Error> {
-    let mut reader = Reader::from_path_with_label(path, label).unwrap();
+    let mut reader = Reader::from_path_with_label(path, label)?;
     reader.read()
 }
 
+/// One liner to read the content of a .dbf file along with its [TableInfo],
+/// which allows to write the records back with the same fields
+///
+/// # Example
+///
+/// ```
+/// # fn main() -> Result<(), dbase::Error> {
+/// let (records, table_info) = dbase::read_with_info("tests/data/line.dbf")?;
+/// assert_eq!(records.len(), table_info.num_records() as usize);
+///
+/// let mut dst = std::io::Cursor::new(Vec::<u8>::new());
+/// dbase::TableWriterBuilder::from_table_info(table_info)
+///     .build_with_dest(&mut dst)
+///     .write_records(&records)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn read_with_info<P: AsRef<Path>>(path: P) -> Result<(Vec<Record>, TableInfo), Error> {
+    let mut reader = Reader::from_path(path)?;
+    let records = reader.read()?;
+    Ok((records, reader.into_table_info()))
+}
+
+/// Same as [read_with_info], decoding the file with the encoding of the given label
+pub fn read_with_label_and_info<P: AsRef<Path>>(
+    path: P,
+    label: &str,
+) -> Result<(Vec<Record>, TableInfo), Error> {
+    let mut reader = Reader::from_path_with_label(path, label)?;
+    let records = reader.read()?;
+    Ok((records, reader.into_table_info()))
+}
+
 #[cfg(test)]
 mod test {
     use std::fs::File;
-    use std::io::{Seek, SeekFrom};
+    use std::io::Seek;
 
     use super::*;
 
+    #[test]
+    fn record_keeps_the_order_of_insertion() {
+        let mut record = Record::new();
+        assert!(record.is_empty());
+        record.insert("b".to_string(), FieldValue::Integer(1));
+        record.insert("a".to_string(), FieldValue::Integer(2));
+        record.extend(vec![
+            ("c".to_string(), FieldValue::Integer(3)),
+            ("b".to_string(), FieldValue::Integer(4)),
+        ]);
+        assert_eq!(record.len(), 3);
+        assert!(record.contains("c"));
+        assert!(!record.contains("d"));
+        assert_eq!(record.field_names().collect::<Vec<_>>(), ["b", "a", "c"]);
+        assert_eq!(record.get_at(0), Some(("b", &FieldValue::Integer(4))));
+        assert_eq!(record.get_at(3), None);
+
+        assert_eq!(record.remove("a"), Some(FieldValue::Integer(2)));
+        assert_eq!(record.remove("a"), None);
+        assert_eq!(record.field_names().collect::<Vec<_>>(), ["b", "c"]);
+        assert_eq!(record.get("c"), Some(&FieldValue::Integer(3)));
+
+        let collected: Record = vec![
+            ("z".to_string(), FieldValue::Integer(1)),
+            ("y".to_string(), FieldValue::Integer(2)),
+            ("z".to_string(), FieldValue::Integer(3)),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(collected.field_names().collect::<Vec<_>>(), ["z", "y"]);
+        assert_eq!(collected.get("z"), Some(&FieldValue::Integer(3)));
+    }
+
     #[test]
     fn pos_after_reading() {
         let file = File::open("tests/data/line.dbf").unwrap();
         let mut reader = Reader::new(file).unwrap();
-        let pos_after_reading = reader.source.seek(SeekFrom::Current(0)).unwrap();
+        let pos_after_reading = reader.source.stream_position().unwrap();
 
         // Do not count the the "DeletionFlag record info that is added
         let mut expected_pos = Header::SIZE + ((reader.fields_info.len() - 1) * FieldInfo::SIZE);
@@ -681,9 +2816,10 @@ mod test {
     fn specify_invalid_encoding_label() {
         let file = File::open("tests/data/line.dbf").unwrap();
         let reader = Reader::new_with_label(file, "invalid-encoding");
-        if reader.is_ok() {
-            assert!(false, "the invalid encoding error was not returned");
-        }
+        assert!(
+            reader.is_err(),
+            "the invalid encoding error was not returned"
+        );
     }
 
     #[test]
@@ -738,4 +2874,29 @@ mod test {
             &FieldValue::Character(None),
         );
     }
+
+    #[test]
+    fn record_keeps_field_order() {
+        let mut record = Record::default();
+        for (i, name) in ["C", "A", "B", "D"].iter().enumerate() {
+            record.insert(name.to_string(), FieldValue::Integer(i as i32));
+        }
+        assert_eq!(
+            record.insert("A".to_string(), FieldValue::Integer(10)),
+            Some(FieldValue::Integer(1))
+        );
+        assert_eq!(record.remove("C"), Some(FieldValue::Integer(0)));
+        assert_eq!(record.remove("C"), None);
+        *record.get_mut("D").unwrap() = FieldValue::Integer(30);
+
+        assert_eq!(record.get("A"), Some(&FieldValue::Integer(10)));
+        assert_eq!(record.get("B"), Some(&FieldValue::Integer(2)));
+        assert_eq!(record.get("D"), Some(&FieldValue::Integer(30)));
+        let names = record
+            .clone()
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["A", "B", "D"]);
+    }
 }
@@ -1,7 +1,7 @@
 use std::convert::TryFrom;
 use std::io::{Read, Write};
 
-use byteorder::{ReadBytesExt, WriteBytesExt};
+use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
 use encoding_rs::Encoding;
 
 pub mod field;
@@ -11,35 +11,221 @@ use crate::{encoded_bytes, invalid_data_error, ErrorKind, FieldValue};
 
 const DELETION_FLAG_NAME: &str = "DeletionFlag";
 const FIELD_NAME_LENGTH: usize = 11;
+/// Maximum number of bytes of a field name, the last byte of the 11 bytes
+/// stored in the file being the null terminator
+const FIELD_NAME_MAX_LEN: usize = FIELD_NAME_LENGTH - 1;
+/// Maximum length of Character fields
+pub(crate) const MAX_CHARACTER_LENGTH: u8 = 254;
 
 #[derive(Debug)]
 /// Wrapping struct to create a FieldName from a String.
 ///
-/// FieldNames in the dBase format cannot exceed 11 bytes (not char).
+/// FieldNames in the dBase format cannot exceed 10 bytes,
+/// can only contain ASCII letters, digits and underscores,
+/// and cannot start with a digit.
+///
+/// dBase programs write names in uppercase, lowercase letters are
+/// still accepted as many other programs use them and are kept as given
+/// (use [FieldName::new_normalized] to uppercase them).
 ///
 /// # Examples
 ///
 /// ```
-/// use dbase::FieldName;
+/// use dbase::{FieldName, InvalidFieldNameError};
 /// use std::convert::TryFrom;
 ///
+/// let name = FieldName::try_from("Small_Name");
+/// assert!(name.is_ok());
+///
 /// let name = FieldName::try_from("Small Name");
-/// assert!(name.is_ok())
+/// assert_eq!(name.unwrap_err(), InvalidFieldNameError::InvalidCharacter(' '));
 /// ```
 pub struct FieldName(String);
 
+impl FieldName {
+    /// Creates a FieldName from the name in uppercase, as dBase programs write them
+    ///
+    /// The name is validated the same way as with `FieldName::try_from`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dbase::{FieldName, InvalidFieldNameError};
+    ///
+    /// assert_eq!(FieldName::new_normalized("Name").unwrap().as_str(), "NAME");
+    /// assert_eq!(
+    ///     FieldName::new_normalized("Name_too_long").unwrap_err(),
+    ///     InvalidFieldNameError::TooLong(13)
+    /// );
+    /// ```
+    pub fn new_normalized(name: &str) -> Result<Self, InvalidFieldNameError> {
+        Self::try_from(name.to_ascii_uppercase().as_str())
+    }
+
+    /// Creates a valid FieldName from any string.
+    ///
+    /// The name is uppercased, characters other than ASCII letters, digits and underscores
+    /// are replaced by `'_'`, a leading digit is prefixed by `'_'`
+    /// and the result is truncated to 10 bytes.
+    /// An empty name becomes `"_"`.
+    ///
+    /// Note that two different names may give the same sanitized name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dbase::FieldName;
+    ///
+    /// assert_eq!(FieldName::sanitized("First name").as_str(), "FIRST_NAME");
+    /// assert_eq!(FieldName::sanitized("2nd_address").as_str(), "_2ND_ADDRE");
+    /// assert_eq!(FieldName::sanitized("Prénom").as_str(), "PR_NOM");
+    /// ```
+    pub fn sanitized(name: &str) -> Self {
+        let mut sanitized = String::with_capacity(FIELD_NAME_MAX_LEN);
+        if name.chars().next().is_none_or(|c| c.is_ascii_digit()) {
+            sanitized.push('_');
+        }
+        sanitized.extend(
+            name.chars()
+                .map(|c| {
+                    if c.is_ascii_alphanumeric() {
+                        c.to_ascii_uppercase()
+                    } else {
+                        '_'
+                    }
+                })
+                .take(FIELD_NAME_MAX_LEN - sanitized.len()),
+        );
+        Self(sanitized)
+    }
+
+    /// Returns the name as a str
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
 impl TryFrom<&str> for FieldName {
-    type Error = &'static str;
+    type Error = InvalidFieldNameError;
 
     fn try_from(name: &str) -> Result<Self, Self::Error> {
-        if name.as_bytes().len() > FIELD_NAME_LENGTH {
-            Err("FieldName byte representation cannot exceed 11 bytes")
+        let first_char = name.chars().next().ok_or(InvalidFieldNameError::Empty)?;
+        if let Some(c) = name
+            .chars()
+            .find(|c| !c.is_ascii_alphanumeric() && *c != '_')
+        {
+            Err(InvalidFieldNameError::InvalidCharacter(c))
+        } else if name.len() > FIELD_NAME_MAX_LEN {
+            Err(InvalidFieldNameError::TooLong(name.len()))
+        } else if first_char.is_ascii_digit() {
+            Err(InvalidFieldNameError::StartsWithDigit)
         } else {
             Ok(Self(name.to_string()))
         }
     }
 }
 
+/// Errors returned when trying to create an invalid [FieldName]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InvalidFieldNameError {
+    /// The name is empty
+    Empty,
+    /// The name is longer than 10 bytes, the value is its length
+    TooLong(usize),
+    /// The name starts with a digit
+    StartsWithDigit,
+    /// The name contains a character that is not
+    /// an ASCII letter, an ASCII digit or an underscore
+    InvalidCharacter(char),
+}
+
+impl std::fmt::Display for InvalidFieldNameError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InvalidFieldNameError::Empty => write!(f, "FieldName cannot be empty"),
+            InvalidFieldNameError::TooLong(len) => write!(
+                f,
+                "FieldName byte representation cannot exceed {} bytes, got {}",
+                FIELD_NAME_MAX_LEN, len
+            ),
+            InvalidFieldNameError::StartsWithDigit => {
+                write!(f, "FieldName cannot start with a digit")
+            }
+            InvalidFieldNameError::InvalidCharacter(c) => write!(
+                f,
+                "FieldName can only contain ASCII letters, digits and underscores, got '{}'",
+                c
+            ),
+        }
+    }
+}
+
+impl std::error::Error for InvalidFieldNameError {}
+
+/// Errors returned when trying to create an invalid [FieldInfo]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FieldInfoError {
+    /// The length of the field is 0
+    ZeroLength,
+    /// The length of a field whose type has a fixed size is not that size
+    InvalidLength {
+        field_type: FieldType,
+        length: u8,
+        expected: u8,
+    },
+    /// The length of the field is greater than the maximum length of its type
+    TooLong {
+        field_type: FieldType,
+        length: u8,
+        max: u8,
+    },
+    /// The number of decimal places is greater than the length of the field minus 2,
+    /// or the type of the field has no decimal places
+    InvalidDecimals { length: u8, num_decimals: u8 },
+    /// Fields of this type cannot be created (e.g. the `_NullFlags` system column)
+    UnsupportedType(FieldType),
+}
+
+impl std::fmt::Display for FieldInfoError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FieldInfoError::ZeroLength => write!(f, "The length of a field cannot be 0"),
+            FieldInfoError::InvalidLength {
+                field_type,
+                length,
+                expected,
+            } => write!(
+                f,
+                "The length of a {:?} field must be {}, got {}",
+                field_type, expected, length
+            ),
+            FieldInfoError::TooLong {
+                field_type,
+                length,
+                max,
+            } => write!(
+                f,
+                "The length of a {:?} field must be between 1 and {}, got {}",
+                field_type, max, length
+            ),
+            FieldInfoError::InvalidDecimals {
+                length,
+                num_decimals,
+            } => write!(
+                f,
+                "The decimal places of a field cannot exceed its length - 2 \
+                 (and only Numeric & Float fields have some), got {} for a length of {}",
+                num_decimals, length
+            ),
+            FieldInfoError::UnsupportedType(field_type) => {
+                write!(f, "Fields of type {:?} cannot be created", field_type)
+            }
+        }
+    }
+}
+
+impl std::error::Error for FieldInfoError {}
+
 /// Struct giving the info for a record field
 #[derive(Debug, PartialEq, Clone)]
 pub struct FieldInfo {
@@ -51,8 +237,12 @@ pub struct FieldInfo {
     pub(crate) field_length: u8,
     pub(crate) num_decimal_places: u8,
     pub(crate) flags: FieldFlags,
-    pub(crate) autoincrement_next_val: [u8; 5],
+    /// Next value of AutoIncrement fields
+    pub(crate) autoincrement_next_val: u32,
+    /// Step of AutoIncrement fields
     pub(crate) autoincrement_step: u8,
+    /// Index of the bit telling if the value is null in the `_NullFlags` column
+    pub(crate) null_flag_bit: Option<usize>,
 }
 
 impl FieldInfo {
@@ -70,19 +260,188 @@ impl FieldInfo {
         self.field_length
     }
 
-    pub(crate) fn new(name: FieldName, field_type: FieldType, length: u8) -> Self {
+    /// Returns the number of decimal places, only meaningful for Numeric & Float fields
+    pub fn num_decimal_places(&self) -> u8 {
+        self.num_decimal_places
+    }
+
+    pub fn flags(&self) -> FieldFlags {
+        self.flags
+    }
+
+    /// Returns true if the field can store null values (Visual FoxPro)
+    pub fn is_nullable(&self) -> bool {
+        self.flags.is_nullable()
+    }
+
+    /// Returns the value the next record added will get,
+    /// only meaningful for [AutoIncrement](enum.FieldType.html#variant.AutoIncrement) fields
+    pub fn autoincrement_next_val(&self) -> u32 {
+        self.autoincrement_next_val
+    }
+
+    /// Returns by how much the value is incremented for each new record,
+    /// only meaningful for [AutoIncrement](enum.FieldType.html#variant.AutoIncrement) fields
+    pub fn autoincrement_step(&self) -> u8 {
+        self.autoincrement_step
+    }
+
+    /// Creates the information of a field, validating its length and number of decimal places
+    ///
+    /// Unlike [new](#method.new), types with a fixed size (e.g. Date, Integer)
+    /// must be given that size, and the `_NullFlags` system column cannot be created.
+    /// The decimal places are validated as in [with_decimals](#method.with_decimals).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dbase::{FieldInfo, FieldInfoError, FieldName, FieldType};
+    /// use std::convert::TryFrom;
+    ///
+    /// let name = FieldName::try_from("PRICE").unwrap();
+    /// let info = FieldInfo::new_with_precision(name, FieldType::Numeric, 10, 2).unwrap();
+    /// assert_eq!(info.length(), 10);
+    ///
+    /// let name = FieldName::try_from("DAY").unwrap();
+    /// assert_eq!(
+    ///     FieldInfo::new_with_precision(name, FieldType::Date, 10, 0).unwrap_err(),
+    ///     FieldInfoError::InvalidLength {
+    ///         field_type: FieldType::Date,
+    ///         length: 10,
+    ///         expected: 8
+    ///     }
+    /// );
+    /// ```
+    pub fn new_with_precision(
+        name: FieldName,
+        field_type: FieldType,
+        length: u8,
+        num_decimals: u8,
+    ) -> Result<Self, FieldInfoError> {
+        if field_type == FieldType::NullFlags {
+            return Err(FieldInfoError::UnsupportedType(field_type));
+        }
+        if let Some(expected) = field_type.size().filter(|&size| size != length) {
+            return Err(FieldInfoError::InvalidLength {
+                field_type,
+                length,
+                expected,
+            });
+        }
+        Self::new(name, field_type, length)?.with_decimals(num_decimals)
+    }
+
+    /// Creates the information of a field, validating its length and number of decimal places
+    #[deprecated(note = "Use FieldInfo::new_with_precision")]
+    pub fn new_with_decimals(
+        name: FieldName,
+        field_type: FieldType,
+        length: u8,
+        num_decimals: u8,
+    ) -> Result<Self, FieldInfoError> {
+        Self::new_with_precision(name, field_type, length, num_decimals)
+    }
+
+    /// Creates the information of a field
+    ///
+    /// For types with a fixed size (e.g. Date, Integer) the length is ignored,
+    /// the size of the type is used instead.
+    /// The value of AutoIncrement fields starts at 1 and is incremented by 1.
+    ///
+    /// Fails if the length is 0, or if a Character field is longer than 254 bytes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dbase::{FieldFlags, FieldInfo, FieldInfoError, FieldName, FieldType};
+    /// use std::convert::TryFrom;
+    ///
+    /// let info = FieldInfo::new(FieldName::try_from("PRICE").unwrap(), FieldType::Numeric, 10)
+    ///     .unwrap()
+    ///     .with_decimals(2)
+    ///     .unwrap()
+    ///     .with_flags(FieldFlags::default().nullable(true));
+    /// assert_eq!(info.num_decimal_places(), 2);
+    /// assert!(info.is_nullable());
+    ///
+    /// let info = FieldInfo::new(FieldName::try_from("DAY").unwrap(), FieldType::Date, 10).unwrap();
+    /// assert_eq!(info.length(), 8);
+    ///
+    /// assert_eq!(
+    ///     FieldInfo::new(FieldName::try_from("NAME").unwrap(), FieldType::Character, 0),
+    ///     Err(FieldInfoError::ZeroLength)
+    /// );
+    /// ```
+    pub fn new(name: FieldName, field_type: FieldType, length: u8) -> Result<Self, FieldInfoError> {
+        let info = Self::new_unchecked(name, field_type, length);
+        info.validate()?;
+        Ok(info)
+    }
+
+    /// Creates the information of a field without validating its length,
+    /// see [validate](#method.validate)
+    pub(crate) fn new_unchecked(name: FieldName, field_type: FieldType, length: u8) -> Self {
+        let (autoincrement_next_val, autoincrement_step) = match field_type {
+            FieldType::AutoIncrement => (1, 1),
+            _ => (0, 0),
+        };
         Self {
             name: name.0,
             field_type,
             displacement_field: [0u8; 4],
-            field_length: length,
+            field_length: field_type.size().unwrap_or(length),
             num_decimal_places: 0,
             flags: FieldFlags::default(),
-            autoincrement_next_val: [0u8; 5],
-            autoincrement_step: 0u8,
+            autoincrement_next_val,
+            autoincrement_step,
+            null_flag_bit: None,
         }
     }
 
+    /// Checks that the length and the number of decimal places of the field
+    /// can be written to a file that is then read back
+    pub(crate) fn validate(&self) -> Result<(), FieldInfoError> {
+        if self.field_length == 0 {
+            return Err(FieldInfoError::ZeroLength);
+        }
+        if self.field_type == FieldType::Character && self.field_length > MAX_CHARACTER_LENGTH {
+            return Err(FieldInfoError::TooLong {
+                field_type: self.field_type,
+                length: self.field_length,
+                max: MAX_CHARACTER_LENGTH,
+            });
+        }
+        let can_have_decimals = matches!(self.field_type, FieldType::Numeric | FieldType::Float);
+        if self.num_decimal_places > 0
+            && (!can_have_decimals || self.num_decimal_places > self.field_length.saturating_sub(2))
+        {
+            return Err(FieldInfoError::InvalidDecimals {
+                length: self.field_length,
+                num_decimals: self.num_decimal_places,
+            });
+        }
+        Ok(())
+    }
+
+    /// Sets the number of decimal places
+    ///
+    /// Only Numeric & Float fields can have decimal places,
+    /// and they can be at most the length of the field minus 2
+    /// (for the integer part & the decimal point).
+    pub fn with_decimals(mut self, num_decimals: u8) -> Result<Self, FieldInfoError> {
+        self.num_decimal_places = num_decimals;
+        self.validate()?;
+        Ok(self)
+    }
+
+    /// Sets the flags of the field
+    ///
+    /// Null values are only written in tables that have a `_NullFlags` column.
+    pub fn with_flags(mut self, flags: FieldFlags) -> Self {
+        self.flags = flags;
+        self
+    }
+
     pub(crate) fn read_from<T: Read>(
         source: &mut T,
         encoding: &'static Encoding,
@@ -95,16 +454,18 @@ impl FieldInfo {
         source.read_exact(&mut displacement_field)?;
 
         let record_length = source.read_u8()?;
+        if record_length == 0 {
+            // The records could not be read correctly
+            return Err(FieldInfoError::ZeroLength.into());
+        }
         let num_decimal_places = source.read_u8()?;
 
         let flags = FieldFlags(source.read_u8()?);
 
-        let mut autoincrement_next_val = [0u8; 5];
-
-        source.read_exact(&mut autoincrement_next_val)?;
+        let autoincrement_next_val = source.read_u32::<LittleEndian>()?;
         let autoincrement_step = source.read_u8()?;
 
-        let mut _reserved = [0u8; 7];
+        let mut _reserved = [0u8; 8];
         source.read_exact(&mut _reserved)?;
 
         let s = if encoding == encoding_rs::UTF_8 {
@@ -120,6 +481,24 @@ impl FieldInfo {
         };
 
         let field_type = FieldType::try_from(field_type as char)?;
+        // Binary values are read from the first bytes of the field
+        let min_length = match field_type {
+            FieldType::Integer
+            | FieldType::AutoIncrement
+            | FieldType::Currency
+            | FieldType::DateTime
+            | FieldType::Double => field_type.size(),
+            FieldType::Memo => Some(std::mem::size_of::<u32>() as u8),
+            _ => None,
+        };
+        if let Some(expected) = min_length.filter(|&min_length| record_length < min_length) {
+            return Err(FieldInfoError::InvalidLength {
+                field_type,
+                length: record_length,
+                expected,
+            }
+            .into());
+        }
 
         Ok(Self {
             name: s,
@@ -130,6 +509,7 @@ impl FieldInfo {
             flags,
             autoincrement_next_val,
             autoincrement_step,
+            null_flag_bit: None,
         })
     }
 
@@ -140,21 +520,20 @@ impl FieldInfo {
     ) -> std::io::Result<()> {
         // get bytes of field name by the encoding.
         let mut name_bytes = [0u8; FIELD_NAME_LENGTH];
-        if encoding == encoding_rs::UTF_8 {
-            let num_bytes = self.name.as_bytes().len();
-            name_bytes[..num_bytes.min(FIELD_NAME_LENGTH)].copy_from_slice(self.name.as_bytes());
+        let encoded = if encoding == encoding_rs::UTF_8 {
+            self.name.as_bytes().to_vec()
         } else {
-            let encoded = encoded_bytes(self.name.as_str(), encoding)?;
-            if FIELD_NAME_LENGTH <= encoded.len() {
-                return Err(invalid_data_error(format!(
-                    "field name({}) is less than or equal to `{} bytes(actual: {}bytes)",
-                    self.name,
-                    FIELD_NAME_LENGTH - 1,
-                    encoded.len(),
-                )));
-            }
-            name_bytes[..encoded.len()].copy_from_slice(&encoded);
+            encoded_bytes(self.name.as_str(), encoding)?
+        };
+        if FIELD_NAME_LENGTH <= encoded.len() {
+            return Err(invalid_data_error(format!(
+                "field name({}) is less than or equal to `{} bytes(actual: {}bytes)",
+                self.name,
+                FIELD_NAME_MAX_LEN,
+                encoded.len(),
+            )));
         }
+        name_bytes[..encoded.len()].copy_from_slice(&encoded);
         dest.write_all(&name_bytes)?;
 
         dest.write_u8(u8::from(self.field_type))?;
@@ -162,10 +541,10 @@ impl FieldInfo {
         dest.write_u8(self.field_length)?;
         dest.write_u8(self.num_decimal_places)?;
         dest.write_u8(self.flags.0)?;
-        dest.write_all(&self.autoincrement_next_val)?;
+        dest.write_u32::<LittleEndian>(self.autoincrement_next_val)?;
         dest.write_u8(self.autoincrement_step)?;
 
-        let reserved = [0u8; 7];
+        let reserved = [0u8; 8];
         dest.write_all(&reserved)?;
 
         Ok(())
@@ -179,14 +558,49 @@ impl FieldInfo {
             field_length: 1,
             num_decimal_places: 0,
             flags: FieldFlags(0u8),
-            autoincrement_next_val: [0u8; 5],
+            autoincrement_next_val: 0,
             autoincrement_step: 0u8,
+            null_flag_bit: None,
         }
     }
 
     pub(crate) fn is_deletion_flag(&self) -> bool {
         self.name == DELETION_FLAG_NAME
     }
+
+    /// Returns true if this is the Visual FoxPro `_NullFlags` system column
+    pub(crate) fn is_null_flags(&self) -> bool {
+        self.field_type == FieldType::NullFlags
+            || (self.flags.is_system() && self.name.starts_with('_'))
+    }
+
+    /// Returns true if the field is not part of the values of a record
+    pub(crate) fn is_hidden(&self) -> bool {
+        self.is_deletion_flag() || self.is_null_flags()
+    }
+}
+
+/// Gives to each nullable field its bit in the `_NullFlags` column,
+/// in the order of the fields.
+///
+/// Fields have no bit if there is no `_NullFlags` column.
+pub(crate) fn assign_null_flag_bits(fields_info: &mut [FieldInfo]) {
+    let has_null_flags = fields_info.iter().any(FieldInfo::is_null_flags);
+    let mut next_bit = 0;
+    for field_info in fields_info.iter_mut() {
+        field_info.null_flag_bit = None;
+        if has_null_flags && field_info.is_nullable() && !field_info.is_null_flags() {
+            field_info.null_flag_bit = Some(next_bit);
+            next_bit += 1;
+        }
+    }
+}
+
+/// Returns true if the bit is set in the `_NullFlags` bytes
+pub(crate) fn null_flag_is_set(null_flags: &[u8], bit: usize) -> bool {
+    null_flags
+        .get(bit / 8)
+        .is_some_and(|byte| byte & (1 << (bit % 8)) != 0)
 }
 
 impl std::fmt::Display for FieldInfo {
@@ -199,9 +613,54 @@ impl std::fmt::Display for FieldInfo {
     }
 }
 
-/// Flags describing a field
-#[derive(Debug, Copy, Clone, PartialEq, Default)]
-pub(crate) struct FieldFlags(u8);
+/// Flags describing a field (Visual FoxPro)
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub struct FieldFlags(pub(crate) u8);
+
+impl FieldFlags {
+    /// The field is a system column, hidden to the user
+    pub(crate) const SYSTEM: u8 = 0x01;
+    /// The field can store null values
+    pub(crate) const NULLABLE: u8 = 0x02;
+    /// The field stores binary data (Character & Memo fields)
+    pub(crate) const BINARY: u8 = 0x04;
+
+    /// Returns true if the field is a system column (e.g. `_NullFlags`)
+    pub fn is_system(self) -> bool {
+        self.0 & Self::SYSTEM != 0
+    }
+
+    /// Returns true if the field can store null values
+    pub fn is_nullable(self) -> bool {
+        self.0 & Self::NULLABLE != 0
+    }
+
+    /// Sets whether the field can store null values
+    pub fn nullable(mut self, nullable: bool) -> Self {
+        if nullable {
+            self.0 |= Self::NULLABLE;
+        } else {
+            self.0 &= !Self::NULLABLE;
+        }
+        self
+    }
+
+    /// Returns true if the field stores binary data,
+    /// the values of such Memo fields are read as [FieldValue::BinaryMemo]
+    pub fn is_binary(self) -> bool {
+        self.0 & Self::BINARY != 0
+    }
+
+    /// Sets whether the field stores binary data
+    pub fn binary(mut self, binary: bool) -> Self {
+        if binary {
+            self.0 |= Self::BINARY;
+        } else {
+            self.0 &= !Self::BINARY;
+        }
+        self
+    }
+}
 
 /// Errors that can happen when trying to convert a FieldValue into
 /// a more concrete type
@@ -219,6 +678,15 @@ pub enum FieldConversionError {
     /// The value written is the file was only pad bytes / uninitialized
     /// and the user tried to convert it into a non Option-Type
     NoneValue,
+    /// The value does not fit in the range of the integer type
+    IntegerOverflow,
+    /// The numeric value has a fractional part and thus cannot be converted
+    /// into an integer type without loosing it
+    FractionalPart,
+    /// The date or time is not valid in the type it is converted into
+    InvalidDateTime,
+    /// The record does not have a field with the given name
+    MissingField(String),
 }
 
 macro_rules! impl_try_from_field_value_for_ {
@@ -256,38 +724,203 @@ macro_rules! impl_try_from_field_value_for_ {
     };
 }
 
-impl_try_from_field_value_for_!(FieldValue::Numeric => Option<f64>);
+/// Converts Numeric & Float values, other variants give
+/// [FieldConversionError::FieldTypeNotAsExpected]
+impl TryFrom<FieldValue> for Option<f64> {
+    type Error = FieldConversionError;
+
+    fn try_from(value: FieldValue) -> Result<Self, Self::Error> {
+        match value {
+            FieldValue::Numeric(v) => Ok(v),
+            FieldValue::Float(v) => Ok(v.map(f64::from)),
+            _ => Err(FieldConversionError::FieldTypeNotAsExpected {
+                expected: FieldType::Numeric,
+                actual: value.field_type(),
+            }),
+        }
+    }
+}
+
+/// Converts Float & Numeric values, other variants give
+/// [FieldConversionError::FieldTypeNotAsExpected]
+///
+/// Numeric values are stored as `f64`, they lose precision when converted.
+impl TryFrom<FieldValue> for Option<f32> {
+    type Error = FieldConversionError;
+
+    fn try_from(value: FieldValue) -> Result<Self, Self::Error> {
+        match value {
+            FieldValue::Float(v) => Ok(v),
+            FieldValue::Numeric(v) => Ok(v.map(|v| v as f32)),
+            _ => Err(FieldConversionError::FieldTypeNotAsExpected {
+                expected: FieldType::Float,
+                actual: value.field_type(),
+            }),
+        }
+    }
+}
+
+/// Converts `Float(Some(_))` & `Numeric(Some(_))` values,
+/// `Float(None)` & `Numeric(None)` give [FieldConversionError::NoneValue]
+/// and other variants [FieldConversionError::FieldTypeNotAsExpected]
+///
+/// Numeric values are stored as `f64`, they lose precision when converted.
+impl TryFrom<FieldValue> for f32 {
+    type Error = FieldConversionError;
 
-impl_try_from_field_value_for_!(FieldValue::Float => Option<f32>);
-impl_try_from_field_value_for_!(FieldValue::Float(Some(v)) => f32);
+    fn try_from(value: FieldValue) -> Result<Self, Self::Error> {
+        match Option::<f32>::try_from(value)? {
+            Some(v) => Ok(v),
+            None => Err(FieldConversionError::NoneValue),
+        }
+    }
+}
 
 impl_try_from_field_value_for_!(FieldValue::Date => Option<field::Date>);
-impl_try_from_field_value_for_!(FieldValue::Date(Some(v)) => field::Date);
+
+/// Converts `Date(Some(_))` values,
+/// `Date(None)` gives [FieldConversionError::NoneValue]
+/// and other variants [FieldConversionError::FieldTypeNotAsExpected]
+impl TryFrom<FieldValue> for field::Date {
+    type Error = FieldConversionError;
+
+    fn try_from(value: FieldValue) -> Result<Self, Self::Error> {
+        match value {
+            FieldValue::Date(Some(date)) => Ok(date),
+            FieldValue::Date(None) => Err(FieldConversionError::NoneValue),
+            _ => Err(FieldConversionError::FieldTypeNotAsExpected {
+                expected: FieldType::Date,
+                actual: value.field_type(),
+            }),
+        }
+    }
+}
 
 impl_try_from_field_value_for_!(FieldValue::Character => Option<String>);
-impl_try_from_field_value_for_!(FieldValue::Character(Some(string)) => String);
+
+/// Converts `Character(Some(_))` & `Memo` values,
+/// `Character(None)` gives [FieldConversionError::NoneValue]
+/// and other variants [FieldConversionError::FieldTypeNotAsExpected].
+///
+/// Numbers or dates are not converted to text, use
+/// [FieldValue::to_display_string] for that.
+impl TryFrom<FieldValue> for String {
+    type Error = FieldConversionError;
+
+    fn try_from(value: FieldValue) -> Result<Self, Self::Error> {
+        match value {
+            FieldValue::Character(Some(string)) | FieldValue::Memo(string) => Ok(string),
+            FieldValue::Character(None) => Err(FieldConversionError::NoneValue),
+            _ => Err(FieldConversionError::FieldTypeNotAsExpected {
+                expected: FieldType::Character,
+                actual: value.field_type(),
+            }),
+        }
+    }
+}
+
+/// Converts `BinaryMemo` & `Memo` values into their bytes,
+/// other variants give [FieldConversionError::FieldTypeNotAsExpected]
+impl TryFrom<FieldValue> for Vec<u8> {
+    type Error = FieldConversionError;
+
+    fn try_from(value: FieldValue) -> Result<Self, Self::Error> {
+        match value {
+            FieldValue::BinaryMemo(bytes) => Ok(bytes),
+            FieldValue::Memo(string) => Ok(string.into_bytes()),
+            _ => Err(FieldConversionError::FieldTypeNotAsExpected {
+                expected: FieldType::Memo,
+                actual: value.field_type(),
+            }),
+        }
+    }
+}
 
 impl_try_from_field_value_for_!(FieldValue::Logical => Option<bool>);
-impl_try_from_field_value_for_!(FieldValue::Logical(Some(b)) => bool);
 
-impl_try_from_field_value_for_!(FieldValue::Integer => i32);
+/// Converts `Logical(Some(_))` values,
+/// `Logical(None)` gives [FieldConversionError::NoneValue]
+/// and other variants [FieldConversionError::FieldTypeNotAsExpected]
+impl TryFrom<FieldValue> for bool {
+    type Error = FieldConversionError;
 
+    fn try_from(value: FieldValue) -> Result<Self, Self::Error> {
+        match value {
+            FieldValue::Logical(Some(b)) => Ok(b),
+            FieldValue::Logical(None) => Err(FieldConversionError::NoneValue),
+            _ => Err(FieldConversionError::FieldTypeNotAsExpected {
+                expected: FieldType::Logical,
+                actual: value.field_type(),
+            }),
+        }
+    }
+}
+
+/// Integer types can be read from Integer & AutoIncrement fields,
+/// as well as from Numeric, Float and Double fields as long as the value has no
+/// fractional part.
+macro_rules! impl_try_from_field_value_for_integer {
+    ($($int:ty),*) => {
+        $(
+            impl TryFrom<FieldValue> for $int {
+                type Error = FieldConversionError;
+
+                fn try_from(value: FieldValue) -> Result<Self, Self::Error> {
+                    let value = match value {
+                        FieldValue::Integer(v) | FieldValue::AutoIncrement(v) => {
+                            return <$int>::try_from(v)
+                                .map_err(|_| FieldConversionError::IntegerOverflow);
+                        }
+                        FieldValue::Numeric(Some(v)) | FieldValue::Double(v) => v,
+                        FieldValue::Float(Some(v)) => f64::from(v),
+                        FieldValue::Numeric(None) | FieldValue::Float(None) => {
+                            return Err(FieldConversionError::NoneValue);
+                        }
+                        _ => return Err(FieldConversionError::IncompatibleType),
+                    };
+                    if value.fract() != 0.0 {
+                        Err(FieldConversionError::FractionalPart)
+                    } else if value >= <$int>::MIN as f64 && value < <$int>::MAX as f64 + 1.0 {
+                        Ok(value as $int)
+                    } else {
+                        Err(FieldConversionError::IntegerOverflow)
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_try_from_field_value_for_integer!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+/// Converts all the numeric variants: `Numeric(Some(_))`, `Float(Some(_))`,
+/// `Double`, `Currency`, `Integer` & `AutoIncrement`,
+/// `Numeric(None)` & `Float(None)` give [FieldConversionError::NoneValue]
+/// and other variants [FieldConversionError::FieldTypeNotAsExpected]
 impl TryFrom<FieldValue> for f64 {
     type Error = FieldConversionError;
 
     fn try_from(value: FieldValue) -> Result<Self, Self::Error> {
         match value {
             FieldValue::Numeric(Some(v)) => Ok(v),
-            FieldValue::Numeric(None) => Err(FieldConversionError::NoneValue),
-            FieldValue::Currency(c) => Ok(c),
+            FieldValue::Float(Some(v)) => Ok(f64::from(v)),
+            FieldValue::Numeric(None) | FieldValue::Float(None) => {
+                Err(FieldConversionError::NoneValue)
+            }
+            FieldValue::Currency(c) => Ok(c.to_f64()),
             FieldValue::Double(d) => Ok(d),
-            _ => Err(FieldConversionError::IncompatibleType),
+            FieldValue::Integer(i) | FieldValue::AutoIncrement(i) => Ok(f64::from(i)),
+            _ => Err(FieldConversionError::FieldTypeNotAsExpected {
+                expected: FieldType::Numeric,
+                actual: value.field_type(),
+            }),
         }
     }
 }
 
 // Fox Pro types
 impl_try_from_field_value_for_!(FieldValue::DateTime => DateTime);
+impl_try_from_field_value_for_!(FieldValue::Currency => field::CurrencyValue);
 
 macro_rules! impl_from_type_for_field_value (
     ($t:ty => FieldValue::$variant:ident) => {
@@ -309,12 +942,26 @@ macro_rules! impl_from_type_for_field_value (
 impl_from_type_for_field_value!(Option<String> => FieldValue::Character);
 impl_from_type_for_field_value!(String => FieldValue::Character(Some(s)));
 
+impl From<&str> for FieldValue {
+    fn from(s: &str) -> Self {
+        FieldValue::Character(Some(s.to_string()))
+    }
+}
+
+impl From<Option<&str>> for FieldValue {
+    fn from(s: Option<&str>) -> Self {
+        FieldValue::Character(s.map(str::to_string))
+    }
+}
+
 impl_from_type_for_field_value!(Option<f64> => FieldValue::Numeric);
 impl_from_type_for_field_value!(f64 => FieldValue::Numeric(Some(v)));
 
 impl_from_type_for_field_value!(Option<f32> => FieldValue::Float);
 impl_from_type_for_field_value!(f32 => FieldValue::Float(Some(v)));
 
+impl_from_type_for_field_value!(i32 => FieldValue::Integer);
+
 impl_from_type_for_field_value!(Option<bool> => FieldValue::Logical);
 impl_from_type_for_field_value!(bool => FieldValue::Logical(Some(v)));
 
@@ -323,6 +970,7 @@ impl_from_type_for_field_value!(Date => FieldValue::Date(Some(v)));
 
 // Fox Pro types
 impl_from_type_for_field_value!(DateTime => FieldValue::DateTime);
+impl_from_type_for_field_value!(field::CurrencyValue => FieldValue::Currency);
 
 #[cfg(test)]
 mod test {
@@ -335,7 +983,8 @@ mod test {
             FieldName::try_from("LICENSE").unwrap(),
             FieldType::Character,
             30,
-        );
+        )
+        .unwrap();
         let mut cursor = Cursor::new(Vec::<u8>::with_capacity(FieldInfo::SIZE));
         field_info
             .write_to(&mut cursor, encoding_rs::UTF_8)
@@ -347,4 +996,226 @@ mod test {
 
         assert_eq!(read_field_info, field_info);
     }
+
+    #[test]
+    fn field_name_validation() {
+        assert!(FieldName::try_from("NAME_2").is_ok());
+        assert!(FieldName::try_from("_lowercase").is_ok());
+        assert_eq!(
+            FieldName::try_from("属性1").unwrap_err(),
+            InvalidFieldNameError::InvalidCharacter('属')
+        );
+        assert_eq!(
+            FieldName::try_from("Prénom").unwrap_err(),
+            InvalidFieldNameError::InvalidCharacter('é')
+        );
+        assert_eq!(
+            FieldName::try_from("").unwrap_err(),
+            InvalidFieldNameError::Empty
+        );
+        assert_eq!(
+            FieldName::try_from("ELEVEN_CHAR").unwrap_err(),
+            InvalidFieldNameError::TooLong(11)
+        );
+        assert_eq!(
+            FieldName::try_from("2ND").unwrap_err(),
+            InvalidFieldNameError::StartsWithDigit
+        );
+        assert_eq!(
+            FieldName::try_from("FIRST-NAME").unwrap_err(),
+            InvalidFieldNameError::InvalidCharacter('-')
+        );
+    }
+
+    #[test]
+    fn field_info_validation() {
+        let new = |field_type, length, num_decimals| {
+            FieldInfo::new_with_precision(
+                FieldName::try_from("FIELD").unwrap(),
+                field_type,
+                length,
+                num_decimals,
+            )
+        };
+        assert!(new(FieldType::Character, 254, 0).is_ok());
+        assert!(new(FieldType::Float, 20, 18).is_ok());
+        assert!(new(FieldType::Integer, 4, 0).is_ok());
+        assert_eq!(
+            new(FieldType::Character, 0, 0),
+            Err(FieldInfoError::ZeroLength)
+        );
+        assert_eq!(
+            new(FieldType::Currency, 4, 0),
+            Err(FieldInfoError::InvalidLength {
+                field_type: FieldType::Currency,
+                length: 4,
+                expected: 8
+            })
+        );
+        assert_eq!(
+            new(FieldType::Numeric, 5, 5),
+            Err(FieldInfoError::InvalidDecimals {
+                length: 5,
+                num_decimals: 5
+            })
+        );
+        // No room for the integer part & the decimal point
+        assert_eq!(
+            new(FieldType::Float, 20, 19),
+            Err(FieldInfoError::InvalidDecimals {
+                length: 20,
+                num_decimals: 19
+            })
+        );
+        assert_eq!(
+            new(FieldType::Character, 255, 0),
+            Err(FieldInfoError::TooLong {
+                field_type: FieldType::Character,
+                length: 255,
+                max: 254
+            })
+        );
+        assert_eq!(
+            new(FieldType::Character, 5, 2),
+            Err(FieldInfoError::InvalidDecimals {
+                length: 5,
+                num_decimals: 2
+            })
+        );
+        assert_eq!(
+            new(FieldType::NullFlags, 1, 0),
+            Err(FieldInfoError::UnsupportedType(FieldType::NullFlags))
+        );
+        assert_eq!(
+            new(FieldType::AutoIncrement, 4, 0)
+                .unwrap()
+                .autoincrement_next_val(),
+            1
+        );
+    }
+
+    #[test]
+    fn field_info_builders() {
+        let name = || FieldName::try_from("FIELD").unwrap();
+        let info = FieldInfo::new(name(), FieldType::Numeric, 10)
+            .unwrap()
+            .with_decimals(3)
+            .unwrap();
+        assert_eq!(info.length(), 10);
+        assert_eq!(info.num_decimal_places(), 3);
+        assert_eq!(
+            FieldInfo::new(name(), FieldType::Float, 4)
+                .unwrap()
+                .with_decimals(4),
+            Err(FieldInfoError::InvalidDecimals {
+                length: 4,
+                num_decimals: 4
+            })
+        );
+        assert_eq!(
+            FieldInfo::new(name(), FieldType::Logical, 1)
+                .unwrap()
+                .with_decimals(1),
+            Err(FieldInfoError::InvalidDecimals {
+                length: 1,
+                num_decimals: 1
+            })
+        );
+
+        // The length of fixed size types is overridden
+        let info = FieldInfo::new(name(), FieldType::DateTime, 4).unwrap();
+        assert_eq!(info.length(), 8);
+        assert_eq!(info.with_decimals(0).unwrap().num_decimal_places(), 0);
+
+        let flags = FieldFlags::default().nullable(true);
+        let info = FieldInfo::new(name(), FieldType::Character, 10)
+            .unwrap()
+            .with_flags(flags);
+        assert!(info.is_nullable());
+        assert!(!info.flags().is_system());
+        assert!(!info.flags().nullable(false).is_nullable());
+
+        assert_eq!(
+            FieldInfo::new(name(), FieldType::Character, 0),
+            Err(FieldInfoError::ZeroLength)
+        );
+        assert!(matches!(
+            FieldInfo::new(name(), FieldType::Character, 255),
+            Err(FieldInfoError::TooLong { .. })
+        ));
+        // The length of fixed size types is not checked, as it is overridden
+        assert!(FieldInfo::new(name(), FieldType::Date, 0).is_ok());
+    }
+
+    #[test]
+    fn normalized_field_name() {
+        assert_eq!(FieldName::new_normalized("name").unwrap().as_str(), "NAME");
+        assert_eq!(
+            FieldName::new_normalized("Né").unwrap_err(),
+            InvalidFieldNameError::InvalidCharacter('é')
+        );
+        assert_eq!(
+            FieldName::new_normalized("first name").unwrap_err(),
+            InvalidFieldNameError::InvalidCharacter(' ')
+        );
+    }
+
+    #[test]
+    fn sanitized_field_name() {
+        assert_eq!(FieldName::sanitized("name").as_str(), "NAME");
+        assert_eq!(FieldName::sanitized("").as_str(), "_");
+        assert_eq!(FieldName::sanitized("3D model").as_str(), "_3D_MODEL");
+        assert_eq!(
+            FieldName::sanitized("a.very.long.name").as_str(),
+            "A_VERY_LON"
+        );
+        assert_eq!(FieldName::sanitized("属性1").as_str(), "__1");
+        for name in ["name", "", "3D model", "a.very.long.name", "属性1"] {
+            let sanitized = FieldName::sanitized(name);
+            assert!(FieldName::try_from(sanitized.as_str()).is_ok());
+        }
+    }
+
+    #[test]
+    fn try_from_field_value() {
+        assert_eq!(f64::try_from(FieldValue::Integer(3)).unwrap(), 3.0);
+        assert_eq!(f64::try_from(FieldValue::Float(Some(1.5))).unwrap(), 1.5);
+        assert_eq!(
+            f64::try_from(FieldValue::Currency(field::CurrencyValue::from_f64(2.25))).unwrap(),
+            2.25
+        );
+        assert!(matches!(
+            f64::try_from(FieldValue::Numeric(None)),
+            Err(FieldConversionError::NoneValue)
+        ));
+        assert!(matches!(
+            f64::try_from(FieldValue::Logical(Some(true))),
+            Err(FieldConversionError::FieldTypeNotAsExpected {
+                expected: FieldType::Numeric,
+                actual: FieldType::Logical,
+            })
+        ));
+
+        assert_eq!(
+            String::try_from(FieldValue::Memo("memo".to_string())).unwrap(),
+            "memo"
+        );
+        assert!(matches!(
+            String::try_from(FieldValue::Numeric(Some(1.0))),
+            Err(FieldConversionError::FieldTypeNotAsExpected {
+                expected: FieldType::Character,
+                actual: FieldType::Numeric,
+            })
+        ));
+
+        assert!(!bool::try_from(FieldValue::Logical(Some(false))).unwrap());
+        assert!(matches!(
+            bool::try_from(FieldValue::Logical(None)),
+            Err(FieldConversionError::NoneValue)
+        ));
+
+        let date = Date::new(1, 2, 2003);
+        assert_eq!(Date::try_from(FieldValue::Date(Some(date))).unwrap(), date);
+        assert!(Date::try_from(FieldValue::Character(Some("20030201".to_string()))).is_err());
+    }
 }
@@ -1,14 +1,15 @@
+use serde::ser::{Impossible, SerializeMap};
 use serde::{Serialize, Serializer};
 use std::io::Write;
 
 use crate::record::field::FieldType;
 use crate::writing::FieldWriter;
-use crate::{Date, FieldIOError};
+use crate::{Date, FieldIOError, Record};
 use crate::{ErrorKind, WritableRecord};
 
 impl<T> WritableRecord for T
 where
-    T: Serialize,
+    T: Serialize + ?Sized,
 {
     fn write_using<'a, W: Write>(
         &self,
@@ -18,27 +19,63 @@ where
     }
 }
 
-impl<'a, W: Write> Serializer for &mut FieldWriter<'a, W> {
+/// Records are serialized as a map of field names to values, in the order of the fields
+/// (see the `Serialize` implementation of [FieldValue](crate::FieldValue) for the values),
+/// e.g. to convert them to JSON.
+///
+/// The implementation is for references, as [Record] itself is written
+/// by its [WritableRecord] implementation, functions that take a reference
+/// to the value to serialize (e.g. `serde_json::to_string`) must be given `&&record`.
+///
+/// # Example
+///
+/// ```
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let record = dbase::read("tests/data/stations.dbf")?.remove(0);
+/// let json = serde_json::to_value(&record)?;
+/// assert_eq!(json["line"], "blue");
+/// # Ok(())
+/// # }
+/// ```
+impl Serialize for &Record {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(self.len()))?;
+        for (name, value) in self.iter() {
+            map.serialize_entry(name, value)?;
+        }
+        map.end()
+    }
+}
+
+impl<'b, 'a, W: Write> Serializer for &'b mut FieldWriter<'a, W> {
     type Ok = ();
     type Error = FieldIOError;
     type SerializeSeq = Self;
     type SerializeTuple = Self;
     type SerializeTupleStruct = Self;
     type SerializeTupleVariant = Self;
-    type SerializeMap = Self;
+    type SerializeMap = MapWriter<'b, 'a, W>;
     type SerializeStruct = Self;
     type SerializeStructVariant = Self;
 
+    // Dates & date times are written from their bytes
+    fn is_human_readable(&self) -> bool {
+        false
+    }
+
     fn serialize_bool(self, v: bool) -> Result<Self::Ok, Self::Error> {
         self.write_next_field_value(&v)
     }
 
-    fn serialize_i8(self, _v: i8) -> Result<Self::Ok, Self::Error> {
-        unimplemented!("Dbase cannot serialize i8")
+    fn serialize_i8(self, v: i8) -> Result<Self::Ok, Self::Error> {
+        self.write_next_field_value(&v)
     }
 
-    fn serialize_i16(self, _v: i16) -> Result<Self::Ok, Self::Error> {
-        unimplemented!("Dbase cannot serialize i16")
+    fn serialize_i16(self, v: i16) -> Result<Self::Ok, Self::Error> {
+        self.write_next_field_value(&v)
     }
 
     fn serialize_i32(self, v: i32) -> Result<Self::Ok, Self::Error> {
@@ -50,24 +87,24 @@ impl<'a, W: Write> Serializer for &mut FieldWriter<'a, W> {
         //        }
     }
 
-    fn serialize_i64(self, _v: i64) -> Result<Self::Ok, Self::Error> {
-        unimplemented!("Dbase cannot serialize i64")
+    fn serialize_i64(self, v: i64) -> Result<Self::Ok, Self::Error> {
+        self.write_next_field_value(&v)
     }
 
-    fn serialize_u8(self, _v: u8) -> Result<Self::Ok, Self::Error> {
-        unimplemented!("Dbase cannot serialize u8")
+    fn serialize_u8(self, v: u8) -> Result<Self::Ok, Self::Error> {
+        self.write_next_field_value(&v)
     }
 
-    fn serialize_u16(self, _v: u16) -> Result<Self::Ok, Self::Error> {
-        unimplemented!("Dbase cannot serialize u16")
+    fn serialize_u16(self, v: u16) -> Result<Self::Ok, Self::Error> {
+        self.write_next_field_value(&v)
     }
 
-    fn serialize_u32(self, _v: u32) -> Result<Self::Ok, Self::Error> {
-        unimplemented!("Dbase cannot serialize u32")
+    fn serialize_u32(self, v: u32) -> Result<Self::Ok, Self::Error> {
+        self.write_next_field_value(&v)
     }
 
-    fn serialize_u64(self, _v: u64) -> Result<Self::Ok, Self::Error> {
-        unimplemented!("Dbase cannot serialize u64")
+    fn serialize_u64(self, v: u64) -> Result<Self::Ok, Self::Error> {
+        self.write_next_field_value(&v)
     }
 
     fn serialize_f32(self, v: f32) -> Result<Self::Ok, Self::Error> {
@@ -83,6 +120,11 @@ impl<'a, W: Write> Serializer for &mut FieldWriter<'a, W> {
     }
 
     fn serialize_str(self, v: &str) -> Result<Self::Ok, Self::Error> {
+        // chrono serializes its dates as text
+        #[cfg(feature = "chrono")]
+        if let Some(result) = crate::chrono_types::write_iso_text(self, v) {
+            return result;
+        }
         self.write_next_field_value(&v)
     }
 
@@ -99,7 +141,7 @@ impl<'a, W: Write> Serializer for &mut FieldWriter<'a, W> {
                 FieldType::Date => self.write_next_field_value::<Option<Date>>(&None),
                 FieldType::Logical => self.write_next_field_value::<Option<bool>>(&None),
                 _ => Err(FieldIOError::new(
-                    ErrorKind::Message(format!("This field cannot store None values")),
+                    ErrorKind::Message("This field cannot store None values".to_string()),
                     Some((*field_info).to_owned()),
                 )),
             }
@@ -108,9 +150,9 @@ impl<'a, W: Write> Serializer for &mut FieldWriter<'a, W> {
         }
     }
 
-    fn serialize_some<T: ?Sized>(self, value: &T) -> Result<Self::Ok, Self::Error>
+    fn serialize_some<T>(self, value: &T) -> Result<Self::Ok, Self::Error>
     where
-        T: Serialize,
+        T: Serialize + ?Sized,
     {
         value.serialize(self)
     }
@@ -123,27 +165,28 @@ impl<'a, W: Write> Serializer for &mut FieldWriter<'a, W> {
         unimplemented!("dBase cannot serialize unit struct")
     }
 
+    /// Unit variants are written as their name, in a Character field
     fn serialize_unit_variant(
         self,
         _name: &'static str,
         _variant_index: u32,
-        _variant: &'static str,
+        variant: &'static str,
     ) -> Result<Self::Ok, Self::Error> {
-        unimplemented!("dBase cannot serialize unit_variant")
+        self.write_next_field_value(&variant)
     }
 
-    fn serialize_newtype_struct<T: ?Sized>(
+    fn serialize_newtype_struct<T>(
         self,
         _name: &'static str,
         value: &T,
     ) -> Result<Self::Ok, Self::Error>
     where
-        T: Serialize,
+        T: Serialize + ?Sized,
     {
         value.serialize(self)
     }
 
-    fn serialize_newtype_variant<T: ?Sized>(
+    fn serialize_newtype_variant<T>(
         self,
         _name: &'static str,
         _variant_index: u32,
@@ -151,7 +194,7 @@ impl<'a, W: Write> Serializer for &mut FieldWriter<'a, W> {
         _value: &T,
     ) -> Result<Self::Ok, Self::Error>
     where
-        T: Serialize,
+        T: Serialize + ?Sized,
     {
         unimplemented!()
     }
@@ -182,8 +225,13 @@ impl<'a, W: Write> Serializer for &mut FieldWriter<'a, W> {
         unimplemented!()
     }
 
-    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
-        unimplemented!()
+    /// Maps are written like [Record]s: the values are looked up by the name of the fields
+    fn serialize_map(self, len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        Ok(MapWriter {
+            field_writer: self,
+            key: None,
+            values: Vec::with_capacity(len.unwrap_or(0)),
+        })
     }
 
     fn serialize_struct(
@@ -204,11 +252,11 @@ impl<'a, W: Write> Serializer for &mut FieldWriter<'a, W> {
         unimplemented!()
     }
 
-    fn collect_str<T: ?Sized>(self, _value: &T) -> Result<Self::Ok, Self::Error>
+    fn collect_str<T>(self, value: &T) -> Result<Self::Ok, Self::Error>
     where
-        T: std::fmt::Display,
+        T: std::fmt::Display + ?Sized,
     {
-        unimplemented!()
+        self.serialize_str(&value.to_string())
     }
 }
 
@@ -216,13 +264,9 @@ impl<'a, W: Write> serde::ser::SerializeStructVariant for &mut FieldWriter<'a, W
     type Ok = ();
     type Error = FieldIOError;
 
-    fn serialize_field<T: ?Sized>(
-        &mut self,
-        _key: &'static str,
-        _value: &T,
-    ) -> Result<(), Self::Error>
+    fn serialize_field<T>(&mut self, _key: &'static str, _value: &T) -> Result<(), Self::Error>
     where
-        T: Serialize,
+        T: Serialize + ?Sized,
     {
         unimplemented!()
     }
@@ -236,13 +280,9 @@ impl<'a, W: Write> serde::ser::SerializeStruct for &mut FieldWriter<'a, W> {
     type Ok = ();
     type Error = FieldIOError;
 
-    fn serialize_field<T: ?Sized>(
-        &mut self,
-        _key: &'static str,
-        value: &T,
-    ) -> Result<(), Self::Error>
+    fn serialize_field<T>(&mut self, _key: &'static str, value: &T) -> Result<(), Self::Error>
     where
-        T: Serialize,
+        T: Serialize + ?Sized,
     {
         value.serialize(&mut **self)
     }
@@ -256,9 +296,9 @@ impl<'a, W: Write> serde::ser::SerializeSeq for &mut FieldWriter<'a, W> {
     type Ok = ();
     type Error = FieldIOError;
 
-    fn serialize_element<T: ?Sized>(&mut self, value: &T) -> Result<(), Self::Error>
+    fn serialize_element<T>(&mut self, value: &T) -> Result<(), Self::Error>
     where
-        T: Serialize,
+        T: Serialize + ?Sized,
     {
         value.serialize(&mut **self)
     }
@@ -268,26 +308,254 @@ impl<'a, W: Write> serde::ser::SerializeSeq for &mut FieldWriter<'a, W> {
     }
 }
 
-impl<'a, W: Write> serde::ser::SerializeMap for &mut FieldWriter<'a, W> {
+/// Writes the values of a map in the order of the fields of the writer,
+/// looking them up by the name of the fields
+///
+/// As the entries of a map come in any order (e.g. for a `HashMap`), each value
+/// is written to its own buffer, the buffers are written in the order of the fields
+/// once all the entries are known. Entries that have no field are ignored.
+pub struct MapWriter<'b, 'a, W: Write> {
+    field_writer: &'b mut FieldWriter<'a, W>,
+    key: Option<String>,
+    values: Vec<(String, Vec<u8>)>,
+}
+
+impl<'b, 'a, W: Write> serde::ser::SerializeMap for MapWriter<'b, 'a, W> {
     type Ok = ();
     type Error = FieldIOError;
 
-    fn serialize_key<T: ?Sized>(&mut self, _key: &T) -> Result<(), Self::Error>
+    fn serialize_key<T>(&mut self, key: &T) -> Result<(), Self::Error>
     where
-        T: Serialize,
+        T: Serialize + ?Sized,
     {
-        unimplemented!()
+        self.key = Some(key.serialize(FieldNameSerializer)?);
+        Ok(())
     }
 
-    fn serialize_value<T: ?Sized>(&mut self, _value: &T) -> Result<(), Self::Error>
+    fn serialize_value<T>(&mut self, value: &T) -> Result<(), Self::Error>
     where
-        T: Serialize,
+        T: Serialize + ?Sized,
     {
-        unimplemented!()
+        let key = self.key.take().ok_or_else(|| {
+            FieldIOError::new(
+                ErrorKind::Message("A map value was given without its key".to_string()),
+                None,
+            )
+        })?;
+        let field_info = match self
+            .field_writer
+            .fields_info
+            .clone()
+            .find(|field_info| field_info.name() == key)
+        {
+            Some(field_info) => field_info,
+            None => return Ok(()),
+        };
+        let mut bytes = Vec::with_capacity(field_info.length() as usize);
+        value.serialize(&mut self.field_writer.for_single_field(field_info, &mut bytes))?;
+        self.values.push((key, bytes));
+        Ok(())
     }
 
-    fn end(self) -> Result<Self::Ok, Self::Error> {
-        unimplemented!()
+    fn end(mut self) -> Result<Self::Ok, Self::Error> {
+        while let Some(field_info) = self.field_writer.fields_info.peek().copied() {
+            let position = self
+                .values
+                .iter()
+                .rposition(|(name, _)| name == field_info.name())
+                .ok_or_else(|| {
+                    FieldIOError::new(
+                        ErrorKind::Message(format!(
+                            "Could not find field named '{}' in the record map",
+                            field_info.name()
+                        )),
+                        Some(field_info.clone()),
+                    )
+                })?;
+            let (_, bytes) = self.values.swap_remove(position);
+            self.field_writer.write_next_field_raw(&bytes)?;
+        }
+        Ok(())
+    }
+}
+
+/// Serializes the keys of maps, which must be strings, to the name of their field
+struct FieldNameSerializer;
+
+impl FieldNameSerializer {
+    fn not_a_name<T>() -> Result<T, FieldIOError> {
+        Err(FieldIOError::new(
+            ErrorKind::Message("The keys of a record map must be strings".to_string()),
+            None,
+        ))
+    }
+}
+
+impl Serializer for FieldNameSerializer {
+    type Ok = String;
+    type Error = FieldIOError;
+    type SerializeSeq = Impossible<String, FieldIOError>;
+    type SerializeTuple = Impossible<String, FieldIOError>;
+    type SerializeTupleStruct = Impossible<String, FieldIOError>;
+    type SerializeTupleVariant = Impossible<String, FieldIOError>;
+    type SerializeMap = Impossible<String, FieldIOError>;
+    type SerializeStruct = Impossible<String, FieldIOError>;
+    type SerializeStructVariant = Impossible<String, FieldIOError>;
+
+    fn serialize_str(self, v: &str) -> Result<Self::Ok, Self::Error> {
+        Ok(v.to_owned())
+    }
+
+    fn serialize_char(self, v: char) -> Result<Self::Ok, Self::Error> {
+        Ok(v.to_string())
+    }
+
+    fn serialize_newtype_struct<T>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_bool(self, _v: bool) -> Result<Self::Ok, Self::Error> {
+        Self::not_a_name()
+    }
+
+    fn serialize_i8(self, _v: i8) -> Result<Self::Ok, Self::Error> {
+        Self::not_a_name()
+    }
+
+    fn serialize_i16(self, _v: i16) -> Result<Self::Ok, Self::Error> {
+        Self::not_a_name()
+    }
+
+    fn serialize_i32(self, _v: i32) -> Result<Self::Ok, Self::Error> {
+        Self::not_a_name()
+    }
+
+    fn serialize_i64(self, _v: i64) -> Result<Self::Ok, Self::Error> {
+        Self::not_a_name()
+    }
+
+    fn serialize_u8(self, _v: u8) -> Result<Self::Ok, Self::Error> {
+        Self::not_a_name()
+    }
+
+    fn serialize_u16(self, _v: u16) -> Result<Self::Ok, Self::Error> {
+        Self::not_a_name()
+    }
+
+    fn serialize_u32(self, _v: u32) -> Result<Self::Ok, Self::Error> {
+        Self::not_a_name()
+    }
+
+    fn serialize_u64(self, _v: u64) -> Result<Self::Ok, Self::Error> {
+        Self::not_a_name()
+    }
+
+    fn serialize_f32(self, _v: f32) -> Result<Self::Ok, Self::Error> {
+        Self::not_a_name()
+    }
+
+    fn serialize_f64(self, _v: f64) -> Result<Self::Ok, Self::Error> {
+        Self::not_a_name()
+    }
+
+    fn serialize_bytes(self, _v: &[u8]) -> Result<Self::Ok, Self::Error> {
+        Self::not_a_name()
+    }
+
+    fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+        Self::not_a_name()
+    }
+
+    fn serialize_some<T>(self, _value: &T) -> Result<Self::Ok, Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        Self::not_a_name()
+    }
+
+    fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
+        Self::not_a_name()
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Self::Ok, Self::Error> {
+        Self::not_a_name()
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Self::Ok, Self::Error> {
+        Ok(variant.to_owned())
+    }
+
+    fn serialize_newtype_variant<T>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<Self::Ok, Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        Self::not_a_name()
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        Self::not_a_name()
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        Self::not_a_name()
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        Self::not_a_name()
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        Self::not_a_name()
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        Self::not_a_name()
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        Self::not_a_name()
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        Self::not_a_name()
     }
 }
 
@@ -295,9 +563,9 @@ impl<'a, W: Write> serde::ser::SerializeTupleVariant for &mut FieldWriter<'a, W>
     type Ok = ();
     type Error = FieldIOError;
 
-    fn serialize_field<T: ?Sized>(&mut self, _value: &T) -> Result<(), Self::Error>
+    fn serialize_field<T>(&mut self, _value: &T) -> Result<(), Self::Error>
     where
-        T: Serialize,
+        T: Serialize + ?Sized,
     {
         unimplemented!()
     }
@@ -311,9 +579,9 @@ impl<'a, W: Write> serde::ser::SerializeTupleStruct for &mut FieldWriter<'a, W>
     type Ok = ();
     type Error = FieldIOError;
 
-    fn serialize_field<T: ?Sized>(&mut self, value: &T) -> Result<(), Self::Error>
+    fn serialize_field<T>(&mut self, value: &T) -> Result<(), Self::Error>
     where
-        T: Serialize,
+        T: Serialize + ?Sized,
     {
         value.serialize(&mut **self)
     }
@@ -327,9 +595,9 @@ impl<'a, W: Write> serde::ser::SerializeTuple for &mut FieldWriter<'a, W> {
     type Ok = ();
     type Error = FieldIOError;
 
-    fn serialize_element<T: ?Sized>(&mut self, value: &T) -> Result<(), Self::Error>
+    fn serialize_element<T>(&mut self, value: &T) -> Result<(), Self::Error>
     where
-        T: Serialize,
+        T: Serialize + ?Sized,
     {
         value.serialize(&mut **self)
     }
@@ -0,0 +1,131 @@
+//! Sorting records read in memory by the values of their fields.
+use std::cmp::Ordering;
+
+use crate::{FieldValue, Record};
+
+/// Order in which [sort_records_by_multiple] sorts the values of a field
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SortOrder {
+    /// Smallest values first
+    Ascending,
+    /// Largest values first
+    Descending,
+}
+
+/// Errors returned when records cannot be sorted
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SortError {
+    /// A record does not have the field
+    FieldNotFound(String),
+    /// The values of the field cannot be compared,
+    /// they hold different kinds of data (e.g. numbers and text), or one is NaN
+    IncomparableValues(String),
+}
+
+impl std::fmt::Display for SortError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SortError::FieldNotFound(name) => {
+                write!(f, "Could not find field named '{}' in the record", name)
+            }
+            SortError::IncomparableValues(name) => {
+                write!(f, "The values of the field '{}' cannot be compared", name)
+            }
+        }
+    }
+}
+
+impl std::error::Error for SortError {}
+
+/// Sorts the records in ascending order of the values of the field
+///
+/// The values are compared with their `PartialOrd` implementation,
+/// null values come first. The sort is stable.
+///
+/// # Example
+///
+/// ```
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut records = dbase::read("tests/data/stations.dbf")?;
+/// dbase::sort_records_by(&mut records, "name")?;
+/// assert_eq!(
+///     records[0].get("name"),
+///     Some(&dbase::FieldValue::Character(Some("Capitol South".to_string())))
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub fn sort_records_by(records: &mut [Record], field_name: &str) -> Result<(), SortError> {
+    sort_records_by_multiple(records, &[(field_name, SortOrder::Ascending)])
+}
+
+/// Sorts the records in descending order of the values of the field,
+/// see [sort_records_by]
+pub fn sort_records_by_descending(
+    records: &mut [Record],
+    field_name: &str,
+) -> Result<(), SortError> {
+    sort_records_by_multiple(records, &[(field_name, SortOrder::Descending)])
+}
+
+/// Sorts the records by the values of several fields
+///
+/// Records are sorted by the first field, records with the same value
+/// for the first field are sorted by the second one, and so on.
+///
+/// # Example
+///
+/// ```
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use dbase::SortOrder;
+///
+/// let mut records = dbase::read("tests/data/stations.dbf")?;
+/// dbase::sort_records_by_multiple(
+///     &mut records,
+///     &[("line", SortOrder::Descending), ("name", SortOrder::Ascending)],
+/// )?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn sort_records_by_multiple(
+    records: &mut [Record],
+    fields: &[(&str, SortOrder)],
+) -> Result<(), SortError> {
+    for (field_name, _) in fields {
+        check_comparable(records, field_name)?;
+    }
+    // The values were checked to be comparable, so the ordering is total
+    records.sort_by(|a, b| {
+        fields
+            .iter()
+            .map(|&(field_name, order)| {
+                let ordering = a
+                    .get(field_name)
+                    .partial_cmp(&b.get(field_name))
+                    .unwrap_or(Ordering::Equal);
+                match order {
+                    SortOrder::Ascending => ordering,
+                    SortOrder::Descending => ordering.reverse(),
+                }
+            })
+            .find(|ordering| *ordering != Ordering::Equal)
+            .unwrap_or(Ordering::Equal)
+    });
+    Ok(())
+}
+
+/// Checks that all the records have the field, and that its values can be compared
+fn check_comparable(records: &[Record], field_name: &str) -> Result<(), SortError> {
+    let mut first_value: Option<&FieldValue> = None;
+    for record in records {
+        let value = record
+            .get(field_name)
+            .ok_or_else(|| SortError::FieldNotFound(field_name.to_string()))?;
+        let reference = *first_value.get_or_insert(value);
+        // Values that are not equal to themselves (NaN) break the ordering
+        if value.partial_cmp(reference).is_none() || value.partial_cmp(value).is_none() {
+            return Err(SortError::IncomparableValues(field_name.to_string()));
+        }
+    }
+    Ok(())
+}
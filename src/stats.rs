@@ -0,0 +1,258 @@
+//! Summary statistics of the values of a column.
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use std::io::{Read, Seek};
+
+use crate::{Error, ErrorKind, FieldInfo, FieldType, FieldValue, Reader};
+
+/// Statistics of the values of a field, computed by [column_stats]
+///
+/// The statistics that do not apply to the type of the field are left to 0.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColumnStats {
+    /// Number of non-null values
+    pub count: u64,
+    /// Number of null values
+    pub null_count: u64,
+    /// Smallest value, `None` if all the values are null
+    pub min: Option<FieldValue>,
+    /// Largest value, `None` if all the values are null
+    pub max: Option<FieldValue>,
+    /// Sum of the values of numeric fields
+    pub sum: f64,
+    /// Mean of the values of numeric fields, `NaN` for other fields or if there are no values
+    pub mean: f64,
+    /// Length in chars of the shortest value of Character & Memo fields
+    pub min_length: usize,
+    /// Length in chars of the longest value of Character & Memo fields
+    pub max_length: usize,
+    /// Number of distinct values of Character & Memo fields
+    pub distinct_count: usize,
+    /// Number of `true` values of Logical fields
+    pub true_count: u64,
+    /// Number of `false` values of Logical fields
+    pub false_count: u64,
+}
+
+impl ColumnStats {
+    fn new() -> Self {
+        Self {
+            count: 0,
+            null_count: 0,
+            min: None,
+            max: None,
+            sum: 0.0,
+            mean: f64::NAN,
+            min_length: 0,
+            max_length: 0,
+            distinct_count: 0,
+            true_count: 0,
+            false_count: 0,
+        }
+    }
+
+    fn add(&mut self, value: FieldValue, distinct_values: &mut HashSet<String>) {
+        if is_null(&value) {
+            self.null_count += 1;
+            return;
+        }
+        self.count += 1;
+        if let Some(number) = as_f64(&value) {
+            self.sum += number;
+        }
+        match &value {
+            FieldValue::Character(Some(text)) | FieldValue::Memo(text) => {
+                let length = text.chars().count();
+                if self.count == 1 || length < self.min_length {
+                    self.min_length = length;
+                }
+                self.max_length = self.max_length.max(length);
+                if !distinct_values.contains(text) {
+                    distinct_values.insert(text.clone());
+                }
+            }
+            FieldValue::Logical(Some(true)) => self.true_count += 1,
+            FieldValue::Logical(Some(false)) => self.false_count += 1,
+            _ => {}
+        }
+
+        if self
+            .min
+            .as_ref()
+            .is_none_or(|min| value.partial_cmp(min) == Some(Ordering::Less))
+        {
+            self.min = Some(value.clone());
+        }
+        if self
+            .max
+            .as_ref()
+            .is_none_or(|max| value.partial_cmp(max) == Some(Ordering::Greater))
+        {
+            self.max = Some(value);
+        }
+    }
+}
+
+/// Computes the statistics of the values of the field named `field_name`
+///
+/// The records are read one at a time, and the records marked as deleted are ignored.
+///
+/// # Example
+///
+/// ```
+/// # fn main() -> Result<(), dbase::Error> {
+/// let reader = dbase::Reader::from_path("tests/data/stations.dbf")?;
+/// let stats = dbase::column_stats(reader, "line")?;
+/// assert_eq!(stats.count, 6);
+/// assert_eq!(stats.distinct_count, 2);
+/// # Ok(())
+/// # }
+/// ```
+pub fn column_stats<T: Read + Seek>(
+    mut reader: Reader<T>,
+    field_name: &str,
+) -> Result<ColumnStats, Error> {
+    let field_type = find_field(&reader, field_name)?.field_type();
+    let mut stats = ColumnStats::new();
+    let mut distinct_values = HashSet::new();
+    for_each_value(&mut reader, field_name, |value| {
+        stats.add(value, &mut distinct_values)
+    })?;
+    stats.distinct_count = distinct_values.len();
+    if is_numeric(field_type) && stats.count > 0 {
+        stats.mean = stats.sum / stats.count as f64;
+    }
+    Ok(stats)
+}
+
+/// Counts the values of the numeric field named `field_name` in each of `bucket_count` buckets
+///
+/// The buckets have the same width and span from the smallest to the largest value,
+/// the last bucket includes the largest value.
+/// The records are read twice, once to find the range of the values and once to count them.
+///
+/// # Example
+///
+/// ```no_run
+/// # fn main() -> Result<(), dbase::Error> {
+/// let reader = dbase::Reader::from_path("products.dbf")?;
+/// let counts = dbase::column_histogram(reader, "price", 10)?;
+/// assert_eq!(counts.len(), 10);
+/// # Ok(())
+/// # }
+/// ```
+pub fn column_histogram<T: Read + Seek>(
+    mut reader: Reader<T>,
+    field_name: &str,
+    bucket_count: usize,
+) -> Result<Vec<u64>, Error> {
+    let field_type = find_field(&reader, field_name)?.field_type();
+    if !is_numeric(field_type) {
+        return Err(stats_error(format!(
+            "Cannot compute the histogram of '{}', a {:?} field",
+            field_name, field_type
+        )));
+    }
+    if bucket_count == 0 {
+        return Err(stats_error(
+            "The histogram must have at least one bucket".to_string(),
+        ));
+    }
+
+    let mut range: Option<(f64, f64)> = None;
+    for_each_value(&mut reader, field_name, |value| {
+        if let Some(number) = as_f64(&value).filter(|number| !number.is_nan()) {
+            range = Some(match range {
+                Some((min, max)) => (min.min(number), max.max(number)),
+                None => (number, number),
+            });
+        }
+    })?;
+
+    let mut counts = vec![0u64; bucket_count];
+    let (min, max) = match range {
+        Some(range) => range,
+        None => return Ok(counts),
+    };
+    let width = (max - min) / bucket_count as f64;
+    for_each_value(&mut reader, field_name, |value| {
+        if let Some(number) = as_f64(&value).filter(|number| !number.is_nan()) {
+            let bucket = if width > 0.0 {
+                (((number - min) / width) as usize).min(bucket_count - 1)
+            } else {
+                0
+            };
+            counts[bucket] += 1;
+        }
+    })?;
+    Ok(counts)
+}
+
+/// Calls `f` with the value of the field of each record that is not marked as deleted
+fn for_each_value<T, F>(reader: &mut Reader<T>, field_name: &str, mut f: F) -> Result<(), Error>
+where
+    T: Read + Seek,
+    F: FnMut(FieldValue),
+{
+    let num_records = reader.header().num_records as usize;
+    let mut records = reader.iter_records_range(0, num_records)?;
+    while let Some(result) = records.next_field_value(field_name) {
+        if let Some(value) = result? {
+            f(value);
+        }
+    }
+    Ok(())
+}
+
+fn find_field<'a, T: Read + Seek>(
+    reader: &'a Reader<T>,
+    field_name: &str,
+) -> Result<&'a FieldInfo, Error> {
+    reader
+        .fields()
+        .iter()
+        .find(|info| !info.is_hidden() && info.name() == field_name)
+        .ok_or_else(|| stats_error(format!("Could not find field named '{}'", field_name)))
+}
+
+fn is_numeric(field_type: FieldType) -> bool {
+    matches!(
+        field_type,
+        FieldType::Numeric
+            | FieldType::Float
+            | FieldType::Integer
+            | FieldType::AutoIncrement
+            | FieldType::Currency
+            | FieldType::Double
+    )
+}
+
+fn is_null(value: &FieldValue) -> bool {
+    matches!(
+        value,
+        FieldValue::Character(None)
+            | FieldValue::Numeric(None)
+            | FieldValue::Float(None)
+            | FieldValue::Logical(None)
+            | FieldValue::Date(None)
+    )
+}
+
+fn as_f64(value: &FieldValue) -> Option<f64> {
+    match value {
+        FieldValue::Numeric(value) => *value,
+        FieldValue::Float(value) => value.map(f64::from),
+        FieldValue::Integer(value) | FieldValue::AutoIncrement(value) => Some(f64::from(*value)),
+        FieldValue::Currency(value) => Some(value.to_f64()),
+        FieldValue::Double(value) => Some(*value),
+        _ => None,
+    }
+}
+
+fn stats_error(message: String) -> Error {
+    Error {
+        record_num: 0,
+        field: None,
+        kind: ErrorKind::Message(message),
+    }
+}
@@ -0,0 +1,188 @@
+//! Editing a table in memory: its records are read, modified,
+//! then written back to the file.
+use std::path::{Path, PathBuf};
+
+use crate::reading::TableInfo;
+use crate::{
+    Error, ErrorKind, FieldInfo, FieldType, FieldValue, Reader, Record, TableWriterBuilder,
+};
+
+/// A table whose records are all loaded in memory, to be edited then saved
+///
+/// The fields of the table are kept separately from the records,
+/// so that columns can be added or removed before saving.
+///
+/// Records marked as deleted are not loaded, and are thus not saved.
+/// As the writer cannot write Memo values, saving a table with Memo fields
+/// returns an error (its Memo fields can be removed first).
+///
+/// # Example
+///
+/// ```
+/// # fn main() -> Result<(), dbase::Error> {
+/// use dbase::{FieldInfo, FieldName, FieldType, FieldValue, Table};
+/// use std::convert::TryFrom;
+/// # std::fs::copy("tests/data/stations.dbf", "edited_stations.dbf").unwrap();
+///
+/// let mut table = Table::open("edited_stations.dbf")?;
+/// table.remove_field("marker-sym")?;
+/// table.add_field(
+///     FieldInfo::new(FieldName::try_from("visited").unwrap(), FieldType::Logical, 1).unwrap(),
+///     FieldValue::Logical(Some(false)),
+/// )?;
+/// table.records_mut()[0].insert("visited".to_string(), FieldValue::Logical(Some(true)));
+/// table.save()?;
+///
+/// let table = Table::open("edited_stations.dbf")?;
+/// assert_eq!(table.records()[0].get("visited"), Some(&FieldValue::Logical(Some(true))));
+/// assert_eq!(table.records()[0].get("marker-sym"), None);
+/// # std::fs::remove_file("edited_stations.dbf").unwrap();
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct Table {
+    path: PathBuf,
+    table_info: TableInfo,
+    records: Vec<Record>,
+}
+
+impl Table {
+    /// Opens the table at `path` and reads all its records
+    ///
+    /// The encoding is chosen as in [Reader::from_path].
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        let path = path.as_ref();
+        let mut reader = Reader::from_path(path)?;
+        let records = reader.read()?;
+        Ok(Self {
+            path: path.to_path_buf(),
+            table_info: reader.into_table_info(),
+            records,
+        })
+    }
+
+    /// Returns the path of the file the table is saved to by [save](#method.save)
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the fields of the table, without the columns that are not
+    /// part of the values of records (deletion flag, `_NullFlags`)
+    pub fn fields(&self) -> impl Iterator<Item = &FieldInfo> {
+        self.table_info
+            .fields_info
+            .iter()
+            .filter(|field| !field.is_hidden())
+    }
+
+    /// Returns the records of the table
+    pub fn records(&self) -> &[Record] {
+        &self.records
+    }
+
+    /// Returns the records of the table, to modify their values
+    pub fn records_mut(&mut self) -> &mut [Record] {
+        &mut self.records
+    }
+
+    /// Adds a field after the existing ones, its value is `default` in all the records
+    ///
+    /// Fails if the table already has a field with the same name (ignoring the ASCII case),
+    /// or if `default` is not a value of the type of the field.
+    pub fn add_field(&mut self, info: FieldInfo, default: FieldValue) -> Result<(), Error> {
+        if self
+            .fields()
+            .any(|field| field.name().eq_ignore_ascii_case(info.name()))
+        {
+            return Err(table_error(format!(
+                "The table already has a field named '{}'",
+                info.name()
+            )));
+        }
+        if default.field_type() != info.field_type() {
+            return Err(Error {
+                record_num: 0,
+                field: Some(info),
+                kind: ErrorKind::IncompatibleType,
+            });
+        }
+
+        for record in &mut self.records {
+            record.insert(info.name().to_owned(), default.clone());
+        }
+        // The `_NullFlags` column, if any, stays after the other fields
+        let fields = &mut self.table_info.fields_info;
+        let position = fields
+            .iter()
+            .position(|field| field.is_null_flags())
+            .unwrap_or(fields.len());
+        fields.insert(position, info);
+        Ok(())
+    }
+
+    /// Removes the field named `name` from the table and its records,
+    /// returns its information
+    pub fn remove_field(&mut self, name: &str) -> Result<FieldInfo, Error> {
+        let position = self
+            .table_info
+            .fields_info
+            .iter()
+            .position(|field| !field.is_hidden() && field.name() == name)
+            .ok_or_else(|| table_error(format!("Could not find field named '{}'", name)))?;
+        for record in &mut self.records {
+            record.remove(name);
+        }
+        Ok(self.table_info.fields_info.remove(position))
+    }
+
+    /// Writes the table back to the file it was opened from
+    ///
+    /// The table is first written to a temporary file next to it,
+    /// which then replaces the original file, so that the original file
+    /// is left untouched if an error happens.
+    pub fn save(&self) -> Result<(), Error> {
+        let mut temp_path = self.path.clone().into_os_string();
+        temp_path.push(".tmp");
+        let temp_path = PathBuf::from(temp_path);
+
+        let result = self.save_as(&temp_path).and_then(|_| {
+            std::fs::rename(&temp_path, &self.path).map_err(|error| Error::io_error(error, 0))
+        });
+        if result.is_err() {
+            let _ = std::fs::remove_file(&temp_path);
+        }
+        result
+    }
+
+    /// Writes the table to a new file at `path`,
+    /// the table is still saved to its own file by [save](#method.save)
+    ///
+    /// Fails, before creating the file, if the table has Memo fields.
+    pub fn save_as<P: AsRef<Path>>(&self, path: P) -> Result<(), Error> {
+        if let Some(info) = self
+            .fields()
+            .find(|info| info.field_type() == FieldType::Memo)
+        {
+            return Err(Error {
+                record_num: 0,
+                field: Some(info.clone()),
+                kind: ErrorKind::Message(format!(
+                    "The Memo field '{}' cannot be written",
+                    info.name()
+                )),
+            });
+        }
+        TableWriterBuilder::from_table_info(&self.table_info)
+            .build_with_file_dest(path)?
+            .write_records(&self.records)
+    }
+}
+
+fn table_error(message: String) -> Error {
+    Error {
+        record_num: 0,
+        field: None,
+        kind: ErrorKind::Message(message),
+    }
+}
@@ -0,0 +1,69 @@
+//! Helpers to test how values are written & read back,
+//! available with the `test-util` feature.
+use std::io::{Cursor, Write};
+
+use crate::{
+    FieldIOError, FieldInfo, FieldValue, FieldWriter, Reader, TableWriterBuilder,
+    WritableAsDbaseField, WritableRecord,
+};
+
+/// Record made of a single value
+struct SingleValue<'v, T>(&'v T);
+
+impl<T: WritableAsDbaseField> WritableRecord for SingleValue<'_, T> {
+    fn write_using<'a, W: Write>(
+        &self,
+        field_writer: &mut FieldWriter<'a, W>,
+    ) -> Result<(), FieldIOError> {
+        field_writer.write_next_field_value(self.0)
+    }
+}
+
+/// Writes `value` to a table with the single field `field_info`,
+/// then reads the table and returns the value as it is read back
+///
+/// The value goes through the same steps as when writing a file, with the
+/// default [WritingOptions](crate::WritingOptions) and the UTF-8 encoding,
+/// which makes it easy to check that a type is written as expected.
+///
+/// # Panics
+///
+/// Panics if the value cannot be written to the field or cannot be read back,
+/// e.g. for Memo fields, that the writer does not support.
+///
+/// # Example
+///
+/// ```
+/// use dbase::testing::round_trip;
+/// use dbase::{FieldInfo, FieldName, FieldType, FieldValue};
+/// use std::convert::TryFrom;
+///
+/// let info = FieldInfo::new(FieldName::try_from("PRICE").unwrap(), FieldType::Numeric, 10)
+///     .unwrap()
+///     .with_decimals(2)
+///     .unwrap();
+/// assert_eq!(round_trip(&info, &12.5f64), FieldValue::Numeric(Some(12.5)));
+/// assert_eq!(round_trip(&info, &7u8), FieldValue::Numeric(Some(7.0)));
+/// ```
+pub fn round_trip<T: WritableAsDbaseField>(field_info: &FieldInfo, value: &T) -> FieldValue {
+    let mut writer = TableWriterBuilder::from_fields(vec![field_info.clone()])
+        .build_with_dest(Cursor::new(Vec::<u8>::new()));
+    if let Err(error) = writer.write_record(&SingleValue(value)) {
+        panic!("Failed to write the value: {}", error);
+    }
+    let mut dst = writer
+        .finish()
+        .unwrap_or_else(|error| panic!("Failed to finish the table: {}", error));
+    dst.set_position(0);
+
+    let mut reader =
+        Reader::new(dst).unwrap_or_else(|error| panic!("Failed to read the table: {}", error));
+    let mut record = reader
+        .iter_records()
+        .next()
+        .expect("The table has no record")
+        .unwrap_or_else(|error| panic!("Failed to read the value: {}", error));
+    record
+        .remove(field_info.name())
+        .expect("The record has no value for the field")
+}
@@ -1,19 +1,22 @@
 //! Module with all structs & functions charged of writing .dbf file content
-use std::fs::File;
-use std::io::{BufWriter, Cursor, Seek, SeekFrom, Write};
+use std::borrow::Borrow;
+#[cfg(not(feature = "serde"))]
+use std::collections::HashMap;
+use std::fs::{File, OpenOptions};
+use std::io::{BufWriter, Cursor, Read, Seek, SeekFrom, Write};
 use std::path::Path;
+use std::sync::Arc;
 
-use byteorder::WriteBytesExt;
+use byteorder::{ReadBytesExt, WriteBytesExt};
 use encoding_rs::Encoding;
 
-use crate::header::Header;
+use crate::header::{code_page_mark_for_encoding, Header, Version};
+use crate::progress::Progress;
 use crate::reading::TableInfo;
-use crate::reading::TERMINATOR_VALUE;
-use crate::record::{field::FieldType, FieldInfo, FieldName};
-use crate::{Error, ErrorKind, FieldIOError, Record};
-
-/// A dbase file ends with this byte
-const FILE_TERMINATOR: u8 = 0x1A;
+use crate::reading::{BACKLINK_SIZE, FILE_TERMINATOR, TERMINATOR_VALUE};
+use crate::record::field::{FieldType, DEFAULT_MEMO_BLOCK_SIZE};
+use crate::record::{assign_null_flag_bits, FieldInfo, FieldName, MAX_CHARACTER_LENGTH};
+use crate::{Error, ErrorKind, FieldIOError, FieldValue, Record};
 
 /// Builder to be used to create a [TableWriter](struct.TableWriter.html).
 ///
@@ -32,14 +35,21 @@ const FILE_TERMINATOR: u8 = 0x1A;
 /// use std::io::Cursor;
 ///
 /// let writer = TableWriterBuilder::new()
-///     .add_character_field(FieldName::try_from("First Name").unwrap(), 50)
-///     .add_character_field(FieldName::try_from("Last Name").unwrap(), 50)
+///     .add_character_field(FieldName::try_from("First_Name").unwrap(), 50)
+///     .add_character_field(FieldName::try_from("Last_Name").unwrap(), 50)
 ///     .build_with_dest(Cursor::new(Vec::<u8>::new()));
 /// ```
+///
+/// The builder can be cloned to write several files with the same fields.
+#[derive(Clone, Debug)]
 pub struct TableWriterBuilder {
     v: Vec<FieldInfo>,
     hdr: Header,
     encoding: &'static Encoding,
+    options: WritingOptions,
+    memo_block_size: u32,
+    /// Version set with [with_version](#method.with_version)
+    version: Option<Version>,
 }
 
 impl TableWriterBuilder {
@@ -87,12 +97,11 @@ impl TableWriterBuilder {
     pub fn from_reader<T: std::io::Read + std::io::Seek>(
         reader: crate::reading::Reader<T>,
     ) -> Self {
-        let encoding = reader.encoding();
-        Self::_from_table_info(reader.into_table_info(), encoding)
+        Self::from_table_info(reader.into_table_info())
     }
 
-    fn _from_table_info(table_info: TableInfo, encoding: &'static Encoding) -> Self {
-        let mut fields_info = table_info.fields_info;
+    fn _from_table_info(table_info: &TableInfo, encoding: &'static Encoding) -> Self {
+        let mut fields_info = table_info.fields_info.clone();
         if let Some(i) = fields_info.first() {
             if i.is_deletion_flag() {
                 fields_info.remove(0);
@@ -100,18 +109,51 @@ impl TableWriterBuilder {
         }
         let mut hdr = table_info.header;
         hdr.update_date();
+        // The records of the original file are not part of the new one
+        hdr.num_records = 0;
         Self {
             v: fields_info,
             hdr,
             encoding,
+            options: WritingOptions::default(),
+            memo_block_size: table_info
+                .memo_block_size
+                .unwrap_or(DEFAULT_MEMO_BLOCK_SIZE),
+            version: None,
         }
     }
 
-    pub fn from_table_info(table_info: TableInfo) -> Self {
-        Self::_from_table_info(table_info, encoding_rs::UTF_8)
+    /// Gets the field definition, the encoding and the memo block size
+    /// of the table to construct the TableWriter
+    ///
+    /// The table info can be given by value or by reference.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), dbase::Error> {
+    /// use dbase::TableWriterBuilder;
+    /// use std::io::Cursor;
+    ///
+    /// let mut reader = dbase::Reader::from_path("tests/data/stations.dbf")?;
+    /// let stations = reader.read()?;
+    /// let table_info = reader.into_table_info();
+    ///
+    /// let writer = TableWriterBuilder::from_table_info(&table_info)
+    ///     .build_with_dest(Cursor::new(Vec::<u8>::new()));
+    /// writer.write_records(&stations)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_table_info<I: Borrow<TableInfo>>(table_info: I) -> Self {
+        let table_info = table_info.borrow();
+        Self::_from_table_info(table_info, table_info.encoding)
     }
 
-    pub fn from_table_info_with_label(table_info: TableInfo, label: &str) -> Result<Self, Error> {
+    pub fn from_table_info_with_label<I: Borrow<TableInfo>>(
+        table_info: I,
+        label: &str,
+    ) -> Result<Self, Error> {
         let encoding = Encoding::for_label(label.as_bytes());
         if encoding.is_none() {
             return Err(Error {
@@ -121,24 +163,218 @@ impl TableWriterBuilder {
             });
         }
 
-        Ok(Self::_from_table_info(table_info, encoding.unwrap()))
+        Ok(Self::_from_table_info(
+            table_info.borrow(),
+            encoding.unwrap(),
+        ))
+    }
+
+    /// Creates a builder with the given fields,
+    /// created with [FieldInfo::new_with_precision] or taken from another table
+    ///
+    /// The deletion flag of fields taken from a [Reader](crate::Reader) is ignored.
+    /// Fields with the same name are rejected when the writer is built
+    /// with [build_with_file_dest](#method.build_with_file_dest) or when its header is written.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use dbase::{FieldInfo, FieldName, FieldType, TableWriterBuilder};
+    /// use std::convert::TryFrom;
+    /// use std::io::Cursor;
+    ///
+    /// let fields = vec![
+    ///     FieldInfo::new_with_precision(FieldName::try_from("NAME")?, FieldType::Character, 20, 0)?,
+    ///     FieldInfo::new_with_precision(FieldName::try_from("PRICE")?, FieldType::Numeric, 10, 2)?,
+    /// ];
+    /// let writer = TableWriterBuilder::from_fields(fields)
+    ///     .build_with_dest(Cursor::new(Vec::<u8>::new()));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_fields<I: IntoIterator<Item = FieldInfo>>(fields: I) -> Self {
+        let mut builder = Self::new();
+        builder.v = fields
+            .into_iter()
+            .filter(|info| !info.is_deletion_flag())
+            .collect();
+        builder
     }
 
     pub fn set_encoding(&mut self, encoding: &'static Encoding) {
         self.encoding = encoding;
     }
 
+    /// Sets the characters used to write Logical values
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dbase::{LogicalStyle, TableWriterBuilder};
+    /// use std::convert::TryFrom;
+    /// use std::io::Cursor;
+    ///
+    /// let writer = TableWriterBuilder::new()
+    ///     .add_logical_field(dbase::FieldName::try_from("Available").unwrap())
+    ///     .logical_style(LogicalStyle::TrueFalseLower)
+    ///     .build_with_dest(Cursor::new(Vec::<u8>::new()));
+    /// ```
+    pub fn logical_style(mut self, style: LogicalStyle) -> Self {
+        self.options.logical_style = style;
+        self
+    }
+
+    /// Sets how empty Date values are written
+    ///
+    /// Both styles are read as `None`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dbase::{EmptyDateStyle, TableWriterBuilder};
+    /// use std::convert::TryFrom;
+    /// use std::io::Cursor;
+    ///
+    /// let writer = TableWriterBuilder::new()
+    ///     .add_date_field(dbase::FieldName::try_from("Returned").unwrap())
+    ///     .empty_date_style(EmptyDateStyle::Zeros)
+    ///     .build_with_dest(Cursor::new(Vec::<u8>::new()));
+    /// ```
+    pub fn empty_date_style(mut self, style: EmptyDateStyle) -> Self {
+        self.options.empty_date_style = style;
+        self
+    }
+
+    /// Sets what happens when a Character value is longer than its field,
+    /// by default an error is returned
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dbase::{TableWriterBuilder, TruncationPolicy};
+    /// use std::convert::TryFrom;
+    /// use std::io::Cursor;
+    ///
+    /// let writer = TableWriterBuilder::new()
+    ///     .add_character_field(dbase::FieldName::try_from("Comment").unwrap(), 20)
+    ///     .truncation_policy(TruncationPolicy::Truncate)
+    ///     .build_with_dest(Cursor::new(Vec::<u8>::new()));
+    /// ```
+    pub fn truncation_policy(mut self, policy: TruncationPolicy) -> Self {
+        self.options.truncation_policy = policy;
+        self
+    }
+
+    /// Sets what happens when a Numeric or Float value, sign and decimals included,
+    /// needs more characters than its field has, by default an error is returned
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dbase::{NumericOverflowPolicy, TableWriterBuilder};
+    /// use std::convert::TryFrom;
+    /// use std::io::Cursor;
+    ///
+    /// let writer = TableWriterBuilder::new()
+    ///     .add_numeric_field(dbase::FieldName::try_from("Price").unwrap(), 6, 2)
+    ///     .numeric_overflow_policy(NumericOverflowPolicy::Asterisks)
+    ///     .build_with_dest(Cursor::new(Vec::<u8>::new()));
+    /// ```
+    pub fn numeric_overflow_policy(mut self, policy: NumericOverflowPolicy) -> Self {
+        self.options.numeric_overflow_policy = policy;
+        self
+    }
+
+    /// Sets how Numeric & Float values are aligned in their field,
+    /// by default they are right-aligned
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dbase::{NumericAlignment, TableWriterBuilder};
+    /// use std::convert::TryFrom;
+    /// use std::io::Cursor;
+    ///
+    /// let writer = TableWriterBuilder::new()
+    ///     .add_numeric_field(dbase::FieldName::try_from("Price").unwrap(), 10, 2)
+    ///     .numeric_alignment(NumericAlignment::Left)
+    ///     .build_with_dest(Cursor::new(Vec::<u8>::new()));
+    /// ```
+    pub fn numeric_alignment(mut self, alignment: NumericAlignment) -> Self {
+        self.options.numeric_alignment = alignment;
+        self
+    }
+
+    /// Sets how `f64` & `f32` values are rounded to the decimal places
+    /// of Numeric & Float fields, by default half-way values are rounded to even
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dbase::{RoundingMode, TableWriterBuilder};
+    /// use std::convert::TryFrom;
+    /// use std::io::Cursor;
+    ///
+    /// let writer = TableWriterBuilder::new()
+    ///     .add_numeric_field(dbase::FieldName::try_from("Price").unwrap(), 10, 2)
+    ///     .numeric_rounding(RoundingMode::HalfAwayFromZero)
+    ///     .build_with_dest(Cursor::new(Vec::<u8>::new()));
+    /// ```
+    pub fn numeric_rounding(mut self, rounding: RoundingMode) -> Self {
+        self.options.numeric_rounding = rounding;
+        self
+    }
+
+    /// Sets the size of the blocks of the memo file, 512 bytes by default
+    ///
+    /// Small blocks waste less space when storing many short memos,
+    /// large blocks suit large memos.
+    ///
+    /// When created [from a reader](#method.from_reader) with a memo file,
+    /// the block size of that memo file is used.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the size is 0 or does not fit on 16 bits,
+    /// as it is stored on 2 bytes in the header of the memo file.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dbase::TableWriterBuilder;
+    /// use std::io::Cursor;
+    ///
+    /// let writer = TableWriterBuilder::new()
+    ///     .memo_block_size(64)
+    ///     .build_with_dest(Cursor::new(Vec::<u8>::new()));
+    /// assert_eq!(writer.memo_block_size(), 64);
+    /// ```
+    pub fn memo_block_size(mut self, block_size: u32) -> Self {
+        assert!(
+            block_size != 0 && block_size <= u32::from(u16::MAX),
+            "memo block size must be between 1 and {}, got {}",
+            u16::MAX,
+            block_size
+        );
+        self.memo_block_size = block_size;
+        self
+    }
+
     /// Adds a Character field to the record definition,
     /// the length is the maximum number of bytes (not chars) that fields can hold
+    ///
+    /// The length must be between 1 and 254, it is checked when the writer
+    /// writes the header, see [build_with_dest](#method.build_with_dest).
     pub fn add_character_field(mut self, name: FieldName, length: u8) -> Self {
         self.v
-            .push(FieldInfo::new(name, FieldType::Character, length));
+            .push(FieldInfo::new_unchecked(name, FieldType::Character, length));
         self
     }
 
     /// Adds a [Date](struct.Date.html) field
     pub fn add_date_field(mut self, name: FieldName) -> Self {
-        self.v.push(FieldInfo::new(
+        self.v.push(FieldInfo::new_unchecked(
             name,
             FieldType::Date,
             FieldType::Date.size().unwrap(),
@@ -147,16 +383,22 @@ impl TableWriterBuilder {
     }
 
     /// Adds a [Numeric](enum.FieldValue.html#variant.Numeric)
+    ///
+    /// The number of decimals can be at most the length minus 2, it is checked
+    /// when the writer writes the header, see [build_with_dest](#method.build_with_dest).
     pub fn add_numeric_field(mut self, name: FieldName, length: u8, num_decimals: u8) -> Self {
-        let mut info = FieldInfo::new(name, FieldType::Numeric, length);
+        let mut info = FieldInfo::new_unchecked(name, FieldType::Numeric, length);
         info.num_decimal_places = num_decimals;
         self.v.push(info);
         self
     }
 
     /// Adds a [Float](enum.FieldValue.html#variant.Float)
+    ///
+    /// The number of decimals can be at most the length minus 2, it is checked
+    /// when the writer writes the header, see [build_with_dest](#method.build_with_dest).
     pub fn add_float_field(mut self, name: FieldName, length: u8, num_decimals: u8) -> Self {
-        let mut info = FieldInfo::new(name, FieldType::Float, length);
+        let mut info = FieldInfo::new_unchecked(name, FieldType::Float, length);
         info.num_decimal_places = num_decimals;
         self.v.push(info);
         self
@@ -164,7 +406,7 @@ impl TableWriterBuilder {
 
     /// Adds a [Logical](enum.FieldValue.html#variant.Logical)
     pub fn add_logical_field(mut self, name: FieldName) -> Self {
-        self.v.push(FieldInfo::new(
+        self.v.push(FieldInfo::new_unchecked(
             name,
             FieldType::Logical,
             FieldType::Logical
@@ -176,85 +418,246 @@ impl TableWriterBuilder {
 
     /// Adds a [Integer](enum.FieldValue.html#variant.Integer)
     pub fn add_integer_field(mut self, name: FieldName) -> Self {
-        self.v.push(FieldInfo::new(
+        self.v.push(FieldInfo::new_unchecked(
             name,
             FieldType::Integer,
             FieldType::Integer
                 .size()
                 .expect("Internal error Integer field date should be known"),
         ));
-        self.hdr.file_type = crate::header::Version::FoxPro2 {
-            supports_memo: false,
-        };
+        self
+    }
+
+    /// Adds a [AutoIncrement](enum.FieldValue.html#variant.AutoIncrement)
+    ///
+    /// The value of the first record is 1, and it is incremented by 1 for each
+    /// new record, the next value stored in the field information is kept up to date
+    /// by the writer.
+    pub fn add_autoincrement_field(mut self, name: FieldName) -> Self {
+        self.v.push(FieldInfo::new_unchecked(
+            name,
+            FieldType::AutoIncrement,
+            FieldType::AutoIncrement
+                .size()
+                .expect("Internal error AutoIncrement field size should be known"),
+        ));
         self
     }
 
     /// Adds a [DateTime](enum.FieldValue.html#variant.DateTime)
     pub fn add_datetime_field(mut self, name: FieldName) -> Self {
-        self.v.push(FieldInfo::new(
+        self.v.push(FieldInfo::new_unchecked(
             name,
             FieldType::DateTime,
             FieldType::DateTime
                 .size()
                 .expect("Internal error datetime field date should be known"),
         ));
-        self.hdr.file_type = crate::header::Version::FoxPro2 {
-            supports_memo: false,
-        };
         self
     }
 
     /// Adds a [Double](enum.FieldValue.html#variant.Double)
     pub fn add_double_field(mut self, name: FieldName) -> Self {
-        self.v.push(FieldInfo::new(
+        self.v.push(FieldInfo::new_unchecked(
             name,
             FieldType::Double,
             FieldType::Double
                 .size()
                 .expect("Internal error Double field date should be known"),
         ));
-        self.hdr.file_type = crate::header::Version::FoxPro2 {
-            supports_memo: false,
-        };
         self
     }
 
     /// Adds a [Currency](enum.FieldValue.html#variant.Currency)
     pub fn add_currency_field(mut self, name: FieldName) -> Self {
-        self.v.push(FieldInfo::new(
+        self.v.push(FieldInfo::new_unchecked(
             name,
             FieldType::Currency,
             FieldType::Currency
                 .size()
                 .expect("Internal error Currency field date should be known"),
         ));
-        self.hdr.file_type = crate::header::Version::FoxPro2 {
-            supports_memo: false,
-        };
         self
     }
+
+    /// Sets the version written in the header, instead of the one picked by the builder
+    ///
+    /// By default, the version is dBase III, or Visual FoxPro when one of the fields
+    /// has a type dBase III does not know (Integer, Currency, DateTime, ...).
+    /// The version should support all the field types, it is not checked.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dbase::{FieldName, TableWriterBuilder, Version};
+    /// use std::convert::TryFrom;
+    /// use std::io::Cursor;
+    ///
+    /// let writer = TableWriterBuilder::new()
+    ///     .add_integer_field(FieldName::try_from("Count").unwrap())
+    ///     .with_version(Version::FoxPro2 { supports_memo: false })
+    ///     .build_with_dest(Cursor::new(Vec::<u8>::new()));
+    /// ```
+    pub fn with_version(mut self, version: Version) -> Self {
+        self.version = Some(version);
+        self
+    }
+
+    /// Sets the version of the header, to the one given with `with_version`, or to
+    /// Visual FoxPro if some field types are not supported by the current version,
+    /// and the code page mark of the header to the one of the encoding
+    fn resolve_header(&mut self) {
+        self.hdr.code_page_mark = code_page_mark_for_encoding(self.encoding);
+        if let Some(version) = self.version {
+            self.hdr.file_type = version;
+            return;
+        }
+        let needs_visual_fox_pro = self.v.iter().any(|info| {
+            matches!(
+                info.field_type,
+                FieldType::Integer
+                    | FieldType::AutoIncrement
+                    | FieldType::DateTime
+                    | FieldType::Double
+                    | FieldType::Currency
+                    | FieldType::NullFlags
+            )
+        });
+        // Tables taken from FoxPro files keep their version
+        let supports_fox_pro_types = matches!(
+            self.hdr.file_type,
+            Version::VisualFoxPro | Version::FoxPro2 { .. }
+        );
+        if needs_visual_fox_pro && !supports_fox_pro_types {
+            self.hdr.file_type = Version::VisualFoxPro;
+        }
+    }
+
     /// Builds the writer and set the dst as where the file data will be written
-    pub fn build_with_dest<W: Write + Seek>(self, dst: W) -> TableWriter<W> {
-        TableWriter::new(dst, self.v, self.hdr, self.encoding)
+    ///
+    /// If a field is not valid (e.g. a Character field of length 0, see [FieldInfo::new]),
+    /// the writer returns [ErrorKind::InvalidFieldInfo], and if two fields have
+    /// the same name (ignoring the ASCII case), [ErrorKind::DuplicateFieldName],
+    /// when it writes the header, that is with the first record or when finished.
+    pub fn build_with_dest<W: Write + Seek>(mut self, dst: W) -> TableWriter<W> {
+        self.resolve_header();
+        let mut writer = TableWriter::new(dst, self.v, self.hdr, self.encoding, self.options);
+        writer.memo_block_size = self.memo_block_size;
+        writer
     }
 
     /// Helper function to set create a file at the given path
     /// and make the writer write to the newly created file.
     ///
     /// This function wraps the `File` in a `BufWriter` to increase performance.
+    ///
+    /// Returns [ErrorKind::InvalidFieldInfo] or [ErrorKind::DuplicateFieldName],
+    /// without creating the file, if a field is not valid or
+    /// if two fields have the same name (ignoring the ASCII case).
     pub fn build_with_file_dest<P: AsRef<Path>>(
         self,
         path: P,
     ) -> Result<TableWriter<BufWriter<File>>, Error> {
+        check_fields(&self.v)?;
         let file = File::create(path).map_err(|err| Error::io_error(err, 0))?;
         let dst = BufWriter::new(file);
         Ok(self.build_with_dest(dst))
     }
 
-    pub fn build_table_info(self) -> TableInfo {
+    /// Opens the existing file at the given path in read-write mode,
+    /// to update its records with [TableWriter::update_record_at] and
+    /// [TableWriter::update_field_at], or to append new ones.
+    ///
+    /// Unlike [build_with_file_dest](#method.build_with_file_dest), the file is not truncated.
+    /// The fields of the builder must describe the same record layout as the file
+    /// (use [from_reader](#method.from_reader)), otherwise an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), dbase::Error> {
+    /// let reader = dbase::Reader::from_path("tests/data/stations.dbf")?;
+    /// let mut writer = dbase::TableWriterBuilder::from_reader(reader)
+    ///     .build_with_file_dest_readwrite("tests/data/stations.dbf")?;
+    /// writer.update_field_at(0, "name", dbase::FieldValue::Character(Some("Renamed".to_string())))?;
+    /// writer.finish()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn build_with_file_dest_readwrite<P: AsRef<Path>>(
+        self,
+        path: P,
+    ) -> Result<TableWriter<File>, Error> {
+        check_fields(&self.v)?;
+        let mut file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .open(path)
+            .map_err(|err| Error::io_error(err, 0))?;
+        let file_header = Header::read_from(&mut file).map_err(|err| Error::io_error(err, 0))?;
+
+        let mut writer = self.build_with_dest(file);
+        writer.update_header();
+        if writer.header.offset_to_first_record != file_header.offset_to_first_record
+            || writer.header.size_of_record != file_header.size_of_record
+        {
+            // The file must not be modified when the writer is dropped
+            writer.closed = true;
+            return Err(Error {
+                record_num: 0,
+                field: None,
+                kind: ErrorKind::Message(
+                    "The fields of the writer do not match the ones of the file".to_string(),
+                ),
+            });
+        }
+        writer.header.num_records = file_header.num_records;
+        if writer.header.num_records == 0 {
+            // The header is written again with the first record
+            writer
+                .dst_mut()
+                .seek(SeekFrom::Start(0))
+                .map_err(|err| Error::io_error(err, 0))?;
+        } else {
+            writer.seek_to_record(file_header.num_records as usize)?;
+        }
+        Ok(writer)
+    }
+
+    /// Creates a file at each of the given paths
+    /// and returns the writers, one for each file, with the same fields.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), dbase::Error> {
+    /// use dbase::{FieldName, TableWriterBuilder};
+    /// use std::convert::TryFrom;
+    ///
+    /// let writers = TableWriterBuilder::new()
+    ///     .add_character_field(FieldName::try_from("name").unwrap(), 50)
+    ///     .build_with_file_dest_multiple(&["north.dbf", "south.dbf"])?;
+    /// assert_eq!(writers.len(), 2);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn build_with_file_dest_multiple<P: AsRef<Path>>(
+        self,
+        paths: &[P],
+    ) -> Result<Vec<TableWriter<BufWriter<File>>>, Error> {
+        paths
+            .iter()
+            .map(|path| self.clone().build_with_file_dest(path))
+            .collect()
+    }
+
+    pub fn build_table_info(mut self) -> TableInfo {
+        self.resolve_header();
         TableInfo {
             header: self.hdr,
             fields_info: self.v,
+            encoding: self.encoding,
+            memo_block_size: Some(self.memo_block_size),
         }
     }
 }
@@ -265,10 +668,102 @@ impl Default for TableWriterBuilder {
             v: vec![],
             hdr: Header::new(0, 0, 0),
             encoding: encoding_rs::UTF_8,
+            options: WritingOptions::default(),
+            memo_block_size: DEFAULT_MEMO_BLOCK_SIZE,
+            version: None,
+        }
+    }
+}
+
+/// Characters used to write the `true` & `false` Logical values
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum LogicalStyle {
+    /// 'T' & 'F', what dBase writes, and what some tools (e.g. ArcGIS) require
+    #[default]
+    TrueFalseUpper,
+    /// 't' & 'f', what was written by default before
+    TrueFalseLower,
+    /// 'Y' & 'N'
+    YesNo,
+}
+
+impl LogicalStyle {
+    pub(crate) fn char_for(self, value: bool) -> char {
+        match (self, value) {
+            (LogicalStyle::TrueFalseUpper, true) => 'T',
+            (LogicalStyle::TrueFalseUpper, false) => 'F',
+            (LogicalStyle::TrueFalseLower, true) => 't',
+            (LogicalStyle::TrueFalseLower, false) => 'f',
+            (LogicalStyle::YesNo, true) => 'Y',
+            (LogicalStyle::YesNo, false) => 'N',
         }
     }
 }
 
+/// How empty (`None`) Date values are written
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum EmptyDateStyle {
+    /// 8 spaces, what dBase writes
+    #[default]
+    Spaces,
+    /// `00000000`, what some other producers write
+    Zeros,
+}
+
+/// What to do with Character values longer than their field
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum TruncationPolicy {
+    /// Return an [ErrorKind::ValueTooLong] error
+    #[default]
+    Error,
+    /// Truncate the value to the length of the field,
+    /// without splitting the bytes of an encoded character
+    Truncate,
+}
+
+/// What to do with Numeric & Float values that need more characters than their field has
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum NumericOverflowPolicy {
+    /// Return an [ErrorKind::ValueDoesNotFit] error
+    #[default]
+    Error,
+    /// Fill the field with `*`, as dBase does, which is read as a null value
+    Asterisks,
+}
+
+/// How the text of Numeric & Float values is aligned in their field
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum NumericAlignment {
+    /// Padded with leading spaces, what dBase writes
+    #[default]
+    Right,
+    /// Padded with trailing spaces, what was written before
+    Left,
+}
+
+/// How numbers are rounded to the decimal places of their field
+/// when half-way between two values
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum RoundingMode {
+    /// `2.5` is written as `2` and `3.5` as `4`, as Rust formats numbers
+    #[default]
+    HalfToEven,
+    /// `2.5` is written as `3` and `-2.5` as `-3`, as legacy dBase programs do
+    HalfAwayFromZero,
+}
+
+/// Options that change the way field values are written,
+/// set using the [TableWriterBuilder]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct WritingOptions {
+    pub(crate) logical_style: LogicalStyle,
+    pub(crate) empty_date_style: EmptyDateStyle,
+    pub(crate) truncation_policy: TruncationPolicy,
+    pub(crate) numeric_overflow_policy: NumericOverflowPolicy,
+    pub(crate) numeric_alignment: NumericAlignment,
+    pub(crate) numeric_rounding: RoundingMode,
+}
+
 mod private {
     pub trait Sealed {}
 
@@ -285,13 +780,37 @@ mod private {
     impl_sealed_for!(&str);
     impl_sealed_for!(f64);
     impl_sealed_for!(f32);
+    impl_sealed_for!(i8);
+    impl_sealed_for!(i16);
     impl_sealed_for!(i32);
+    impl_sealed_for!(i64);
+    impl_sealed_for!(isize);
+    impl_sealed_for!(u8);
+    impl_sealed_for!(u16);
+    impl_sealed_for!(u32);
+    impl_sealed_for!(u64);
+    impl_sealed_for!(usize);
     impl_sealed_for!(Option<f64>);
     impl_sealed_for!(Option<f32>);
     impl_sealed_for!(crate::record::field::Date);
     impl_sealed_for!(Option<crate::record::field::Date>);
     impl_sealed_for!(crate::record::field::FieldValue);
     impl_sealed_for!(crate::record::field::DateTime);
+    impl_sealed_for!(Option<i32>);
+    impl_sealed_for!(Option<crate::record::field::DateTime>);
+    impl_sealed_for!(crate::record::field::CurrencyValue);
+    impl_sealed_for!(time::Date);
+    impl_sealed_for!(time::PrimitiveDateTime);
+    #[cfg(feature = "chrono")]
+    impl_sealed_for!(chrono::NaiveDate);
+    #[cfg(feature = "chrono")]
+    impl_sealed_for!(Option<chrono::NaiveDate>);
+    #[cfg(feature = "chrono")]
+    impl_sealed_for!(chrono::NaiveDateTime);
+    #[cfg(feature = "decimal")]
+    impl_sealed_for!(rust_decimal::Decimal);
+    #[cfg(feature = "decimal")]
+    impl_sealed_for!(Option<rust_decimal::Decimal>);
 }
 
 /// Trait implemented by types we can write as dBase types
@@ -303,7 +822,15 @@ pub trait WritableAsDbaseField: private::Sealed {
         field_info: &FieldInfo,
         dst: &mut W,
         encoding: &'static Encoding,
+        options: WritingOptions,
     ) -> Result<(), ErrorKind>;
+
+    /// Returns true if the value is a null value,
+    /// used to fill the `_NullFlags` column of Visual FoxPro tables
+    #[doc(hidden)]
+    fn is_null(&self) -> bool {
+        false
+    }
 }
 
 /// Trait to be implemented by struct that you want to be able to write to (serialize)
@@ -316,24 +843,86 @@ pub trait WritableRecord {
     ) -> Result<(), FieldIOError>;
 }
 
+/// Returns an error if a field is not valid, or if two fields have the same name,
+/// ignoring the ASCII case as dBase does, the second one is the field of the error
+fn check_fields(fields_info: &[FieldInfo]) -> Result<(), Error> {
+    for (i, field_info) in fields_info.iter().enumerate() {
+        if let Err(error) = field_info.validate() {
+            return Err(Error {
+                record_num: 0,
+                field: Some(field_info.clone()),
+                kind: ErrorKind::InvalidFieldInfo(error),
+            });
+        }
+        if fields_info[..i]
+            .iter()
+            .any(|other| other.name().eq_ignore_ascii_case(field_info.name()))
+        {
+            return Err(Error {
+                record_num: 0,
+                field: Some(field_info.clone()),
+                kind: ErrorKind::DuplicateFieldName(field_info.name().to_owned()),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Writes the fields in the order in which they were declared to the writer,
+/// looking up their value by name
+fn write_fields_by_name<'a, 'b, W, F>(
+    field_writer: &mut FieldWriter<'a, W>,
+    get_value: F,
+) -> Result<(), FieldIOError>
+where
+    W: Write,
+    F: Fn(&str) -> Option<&'b FieldValue>,
+{
+    while let Some(field_info) = field_writer.fields_info.peek() {
+        let value = get_value(field_info.name()).ok_or_else(|| {
+            FieldIOError::new(
+                ErrorKind::Message(format!(
+                    "Could not find field named '{}' in the record map",
+                    field_info.name()
+                )),
+                Some((*field_info).clone()),
+            )
+        })?;
+        field_writer.write_next_field_value(value)?;
+    }
+    Ok(())
+}
+
+/// Writes the fields in the order of the writer, each declared field must have
+/// a value in the record, otherwise an error is returned.
+///
+/// The fields of the writer are looked up by name: a value whose type does not match
+/// its field gives an [ErrorKind::IncompatibleType] error with the [FieldInfo] of the field.
+/// As the fields of the writer drive what is written,
+/// the values of the record that have no field in the writer are ignored.
+///
+/// This allows to write back records that were read and modified.
 impl WritableRecord for Record {
     fn write_using<'a, W: Write>(
         &self,
         field_writer: &mut FieldWriter<'a, W>,
     ) -> Result<(), FieldIOError> {
-        while let Some(name) = field_writer.next_field_name() {
-            let value = self.get(name).ok_or_else(|| {
-                FieldIOError::new(
-                    ErrorKind::Message(format!(
-                        "Could not find field named '{}' in the record map",
-                        name
-                    )),
-                    None,
-                )
-            })?;
-            field_writer.write_next_field_value(value)?;
-        }
-        Ok(())
+        write_fields_by_name(field_writer, |name| self.get(name))
+    }
+}
+
+/// Same as the implementation for [Record]
+///
+/// When the `serde` feature is enabled, maps are written using their `Serialize`
+/// implementation instead (this implementation would conflict with it),
+/// which also writes the values in the order of the fields.
+#[cfg(not(feature = "serde"))]
+impl WritableRecord for HashMap<String, FieldValue> {
+    fn write_using<'a, W: Write>(
+        &self,
+        field_writer: &mut FieldWriter<'a, W>,
+    ) -> Result<(), FieldIOError> {
+        write_fields_by_name(field_writer, |name| self.get(name))
     }
 }
 
@@ -347,6 +936,9 @@ pub struct FieldWriter<'a, W: Write> {
     pub(crate) fields_info: std::iter::Peekable<std::slice::Iter<'a, FieldInfo>>,
     pub(crate) buffer: &'a mut Cursor<Vec<u8>>,
     encoding: &'static Encoding,
+    options: WritingOptions,
+    /// Bytes of the `_NullFlags` column, filled as the values are written
+    null_flags: &'a mut Vec<u8>,
 }
 
 impl<'a, W: Write> FieldWriter<'a, W> {
@@ -362,11 +954,14 @@ impl<'a, W: Write> FieldWriter<'a, W> {
     /// If the corresponding `FieldType` of the the field_value type (`T`) does not
     /// match the expected type an error is returned.
     ///
-    /// Values for which the number of bytes written would exceed the specified field_length
-    /// (if it had to be specified) will be truncated
+    /// Character values longer than their field return a `ValueTooLong` error,
+    /// unless the writer was built with [TruncationPolicy::Truncate], then they are truncated.
+    /// Numeric & Float values that need more characters than their field has return a
+    /// `ValueDoesNotFit` error, unless the writer was built with
+    /// [NumericOverflowPolicy::Asterisks], then the field is filled with `*`.
     ///
     /// Trying to write more values than was declared when creating the writer will cause
-    /// an `EndOfRecord` error.
+    /// a `TooManyFields` error.
     pub fn write_next_field_value<T: WritableAsDbaseField>(
         &mut self,
         field_value: &T,
@@ -375,7 +970,7 @@ impl<'a, W: Write> FieldWriter<'a, W> {
             self.buffer.set_position(0);
 
             field_value
-                .write_as(field_info, &mut self.buffer, self.encoding)
+                .write_as(field_info, &mut self.buffer, self.encoding, self.options)
                 .map_err(|kind| FieldIOError::new(kind, Some(field_info.clone())))?;
 
             let bytes_written = self.buffer.position();
@@ -387,8 +982,8 @@ impl<'a, W: Write> FieldWriter<'a, W> {
                     })?;
                 }
             }
-            // If the current field value size exceeds the one one set
-            // when creating the writer, it will be cropped
+            // Values longer than the field were truncated or rejected by `write_as`,
+            // according to the options
             let field_bytes = self.buffer.get_ref();
             debug_assert_eq!(self.buffer.position(), field_info.field_length as u64);
             self.dst
@@ -396,12 +991,51 @@ impl<'a, W: Write> FieldWriter<'a, W> {
                 .map_err(|error| {
                     FieldIOError::new(ErrorKind::IoError(error), Some(field_info.clone()))
                 })?;
-            Ok(())
+            if let Some(bit) = field_info.null_flag_bit.filter(|_| field_value.is_null()) {
+                if self.null_flags.len() <= bit / 8 {
+                    self.null_flags.resize(bit / 8 + 1, 0);
+                }
+                self.null_flags[bit / 8] |= 1 << (bit % 8);
+            }
+            self.write_null_flags_if_next()
         } else {
             Err(FieldIOError::new(ErrorKind::TooManyFields, None))
         }
     }
 
+    /// Writes the `_NullFlags` column if it is the next field
+    ///
+    /// Visual FoxPro stores it after all the other fields,
+    /// so the null values of the record are known when it is written.
+    fn write_null_flags_if_next(&mut self) -> Result<(), FieldIOError> {
+        while let Some(field_info) = self.fields_info.next_if(|info| info.is_null_flags()) {
+            let length = field_info.field_length as usize;
+            self.null_flags.resize(length, 0);
+            self.dst.write_all(self.null_flags).map_err(|error| {
+                FieldIOError::new(ErrorKind::IoError(error), Some(field_info.clone()))
+            })?;
+        }
+        Ok(())
+    }
+
+    /// Returns a writer of the single field `field_info` that writes to `dst`,
+    /// with the encoding, the options & the null flags of this writer
+    #[cfg(feature = "serde")]
+    pub(crate) fn for_single_field<'b>(
+        &'b mut self,
+        field_info: &'b FieldInfo,
+        dst: &'b mut Vec<u8>,
+    ) -> FieldWriter<'b, Vec<u8>> {
+        FieldWriter {
+            dst,
+            fields_info: std::slice::from_ref(field_info).iter().peekable(),
+            buffer: self.buffer,
+            encoding: self.encoding,
+            options: self.options,
+            null_flags: self.null_flags,
+        }
+    }
+
     #[cfg(feature = "serde")]
     pub(crate) fn write_next_field_raw(&mut self, value: &[u8]) -> Result<(), FieldIOError> {
         if let Some(field_info) = self.fields_info.next() {
@@ -425,7 +1059,7 @@ impl<'a, W: Write> FieldWriter<'a, W> {
                         FieldIOError::new(ErrorKind::IoError(error), Some(field_info.clone()))
                     })?;
             }
-            Ok(())
+            self.write_null_flags_if_next()
         } else {
             Err(FieldIOError::new(ErrorKind::EndOfRecord, None))
         }
@@ -435,46 +1069,163 @@ impl<'a, W: Write> FieldWriter<'a, W> {
         self.dst.write_u8(b' ')
     }
 
-    fn all_fields_were_written(&mut self) -> bool {
-        self.fields_info.peek().is_none()
+    /// Returns the first field that was not written, if any
+    fn first_unwritten_field(&mut self) -> Option<&'a FieldInfo> {
+        self.fields_info.peek().copied()
     }
 }
 
+const DST_TAKEN: &str = "The destination of the TableWriter was taken";
+
 /// Structs that writes dBase records to a destination
 ///
 /// The only way to create a TableWriter is to use its
 /// [TableWriterBuilder](struct.TableWriterBuilder.html)
-pub struct TableWriter<W: Write + Seek> {
-    dst: W,
-    fields_info: Vec<FieldInfo>,
+///
+/// The number of records does not need to be known up front:
+/// records can be written one at a time with [write_record](#method.write_record),
+/// the header is written with a count of 0 before the first record, then the writer
+/// seeks back to patch it when [finished](#method.finish) (or dropped),
+/// which is why the destination must be `Write + Seek`.
+///
+/// # Example
+///
+/// ```
+/// # fn main() -> Result<(), dbase::Error> {
+/// use std::convert::TryFrom;
+///
+/// let mut writer = dbase::TableWriterBuilder::new()
+///     .add_integer_field(dbase::FieldName::try_from("Id").unwrap())
+///     .build_with_file_dest("streamed.dbf")?;
+///
+/// // e.g. rows coming from a database cursor
+/// for id in (0..).take_while(|id| *id < 100) {
+///     let mut record = dbase::Record::default();
+///     record.insert("Id".to_string(), dbase::FieldValue::Integer(id));
+///     writer.write_record(&record)?;
+/// }
+/// writer.finish()?;
+///
+/// assert_eq!(dbase::Reader::from_path("streamed.dbf")?.header().num_records, 100);
+/// # let ignored_result = std::fs::remove_file("streamed.dbf");
+/// # Ok(())
+/// # }
+/// ```
+pub struct TableWriter<W: Write + Seek> {
+    /// Only `None` once taken by [TableWriter::finish]
+    dst: Option<W>,
+    fields_info: Vec<FieldInfo>,
     /// contains the header of the input file
     /// if this writer was created form a reader
     header: Header,
     /// Buffer used by the FieldWriter
     buffer: Cursor<Vec<u8>>,
     encoding: &'static Encoding,
+    options: WritingOptions,
+    /// Buffer used by the FieldWriter for the `_NullFlags` column
+    null_flags: Vec<u8>,
+    /// Bytes of the record being written, written to the destination
+    /// only once all its fields are
+    record_buffer: Vec<u8>,
+    progress: Progress,
+    /// Total number of records reported to the progress callback, 0 when unknown
+    expected_num_records: usize,
+    memo_block_size: u32,
     closed: bool,
 }
 
 impl<W: Write + Seek> TableWriter<W> {
     fn new(
         dst: W,
-        fields_info: Vec<FieldInfo>,
+        mut fields_info: Vec<FieldInfo>,
         origin_header: Header,
         encoding: &'static Encoding,
+        options: WritingOptions,
     ) -> Self {
+        assign_null_flag_bits(&mut fields_info);
+        let record_size = fields_info
+            .iter()
+            .fold(1usize, |size, info| size + info.field_length as usize);
         Self {
-            dst,
+            dst: Some(dst),
             fields_info,
             header: origin_header,
             buffer: Cursor::new(vec![0u8; 255]),
             encoding,
+            options,
+            null_flags: Vec::new(),
+            record_buffer: Vec::with_capacity(record_size),
+            progress: Progress::default(),
+            expected_num_records: 0,
+            memo_block_size: DEFAULT_MEMO_BLOCK_SIZE,
             closed: false,
         }
     }
 
+    /// Returns the size of the blocks of the memo file,
+    /// set with [TableWriterBuilder::memo_block_size]
+    pub fn memo_block_size(&self) -> u32 {
+        self.memo_block_size
+    }
+
+    /// Sets a callback called while writing records,
+    /// with the number of records written and the total number of records to write.
+    ///
+    /// The total is only known when using [write_records](#method.write_records)
+    /// with a collection whose iterator has an exact size (e.g. a `Vec`), it is 0 otherwise.
+    ///
+    /// The callback is called every [progress interval](#method.set_progress_interval)
+    /// records, and after the last one written by [write_records](#method.write_records).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), dbase::Error> {
+    /// use std::convert::TryFrom;
+    /// use std::io::Cursor;
+    /// use std::sync::{Arc, Mutex};
+    ///
+    /// let calls = Arc::new(Mutex::new(Vec::new()));
+    /// let mut writer = dbase::TableWriterBuilder::new()
+    ///     .add_character_field(dbase::FieldName::try_from("Name").unwrap(), 10)
+    ///     .build_with_dest(Cursor::new(Vec::<u8>::new()));
+    /// let progress = Arc::clone(&calls);
+    /// writer.set_progress_callback(move |processed, total| {
+    ///     progress.lock().unwrap().push((processed, total));
+    /// });
+    /// writer.set_progress_interval(2);
+    ///
+    /// let mut record = dbase::Record::default();
+    /// record.insert("Name".to_string(), dbase::FieldValue::Character(Some("Yoshi".to_string())));
+    /// writer.write_records(&vec![record; 3])?;
+    /// assert_eq!(*calls.lock().unwrap(), vec![(2, 3), (3, 3)]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_progress_callback<F>(&mut self, callback: F)
+    where
+        F: Fn(u64, u64) + Send + Sync + 'static,
+    {
+        self.progress.set_callback(Arc::new(callback));
+    }
+
+    /// Sets the number of records written between two calls of the
+    /// [progress callback](#method.set_progress_callback), 1000 by default.
+    ///
+    /// An interval of 0 is treated as 1.
+    pub fn set_progress_interval(&mut self, n: usize) {
+        self.progress.set_interval(n);
+    }
+
     /// Writes a record the inner destination
     ///
+    /// The record is first written in memory, then to the destination
+    /// once all its fields are written. If the record does not have
+    /// as many fields as the writer ([ErrorKind::NotEnoughFields], [ErrorKind::TooManyFields])
+    /// or a value cannot be written (e.g. [ErrorKind::ValueTooLong]), an error is returned
+    /// and nothing is written, so that the record can be fixed and written again,
+    /// or skipped, and the next records can still be written.
+    ///
     /// # Example
     ///
     /// ```
@@ -482,58 +1233,101 @@ impl<W: Write + Seek> TableWriter<W> {
     ///
     /// # fn main() -> Result<(), dbase::Error> {
     /// let mut writer = dbase::TableWriterBuilder::new()
-    ///     .add_character_field(dbase::FieldName::try_from("First Name").unwrap(), 50)
+    ///     .add_character_field(dbase::FieldName::try_from("First_Name").unwrap(), 50)
     ///     .build_with_file_dest("records.dbf")?;
     ///
     /// let mut record = dbase::Record::default();
     /// // cspell:disable-next-line
-    /// record.insert("First Name".to_string(), dbase::FieldValue::Character(Some("Yoshi".to_string())));
+    /// record.insert("First_Name".to_string(), dbase::FieldValue::Character(Some("Yoshi".to_string())));
     ///
     /// writer.write_record(&record)?;
     ///
-    /// # let ignored_result = std::fs::remove_file("record.dbf");
+    /// # let ignored_result = std::fs::remove_file("records.dbf");
     /// Ok(())
     /// # }
     /// ```
     pub fn write_record<R: WritableRecord>(&mut self, record: &R) -> Result<(), Error> {
         if self.header.num_records == 0 {
-            // reserve the header
+            // reserve the header, from the start as the first record
+            // may have failed after the header was reserved
+            self.dst_mut()
+                .seek(SeekFrom::Start(0))
+                .map_err(|error| Error::io_error(error, 0))?;
             self.write_header()?;
         }
 
-        let mut field_writer = FieldWriter {
-            dst: &mut self.dst,
-            fields_info: self.fields_info.iter().peekable(),
-            buffer: &mut self.buffer,
-            encoding: self.encoding,
-        };
-
-        let current_record_num = self.header.num_records as usize;
+        self.write_record_fields(record, self.header.num_records as usize)?;
 
-        field_writer
-            .write_deletion_flag()
-            .map_err(|error| Error::io_error(error, current_record_num))?;
-
-        record
-            .write_using(&mut field_writer)
-            .map_err(|error| Error::new(error, current_record_num))?;
-
-        if !field_writer.all_fields_were_written() {
-            return Err(Error {
-                record_num: current_record_num,
-                field: None,
-                kind: ErrorKind::NotEnoughFields,
-            });
+        for info in self
+            .fields_info
+            .iter_mut()
+            .filter(|info| info.field_type == FieldType::AutoIncrement)
+        {
+            info.autoincrement_next_val = info
+                .autoincrement_next_val
+                .wrapping_add(u32::from(info.autoincrement_step));
         }
 
         self.header.num_records += 1;
+        self.progress
+            .report(self.header.num_records as usize, self.expected_num_records);
         Ok(())
     }
 
-    /// Writes the records to the inner destination
+    /// Overwrites the record at `index`, which must have already been written
+    ///
+    /// The record is written in place, the following records are not moved,
+    /// then the writer goes back to the end of the records so that
+    /// [write_record](#method.write_record) keeps appending.
+    ///
+    /// As with [write_record](#method.write_record), nothing is written
+    /// if the record does not match the fields of the writer.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), dbase::Error> {
+    /// use std::convert::TryFrom;
+    /// use std::io::Cursor;
+    ///
+    /// let mut writer = dbase::TableWriterBuilder::new()
+    ///     .add_character_field(dbase::FieldName::try_from("Name").unwrap(), 10)
+    ///     .build_with_dest(Cursor::new(Vec::<u8>::new()));
+    ///
+    /// let mut record = dbase::Record::default();
+    /// record.insert("Name".to_string(), dbase::FieldValue::Character(Some("Yoshi".to_string())));
+    /// writer.write_record(&record)?;
+    /// writer.write_record(&record)?;
+    ///
+    /// record.insert("Name".to_string(), dbase::FieldValue::Character(Some("Mario".to_string())));
+    /// writer.update_record_at(0, &record)?;
+    ///
+    /// let mut cursor = writer.finish()?;
+    /// cursor.set_position(0);
+    /// let records = dbase::Reader::new(cursor)?.read()?;
+    /// assert_eq!(records[0], record);
+    /// assert_eq!(records.len(), 2);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn update_record_at<R: WritableRecord>(
+        &mut self,
+        index: usize,
+        record: &R,
+    ) -> Result<(), Error> {
+        self.check_record_index(index)?;
+        self.seek_to_record(index)?;
+        let result = self.write_record_fields(record, index);
+        self.seek_to_record(self.header.num_records as usize)?;
+        result
+    }
+
+    /// Writes the records to the inner destination, then [finishes](#method.finish)
+    /// the file
+    ///
+    /// Values that do not fit in their field are handled as described in
+    /// [FieldWriter::write_next_field_value]
     ///
-    /// Values for which the number of bytes written would exceed the specified field_length
-    /// (if it had to be specified) will be truncated
     /// # Example
     /// ```
     /// use dbase::{TableWriterBuilder, FieldName, WritableRecord, FieldWriter, ErrorKind, FieldIOError};
@@ -552,7 +1346,7 @@ impl<W: Write + Seek> TableWriter<W> {
     ///
     /// let mut cursor = Cursor::new(Vec::<u8>::new());
     /// let writer = TableWriterBuilder::new()
-    ///     .add_character_field(FieldName::try_from("First Name").unwrap(), 50)
+    ///     .add_character_field(FieldName::try_from("First_Name").unwrap(), 50)
     ///     .build_with_dest(&mut cursor);
     ///
     /// let records = vec![
@@ -565,42 +1359,232 @@ impl<W: Write + Seek> TableWriter<W> {
     /// assert_eq!(cursor.position(), 117)
     /// ```
     pub fn write_records<'a, R: WritableRecord + 'a, C: IntoIterator<Item = &'a R>>(
-        mut self,
+        self,
         records: C,
     ) -> Result<(), Error> {
-        for record in records.into_iter() {
-            self.write_record(record)?;
+        self.write_all_records::<R, _, _>(records)
+    }
+
+    /// Writes the records yielded by the iterator, then [finishes](#method.finish)
+    /// the file
+    ///
+    /// Unlike [write_records](#method.write_records), the iterator yields owned records,
+    /// so they can be generated lazily: each record is written as soon as it is yielded,
+    /// then dropped, the records are never collected.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), dbase::Error> {
+    /// use std::convert::TryFrom;
+    /// use std::io::Cursor;
+    /// use dbase::{FieldName, FieldValue, Record, TableWriterBuilder};
+    ///
+    /// let mut cursor = Cursor::new(Vec::<u8>::new());
+    /// let writer = TableWriterBuilder::new()
+    ///     .add_numeric_field(FieldName::try_from("Id").unwrap(), 10, 0)
+    ///     .build_with_dest(&mut cursor);
+    ///
+    /// writer.write_records_from_iter((0..1000).map(|i| {
+    ///     let mut record = Record::default();
+    ///     record.insert("Id".to_string(), FieldValue::Numeric(Some(f64::from(i))));
+    ///     record
+    /// }))?;
+    ///
+    /// cursor.set_position(0);
+    /// assert_eq!(dbase::Reader::new(cursor)?.header().num_records, 1000);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn write_records_from_iter<R: WritableRecord, I: IntoIterator<Item = R>>(
+        self,
+        records: I,
+    ) -> Result<(), Error> {
+        self.write_all_records::<R, _, _>(records)
+    }
+
+    fn write_all_records<R, B, I>(mut self, records: I) -> Result<(), Error>
+    where
+        R: WritableRecord,
+        B: Borrow<R>,
+        I: IntoIterator<Item = B>,
+    {
+        let records = records.into_iter();
+        if let (num_records, Some(upper_bound)) = records.size_hint() {
+            if num_records == upper_bound {
+                self.expected_num_records = self.header.num_records as usize + num_records;
+            }
         }
-        Ok(())
+        for record in records {
+            self.write_record(record.borrow())?;
+        }
+        self.progress
+            .report_end(self.header.num_records as usize, self.expected_num_records);
+        self.finish().map(|_| ())
+    }
+
+    /// Finishes the file and returns the inner destination
+    ///
+    /// The end of file marker is written, the number of records and the date
+    /// of last update of the header are updated, then the destination is flushed.
+    ///
+    /// The file is also finished when the writer is dropped, but errors are then ignored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), dbase::Error> {
+    /// use std::convert::TryFrom;
+    /// use std::io::Cursor;
+    ///
+    /// let mut writer = dbase::TableWriterBuilder::new()
+    ///     .add_character_field(dbase::FieldName::try_from("Name").unwrap(), 10)
+    ///     .build_with_dest(Cursor::new(Vec::<u8>::new()));
+    ///
+    /// let mut record = dbase::Record::default();
+    /// record.insert("Name".to_string(), dbase::FieldValue::Character(Some("Yoshi".to_string())));
+    /// writer.write_record(&record)?;
+    /// assert_eq!(writer.num_records_written(), 1);
+    ///
+    /// let bytes = writer.finish()?.into_inner();
+    /// assert_eq!(bytes.last(), Some(&0x1A));
+    /// assert_eq!(dbase::Reader::new(Cursor::new(bytes))?.read()?, vec![record]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn finish(mut self) -> Result<W, Error> {
+        self.close()?;
+        let mut dst = self.dst.take().expect(DST_TAKEN);
+        dst.flush()
+            .map_err(|error| Error::io_error(error, self.header.num_records as usize))?;
+        Ok(dst)
+    }
+
+    /// Finalizes the file and returns the inner destination, same as [finish](#method.finish)
+    #[deprecated(note = "Use TableWriter::finish")]
+    pub fn finalize(self) -> Result<W, Error> {
+        self.finish()
+    }
+
+    /// Returns the number of records in the destination
+    ///
+    /// For a writer created with
+    /// [build_with_file_dest_readwrite](struct.TableWriterBuilder.html#method.build_with_file_dest_readwrite),
+    /// this includes the records that were already in the file.
+    pub fn num_records_written(&self) -> usize {
+        self.header.num_records as usize
     }
 
     /// Close the writer
     ///
     /// Automatically closed when the writer is dropped,
-    /// use it if you want to handle error that can happen when the writer is closing
+    /// use [finish](#method.finish) if you want to handle error that can happen
+    /// when the writer is closing
     ///
     /// Calling close on an already closed writer is a no-op
     fn close(&mut self) -> Result<(), Error> {
         if !self.closed {
-            self.dst
-                .seek(SeekFrom::Start(0))
-                .map_err(|error| Error::io_error(error, self.header.num_records as usize))?;
+            let num_records = self.header.num_records as usize;
             self.update_header();
+            self.header.update_date();
+            self.dst_mut()
+                .seek(SeekFrom::Start(0))
+                .map_err(|error| Error::io_error(error, num_records))?;
             self.write_header()?;
-            self.dst
-                .seek(SeekFrom::End(0))
-                .map_err(|error| Error::io_error(error, self.header.num_records as usize))?;
-            self.dst
+            // Not the end of the file, which may already have a terminator
+            // when the writer was opened on an existing file
+            self.seek_to_record(num_records)?;
+            self.dst_mut()
                 .write_u8(FILE_TERMINATOR)
-                .map_err(|error| Error::io_error(error, self.header.num_records as usize))?;
+                .map_err(|error| Error::io_error(error, num_records))?;
             self.closed = true;
         }
         Ok(())
     }
 
+    /// Writes the deletion flag and the fields of the record at the current position
+    fn write_record_fields<R: WritableRecord>(
+        &mut self,
+        record: &R,
+        record_num: usize,
+    ) -> Result<(), Error> {
+        self.record_buffer.clear();
+        let mut field_writer = FieldWriter {
+            dst: &mut self.record_buffer,
+            fields_info: self.fields_info.iter().peekable(),
+            buffer: &mut self.buffer,
+            encoding: self.encoding,
+            options: self.options,
+            null_flags: &mut self.null_flags,
+        };
+        field_writer.null_flags.clear();
+
+        field_writer
+            .write_deletion_flag()
+            .map_err(|error| Error::io_error(error, record_num))?;
+        field_writer
+            .write_null_flags_if_next()
+            .map_err(|error| Error::new(error, record_num))?;
+
+        record
+            .write_using(&mut field_writer)
+            .map_err(|error| Error::new(error, record_num))?;
+
+        if let Some(missing_field) = field_writer.first_unwritten_field() {
+            return Err(Error {
+                record_num,
+                field: Some(missing_field.clone()),
+                kind: ErrorKind::NotEnoughFields,
+            });
+        }
+        self.dst
+            .as_mut()
+            .expect(DST_TAKEN)
+            .write_all(&self.record_buffer)
+            .map_err(|error| Error::io_error(error, record_num))
+    }
+
+    fn check_record_index(&self, index: usize) -> Result<(), Error> {
+        if index >= self.header.num_records as usize {
+            return Err(Error {
+                record_num: index,
+                field: None,
+                kind: ErrorKind::Message(format!(
+                    "Cannot update record {}, only {} records were written",
+                    index, self.header.num_records
+                )),
+            });
+        }
+        Ok(())
+    }
+
+    /// Seeks to the start of the record at `index`,
+    /// `num_records` being the end of the records
+    fn seek_to_record(&mut self, index: usize) -> Result<(), Error> {
+        self.seek_to_record_offset(index, 0)
+    }
+
+    fn seek_to_record_offset(&mut self, index: usize, offset: usize) -> Result<(), Error> {
+        self.update_header();
+        let position = self.header.offset_to_first_record as u64
+            + (index as u64 * u64::from(self.header.size_of_record))
+            + offset as u64;
+        self.dst_mut()
+            .seek(SeekFrom::Start(position))
+            .map_err(|error| Error::io_error(error, index))?;
+        Ok(())
+    }
+
+    fn dst_mut(&mut self) -> &mut W {
+        self.dst.as_mut().expect(DST_TAKEN)
+    }
+
     fn update_header(&mut self) {
-        let offset_to_first_record =
+        let mut offset_to_first_record =
             Header::SIZE + (self.fields_info.len() * FieldInfo::SIZE) + std::mem::size_of::<u8>();
+        if self.header.file_type.is_visual_fox_pro() {
+            offset_to_first_record += BACKLINK_SIZE as usize;
+        }
         let size_of_record = self
             .fields_info
             .iter()
@@ -611,17 +1595,143 @@ impl<W: Write + Seek> TableWriter<W> {
     }
 
     fn write_header(&mut self) -> Result<(), Error> {
+        check_fields(&self.fields_info)?;
+        let dst = self.dst.as_mut().expect(DST_TAKEN);
         self.header
-            .write_to(&mut self.dst)
+            .write_to(dst)
             .map_err(|error| Error::io_error(error, 0))?;
         for record_info in &self.fields_info {
             record_info
-                .write_to(&mut self.dst, self.encoding)
+                .write_to(dst, self.encoding)
                 .map_err(|error| Error::io_error(error, 0))?;
         }
-        self.dst
-            .write_u8(TERMINATOR_VALUE)
-            .map_err(|error| Error::io_error(error, 0))
+        dst.write_u8(TERMINATOR_VALUE)
+            .map_err(|error| Error::io_error(error, 0))?;
+        if self.header.file_type.is_visual_fox_pro() {
+            dst.write_all(&[0u8; BACKLINK_SIZE as usize])
+                .map_err(|error| Error::io_error(error, 0))?;
+        }
+        Ok(())
+    }
+}
+
+impl<W: Read + Write + Seek> TableWriter<W> {
+    /// Overwrites the value of the field named `field_name`
+    /// of the record at `index`, which must have already been written
+    ///
+    /// The other fields of the record are left untouched,
+    /// then the writer goes back to the end of the records so that
+    /// [write_record](#method.write_record) keeps appending.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), dbase::Error> {
+    /// use std::convert::TryFrom;
+    /// use std::io::Cursor;
+    /// use dbase::FieldValue;
+    ///
+    /// let mut writer = dbase::TableWriterBuilder::new()
+    ///     .add_character_field(dbase::FieldName::try_from("Name").unwrap(), 10)
+    ///     .add_numeric_field(dbase::FieldName::try_from("Age").unwrap(), 3, 0)
+    ///     .build_with_dest(Cursor::new(Vec::<u8>::new()));
+    ///
+    /// let mut record = dbase::Record::default();
+    /// record.insert("Name".to_string(), FieldValue::Character(Some("Yoshi".to_string())));
+    /// record.insert("Age".to_string(), FieldValue::Numeric(Some(32.0)));
+    /// writer.write_record(&record)?;
+    ///
+    /// writer.update_field_at(0, "Age", FieldValue::Numeric(Some(33.0)))?;
+    ///
+    /// let mut cursor = writer.finish()?;
+    /// cursor.set_position(0);
+    /// let records = dbase::Reader::new(cursor)?.read()?;
+    /// assert_eq!(records[0].get("Age"), Some(&FieldValue::Numeric(Some(33.0))));
+    /// assert_eq!(records[0].get("Name"), Some(&FieldValue::Character(Some("Yoshi".to_string()))));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn update_field_at(
+        &mut self,
+        record_index: usize,
+        field_name: &str,
+        value: FieldValue,
+    ) -> Result<(), Error> {
+        self.check_record_index(record_index)?;
+        let position = self
+            .fields_info
+            .iter()
+            .position(|info| !info.is_hidden() && info.name() == field_name)
+            .ok_or_else(|| Error {
+                record_num: record_index,
+                field: None,
+                kind: ErrorKind::Message(format!("Could not find field named '{}'", field_name)),
+            })?;
+        // 1 for the deletion flag
+        let field_offset = 1 + self.fields_info[..position]
+            .iter()
+            .map(|info| info.field_length as usize)
+            .sum::<usize>();
+
+        self.seek_to_record_offset(record_index, field_offset)?;
+        let result = self.write_single_field(record_index, position, &value);
+        self.seek_to_record(self.header.num_records as usize)?;
+        result
+    }
+
+    fn write_single_field(
+        &mut self,
+        record_index: usize,
+        position: usize,
+        value: &FieldValue,
+    ) -> Result<(), Error> {
+        let field_info = &self.fields_info[position];
+        let mut field_writer = FieldWriter {
+            dst: self.dst.as_mut().expect(DST_TAKEN),
+            fields_info: std::slice::from_ref(field_info).iter().peekable(),
+            buffer: &mut self.buffer,
+            encoding: self.encoding,
+            options: self.options,
+            null_flags: &mut self.null_flags,
+        };
+        field_writer.null_flags.clear();
+        field_writer
+            .write_next_field_value(value)
+            .map_err(|error| Error::new(error, record_index))?;
+
+        let null_flag_bit = field_info.null_flag_bit;
+        match null_flag_bit {
+            Some(bit) => self.update_null_flag(record_index, bit, value.is_null()),
+            None => Ok(()),
+        }
+    }
+
+    /// Sets or clears the bit of a field in the `_NullFlags` column of the record
+    fn update_null_flag(
+        &mut self,
+        record_index: usize,
+        bit: usize,
+        is_null: bool,
+    ) -> Result<(), Error> {
+        let null_flags_offset = 1 + self
+            .fields_info
+            .iter()
+            .take_while(|info| !info.is_null_flags())
+            .map(|info| info.field_length as usize)
+            .sum::<usize>();
+        self.seek_to_record_offset(record_index, null_flags_offset + bit / 8)?;
+        let dst = self.dst_mut();
+        let mut byte = dst
+            .read_u8()
+            .map_err(|error| Error::io_error(error, record_index))?;
+        if is_null {
+            byte |= 1 << (bit % 8);
+        } else {
+            byte &= !(1 << (bit % 8));
+        }
+        dst.seek(SeekFrom::Current(-1))
+            .and_then(|_| dst.write_u8(byte))
+            .map_err(|error| Error::io_error(error, record_index))
     }
 }
 
@@ -630,3 +1740,177 @@ impl<T: Write + Seek> Drop for TableWriter<T> {
         let _ = self.close();
     }
 }
+
+/// Infers the field of the `value` named `name`,
+/// `records` are used to get the length of Character, Numeric & Float fields
+fn infer_field(name: &str, value: &FieldValue, records: &[Record]) -> Result<FieldInfo, Error> {
+    let field_name = FieldName::try_from(name).map_err(|error| Error {
+        record_num: 0,
+        field: None,
+        kind: ErrorKind::Message(error.to_string()),
+    })?;
+    let field_type = value.field_type();
+    let info = match field_type {
+        FieldType::Character => {
+            let mut longest = 0;
+            for (record_num, record) in records.iter().enumerate() {
+                if let Some(FieldValue::Character(Some(s))) = record.get(name) {
+                    if s.len() > MAX_CHARACTER_LENGTH as usize {
+                        return Err(Error {
+                            record_num,
+                            field: Some(FieldInfo::new_unchecked(
+                                field_name,
+                                field_type,
+                                MAX_CHARACTER_LENGTH,
+                            )),
+                            kind: ErrorKind::ValueTooLong {
+                                field: name.to_string(),
+                                len: s.len(),
+                                max: MAX_CHARACTER_LENGTH as usize,
+                            },
+                        });
+                    }
+                    longest = longest.max(s.len());
+                }
+            }
+            FieldInfo::new_unchecked(field_name, field_type, longest.max(1) as u8)
+        }
+        FieldType::Numeric | FieldType::Float => {
+            // The shortest text that reads back as the same number
+            let texts = records
+                .iter()
+                .enumerate()
+                .filter_map(|(record_num, record)| match record.get(name) {
+                    Some(FieldValue::Numeric(Some(number))) => {
+                        Some((record_num, number.to_string()))
+                    }
+                    Some(FieldValue::Float(Some(number))) => Some((record_num, number.to_string())),
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
+            let split = |text: &str| {
+                let (integer_part, fractional_part) = text.split_once('.').unwrap_or((text, ""));
+                (integer_part.len(), fractional_part.len())
+            };
+            let integer_len = texts.iter().map(|(_, text)| split(text).0).max();
+            let decimals = texts.iter().map(|(_, text)| split(text).1).max();
+            let decimals = decimals.unwrap_or(0);
+            let length = integer_len.unwrap_or(1) + if decimals > 0 { decimals + 1 } else { 0 };
+            let mut info = FieldInfo::new_unchecked(field_name, field_type, MAX_CHARACTER_LENGTH);
+            if length > MAX_CHARACTER_LENGTH as usize {
+                let record_num = texts
+                    .iter()
+                    .max_by_key(|(_, text)| text.len())
+                    .map_or(0, |(record_num, _)| *record_num);
+                return Err(Error {
+                    record_num,
+                    field: Some(info),
+                    kind: ErrorKind::ValueTooLong {
+                        field: name.to_string(),
+                        len: length,
+                        max: MAX_CHARACTER_LENGTH as usize,
+                    },
+                });
+            }
+            info.field_length = length as u8;
+            info.num_decimal_places = decimals as u8;
+            info
+        }
+        FieldType::Memo => {
+            return Err(Error {
+                record_num: 0,
+                field: Some(FieldInfo::new_unchecked(field_name, field_type, 10)),
+                kind: ErrorKind::Message("Memo values cannot be written".to_string()),
+            });
+        }
+        // Types of a fixed size
+        _ => FieldInfo::new_unchecked(field_name, field_type, 0),
+    };
+    Ok(info)
+}
+
+/// One liner to write `records` to a new .dbf file at `path`,
+/// with fields inferred from the first record
+///
+/// The fields are the ones of the first record, in the same order,
+/// each one with the type of its value:
+///
+/// - `Character` fields are as long as the longest value (in bytes, at least 1)
+/// - `Numeric` & `Float` fields have as many digits & decimal places as needed
+///   to write all the values exactly as they are displayed
+/// - `Logical`, `Date`, `Integer`, `AutoIncrement`, `Currency`, `DateTime` & `Double`
+///   fields have the size of their type
+/// - `Memo` values cannot be written, they give an error
+///
+/// Fails, before creating the file, if there are no records,
+/// if a record does not have the same field names and value types as the first one,
+/// or if a value is longer than 254 bytes.
+/// The file is written in UTF-8, with the version needed by the types of its fields.
+///
+/// # Example
+///
+/// ```
+/// # fn main() -> Result<(), dbase::Error> {
+/// use dbase::Record;
+///
+/// let records = vec![
+///     Record::from_iter([("NAME".to_string(), "Paris".into()), ("AREA".to_string(), 105.4.into())]),
+///     Record::from_iter([("NAME".to_string(), "Lyon".into()), ("AREA".to_string(), 47.87.into())]),
+/// ];
+/// dbase::write_records("cities.dbf", &records)?;
+/// assert_eq!(dbase::read("cities.dbf")?, records);
+/// # std::fs::remove_file("cities.dbf").unwrap();
+/// # Ok(())
+/// # }
+/// ```
+pub fn write_records<P: AsRef<Path>>(path: P, records: &[Record]) -> Result<(), Error> {
+    let first = records.first().ok_or_else(|| Error {
+        record_num: 0,
+        field: None,
+        kind: ErrorKind::Message("Cannot infer the fields of an empty list of records".to_string()),
+    })?;
+    let fields = first
+        .iter()
+        .map(|(name, value)| infer_field(name, value, records))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    for (record_num, record) in records.iter().enumerate() {
+        if record.len() != fields.len() {
+            return Err(Error {
+                record_num,
+                field: None,
+                kind: ErrorKind::Message(format!(
+                    "The record has {} fields, the first one has {}",
+                    record.len(),
+                    fields.len()
+                )),
+            });
+        }
+        for field in &fields {
+            match record.get(field.name()) {
+                Some(value) if value.field_type() == field.field_type() => {}
+                Some(_) => {
+                    return Err(Error {
+                        record_num,
+                        field: Some(field.clone()),
+                        kind: ErrorKind::IncompatibleType,
+                    });
+                }
+                None => {
+                    return Err(Error {
+                        record_num,
+                        field: Some(field.clone()),
+                        kind: ErrorKind::Message(format!(
+                            "The record has no value for the field '{}'",
+                            field.name()
+                        )),
+                    });
+                }
+            }
+        }
+    }
+
+    TableWriterBuilder::from_fields(fields)
+        .build_with_file_dest(path)?
+        .write_records(records)
+}
@@ -5,7 +5,10 @@ use std::fmt::Debug;
 use std::io::Cursor;
 use std::{collections::HashMap, convert::TryFrom};
 
-use dbase::{ErrorKind, FieldName, FieldValue, Reader, TableWriterBuilder};
+use dbase::{
+    ErrorKind, FieldName, FieldValue, InvalidFieldNameError, Reader, TableWriterBuilder,
+    TruncationPolicy,
+};
 
 /// To make sure it fails if you specify the wrong encoding label.
 #[test]
@@ -15,7 +18,7 @@ fn shift_jis_new_with_label_with_wrong_label() {
     match result.err().unwrap().kind() {
         ErrorKind::InvalidEncoding => (),
         _ => {
-            assert!(false, "a ErrorKind is not a ErrorKind::InvalidEncoding");
+            panic!("a ErrorKind is not a ErrorKind::InvalidEncoding");
         }
     }
 }
@@ -35,9 +38,14 @@ dbase_record! {
 }
 
 #[test]
-fn shift_jis_write_file_with_encoded_field_name() {
+fn shift_jis_write_file() {
     let label = "shift_jis";
-    let name = "属性1";
+    // Field names are limited to ASCII characters, whatever the encoding
+    assert_eq!(
+        FieldName::try_from("属性1").unwrap_err(),
+        InvalidFieldNameError::InvalidCharacter('属')
+    );
+    let name = "ZOKUSEI1";
     let value = "吾輩は猫である。名前はまだない。";
     let field_name = FieldName::try_from(name).unwrap();
 
@@ -58,20 +66,14 @@ fn shift_jis_write_file_with_encoded_field_name() {
     let field = reader.fields().get(1).unwrap();
     assert_eq!(field.name(), name);
     let read_records = reader.read_as::<TestRecord>().unwrap();
-    let read_record = read_records.get(0).unwrap();
+    let read_record = read_records.first().unwrap();
     assert_eq!(read_record.text, value);
 }
 
 fn character_field_value(value: Option<&FieldValue>) -> Option<String> {
     match value {
-        Some(value) => match value {
-            FieldValue::Character(value) => match value {
-                Some(value) => Some(value.clone()),
-                None => None,
-            },
-            _ => None,
-        },
-        None => None,
+        Some(FieldValue::Character(value)) => value.clone(),
+        _ => None,
     }
 }
 
@@ -94,8 +96,75 @@ fn shift_jis_read_field_names_and_values() {
         let book = character_field_value(record.get("書籍名"));
         let author = character_field_value(record.get("著者"));
         let book_and_author = data.get(&index).unwrap();
-        let book = book.as_deref();
         assert_eq!(book.as_deref(), book_and_author.0);
         assert_eq!(author.as_deref(), book_and_author.1);
     }
 }
+
+#[test]
+fn shift_jis_write_too_long_value() {
+    let label = "shift_jis";
+    let value = "吾輩は猫である。";
+    let mut record = dbase::Record::default();
+    record.insert(
+        "text".to_string(),
+        FieldValue::Character(Some(value.to_string())),
+    );
+
+    // Each character is encoded on 2 bytes
+    let builder = TableWriterBuilder::new_with_label(label)
+        .unwrap()
+        .add_character_field(FieldName::try_from("text").unwrap(), 5);
+
+    let error = builder
+        .clone()
+        .build_with_dest(Cursor::new(Vec::<u8>::new()))
+        .write_records([&record])
+        .unwrap_err();
+    assert!(matches!(
+        error.kind(),
+        ErrorKind::ValueTooLong {
+            len: 16,
+            max: 5,
+            ..
+        }
+    ));
+
+    let mut dst = Cursor::new(Vec::<u8>::new());
+    builder
+        .truncation_policy(TruncationPolicy::Truncate)
+        .build_with_dest(&mut dst)
+        .write_records([&record])
+        .unwrap();
+    dst.set_position(0);
+
+    let mut reader = Reader::new_with_label(dst, label).unwrap();
+    let records = reader.read().unwrap();
+    assert_eq!(
+        records[0].get("text"),
+        Some(&FieldValue::Character(Some("吾輩".to_string())))
+    );
+}
+
+#[test]
+fn shift_jis_code_page_mark_round_trip() {
+    let name = "ZOKUSEI1";
+    let value = "吾輩は猫である。";
+    let mut writer = TableWriterBuilder::new_with_label("shift_jis")
+        .unwrap()
+        .add_character_field(FieldName::try_from(name).unwrap(), 40)
+        .build_with_dest(Cursor::new(Vec::<u8>::new()));
+    let record = TestRecord {
+        text: value.to_string(),
+    };
+    writer.write_record(&record).unwrap();
+    let mut dst = writer.finish().unwrap();
+    assert_eq!(dst.get_ref()[29], 0x13);
+
+    // No label is given, the encoding is the one of the code page mark
+    dst.set_position(0);
+    let mut reader = Reader::new(dst).unwrap();
+    assert_eq!(reader.encoding(), encoding_rs::SHIFT_JIS);
+    assert_eq!(reader.fields()[1].name(), name);
+    assert_eq!(reader.read_as::<TestRecord>().unwrap(), vec![record]);
+}
@@ -0,0 +1,131 @@
+#[cfg(feature = "chrono")]
+#[macro_use]
+extern crate dbase;
+#[cfg(all(feature = "chrono", feature = "serde"))]
+extern crate serde_derive;
+
+#[cfg(feature = "chrono")]
+mod chrono_tests {
+    use std::convert::TryFrom;
+    use std::io::Cursor;
+
+    use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+    use dbase::{FieldName, Reader, TableWriterBuilder};
+
+    dbase_record! {
+        #[derive(Clone, Debug, PartialEq)]
+        struct Event {
+            day: NaiveDate,
+            cancelled_on: Option<NaiveDate>,
+            at: NaiveDateTime,
+        }
+    }
+
+    fn datetime(y: i32, m: u32, d: u32, time: NaiveTime) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap().and_time(time)
+    }
+
+    #[test]
+    fn chrono_values_round_trip() {
+        let events = vec![
+            Event {
+                day: NaiveDate::from_ymd_opt(2020, 2, 29).unwrap(),
+                cancelled_on: None,
+                at: datetime(2020, 2, 29, NaiveTime::from_hms_opt(23, 59, 59).unwrap()),
+            },
+            Event {
+                day: NaiveDate::from_ymd_opt(1899, 12, 30).unwrap(),
+                cancelled_on: NaiveDate::from_ymd_opt(1900, 1, 1),
+                at: datetime(1970, 1, 1, NaiveTime::from_hms_opt(0, 0, 0).unwrap()),
+            },
+        ];
+
+        let mut dst = Cursor::new(Vec::<u8>::new());
+        let writer = TableWriterBuilder::new()
+            .add_date_field(FieldName::try_from("day").unwrap())
+            .add_date_field(FieldName::try_from("cancelled").unwrap())
+            .add_datetime_field(FieldName::try_from("at").unwrap())
+            .build_with_dest(&mut dst);
+        writer.write_records(&events).unwrap();
+        dst.set_position(0);
+
+        let mut reader = Reader::new(dst).unwrap();
+        assert_eq!(reader.read_as::<Event>().unwrap(), events);
+    }
+
+    #[test]
+    fn sub_millisecond_precision_is_truncated_on_write() {
+        let event = Event {
+            day: NaiveDate::from_ymd_opt(2021, 6, 1).unwrap(),
+            cancelled_on: None,
+            at: datetime(
+                2021,
+                6,
+                1,
+                NaiveTime::from_hms_micro_opt(10, 20, 30, 500_250).unwrap(),
+            ),
+        };
+
+        let mut dst = Cursor::new(Vec::<u8>::new());
+        let writer = TableWriterBuilder::new()
+            .add_date_field(FieldName::try_from("day").unwrap())
+            .add_date_field(FieldName::try_from("cancelled").unwrap())
+            .add_datetime_field(FieldName::try_from("at").unwrap())
+            .build_with_dest(&mut dst);
+        writer.write_records(std::slice::from_ref(&event)).unwrap();
+        dst.set_position(0);
+
+        let read = Reader::new(dst).unwrap().read_as::<Event>().unwrap();
+        assert_eq!(
+            read[0].at,
+            datetime(
+                2021,
+                6,
+                1,
+                NaiveTime::from_hms_milli_opt(10, 20, 30, 500).unwrap()
+            )
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_derive_with_chrono_fields() {
+        use serde_derive::{Deserialize, Serialize};
+
+        #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+        struct Booking {
+            name: String,
+            day: NaiveDate,
+            returned: Option<NaiveDate>,
+            at: NaiveDateTime,
+        }
+
+        let bookings = vec![
+            Booking {
+                name: "first".to_string(),
+                day: NaiveDate::from_ymd_opt(2024, 2, 29).unwrap(),
+                returned: NaiveDate::from_ymd_opt(2024, 3, 2),
+                at: datetime(2024, 2, 29, NaiveTime::from_hms_opt(8, 0, 1).unwrap()),
+            },
+            Booking {
+                name: "second".to_string(),
+                day: NaiveDate::from_ymd_opt(1999, 12, 31).unwrap(),
+                returned: None,
+                at: datetime(2000, 1, 1, NaiveTime::from_hms_opt(0, 0, 0).unwrap()),
+            },
+        ];
+
+        let mut dst = Cursor::new(Vec::<u8>::new());
+        let writer = TableWriterBuilder::new()
+            .add_character_field(FieldName::try_from("name").unwrap(), 20)
+            .add_date_field(FieldName::try_from("day").unwrap())
+            .add_date_field(FieldName::try_from("returned").unwrap())
+            .add_datetime_field(FieldName::try_from("at").unwrap())
+            .build_with_dest(&mut dst);
+        writer.write_records(&bookings).unwrap();
+        dst.set_position(0);
+
+        let mut reader = Reader::new(dst).unwrap();
+        assert_eq!(reader.read_as::<Booking>().unwrap(), bookings);
+    }
+}
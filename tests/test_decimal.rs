@@ -0,0 +1,93 @@
+extern crate dbase;
+
+#[cfg(feature = "decimal")]
+mod decimal_tests {
+    use std::convert::TryFrom;
+    use std::io::{Cursor, Read, Seek, Write};
+    use std::str::FromStr;
+
+    use rust_decimal::Decimal;
+
+    use dbase::{
+        FieldIOError, FieldIterator, FieldName, FieldWriter, ReadableRecord, Reader,
+        TableWriterBuilder, WritableRecord,
+    };
+
+    #[derive(Debug, PartialEq)]
+    struct Payment {
+        id: Decimal,
+        amount: Option<Decimal>,
+        fee: Decimal,
+    }
+
+    impl ReadableRecord for Payment {
+        fn read_using<T>(field_iterator: &mut FieldIterator<T>) -> Result<Self, FieldIOError>
+        where
+            T: Read + Seek,
+        {
+            Ok(Self {
+                id: field_iterator
+                    .read_next_field_as_decimal()?
+                    .value
+                    .expect("id is never empty"),
+                amount: field_iterator.read_next_field_as_decimal()?.value,
+                fee: field_iterator
+                    .read_next_field_as_decimal()?
+                    .value
+                    .expect("fee is never empty"),
+            })
+        }
+    }
+
+    impl WritableRecord for Payment {
+        fn write_using<'a, W: Write>(
+            &self,
+            field_writer: &mut FieldWriter<'a, W>,
+        ) -> Result<(), FieldIOError> {
+            field_writer.write_next_field_value(&self.id)?;
+            field_writer.write_next_field_value(&self.amount)?;
+            field_writer.write_next_field_value(&self.fee)?;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_decimal_round_trip() {
+        let records = vec![
+            Payment {
+                id: Decimal::from_str("92233720368547758.07").unwrap(),
+                amount: Some(Decimal::from_str("-1234567890.1234").unwrap()),
+                fee: Decimal::from_str("0.0001").unwrap(),
+            },
+            Payment {
+                id: Decimal::from_str("1").unwrap(),
+                amount: None,
+                fee: Decimal::from_str("12.5").unwrap(),
+            },
+            Payment {
+                id: Decimal::from_str("2").unwrap(),
+                amount: None,
+                // The largest Currency value
+                fee: Decimal::from_str("922337203685477.5807").unwrap(),
+            },
+        ];
+        // The id cannot be represented exactly as a f64
+        assert_ne!(
+            "92233720368547758.07".parse::<f64>().unwrap().to_string(),
+            "92233720368547758.07"
+        );
+
+        let mut dst = Cursor::new(Vec::<u8>::new());
+        let writer = TableWriterBuilder::new()
+            .add_numeric_field(FieldName::try_from("id").unwrap(), 20, 2)
+            .add_numeric_field(FieldName::try_from("amount").unwrap(), 20, 4)
+            .add_currency_field(FieldName::try_from("fee").unwrap())
+            .build_with_dest(&mut dst);
+        writer.write_records(&records).unwrap();
+        dst.set_position(0);
+
+        let mut reader = Reader::new(dst).unwrap();
+        let read_records = reader.read_as::<Payment>().unwrap();
+        assert_eq!(read_records, records);
+    }
+}
@@ -0,0 +1,184 @@
+#[cfg(feature = "derive")]
+mod derive_tests {
+    use std::convert::{TryFrom, TryInto};
+    use std::fmt::Debug;
+    use std::io::Cursor;
+
+    use dbase::{
+        Date, DateTime, FieldName, FieldValue, ReadableRecord, Reader, Record, TableWriterBuilder,
+        Time, WritableRecord,
+    };
+
+    fn write_read_compare<R: WritableRecord + ReadableRecord + Debug + PartialEq>(
+        records: &Vec<R>,
+        writer_builder: TableWriterBuilder,
+    ) {
+        let mut dst = Cursor::new(Vec::<u8>::new());
+        let writer = writer_builder.build_with_dest(&mut dst);
+
+        writer.write_records(records).unwrap();
+        dst.set_position(0);
+
+        let mut reader = Reader::new(dst).unwrap();
+        let read_records = reader.read_as::<R>().unwrap();
+
+        assert_eq!(&read_records, records);
+    }
+
+    #[derive(Clone, Debug, PartialEq, dbase::ReadableRecord, dbase::WritableRecord)]
+    struct FoxProRecord {
+        datetime: DateTime,
+        double: f64,
+        currency: f64,
+        integer: i32,
+    }
+
+    #[test]
+    fn from_scratch_fox_pro_struct_record() {
+        let writer_builder = TableWriterBuilder::new()
+            .add_datetime_field(FieldName::try_from("datetime").unwrap())
+            .add_double_field(FieldName::try_from("double").unwrap())
+            .add_currency_field(FieldName::try_from("currency").unwrap())
+            .add_integer_field(FieldName::try_from("integer").unwrap());
+
+        let records = vec![FoxProRecord {
+            datetime: DateTime::new(Date::new(12, 2, 1999), Time::new(21, 20, 35)),
+            double: 8649.48851,
+            currency: 3489.9612,
+            integer: 42069,
+        }];
+
+        write_read_compare(&records, writer_builder);
+    }
+
+    #[derive(Clone, Debug, PartialEq, dbase::ReadableRecord, dbase::WritableRecord)]
+    pub struct User {
+        pub first_name: String,
+        pub last_name: String,
+    }
+
+    // We just test that this compiles
+    #[derive(dbase::ReadableRecord, dbase::WritableRecord)]
+    #[allow(dead_code)]
+    struct TestStructWithoutDerive {
+        this_should_compile: String,
+    }
+
+    #[test]
+    fn the_classical_user_record_example() {
+        let users = vec![
+            User {
+                // cspell:disable-next-line
+                first_name: "Ferrys".to_string(),
+                last_name: "Rust".to_string(),
+            },
+            User {
+                first_name: "Alex".to_string(),
+                last_name: "Rider".to_string(),
+            },
+        ];
+
+        let writer_builder = TableWriterBuilder::new()
+            .add_character_field("First_Name".try_into().unwrap(), 50)
+            .add_character_field("Last_Name".try_into().unwrap(), 50);
+        write_read_compare(&users, writer_builder);
+    }
+
+    #[derive(Clone, Debug, PartialEq, dbase::ReadableRecord, dbase::WritableRecord)]
+    #[dbase(by_name)]
+    struct Station {
+        #[dbase(rename = "line")]
+        line_color: String,
+        name: String,
+        #[dbase(skip)]
+        visited: bool,
+    }
+
+    #[test]
+    fn test_read_by_name_with_rename_and_skip() {
+        let mut reader = Reader::from_path("tests/data/stations.dbf").unwrap();
+        let stations = reader.read_as::<Station>().unwrap();
+        assert_eq!(
+            stations[0],
+            Station {
+                line_color: "blue".to_string(),
+                // cspell:disable-next-line
+                name: "Van Dorn Street".to_string(),
+                visited: false,
+            }
+        );
+
+        // The fields of the writer are in another order than the ones of the struct
+        let writer_builder = TableWriterBuilder::new()
+            .add_character_field("name".try_into().unwrap(), 50)
+            .add_character_field("line".try_into().unwrap(), 10);
+        write_read_compare(&stations, writer_builder);
+    }
+
+    #[test]
+    fn test_write_by_name_missing_field() {
+        let writer = TableWriterBuilder::new()
+            .add_character_field("name".try_into().unwrap(), 50)
+            .add_character_field("visited".try_into().unwrap(), 10)
+            .build_with_dest(Cursor::new(Vec::<u8>::new()));
+        let station = Station {
+            line_color: "red".to_string(),
+            name: "Gallery Place".to_string(),
+            visited: true,
+        };
+        assert!(writer.write_records(&[station]).is_err());
+    }
+
+    #[derive(Clone, Debug, PartialEq, dbase::ReadableRecord, dbase::WritableRecord)]
+    struct Positional {
+        name: String,
+        #[dbase(skip)]
+        note: Option<String>,
+        count: i32,
+    }
+
+    #[test]
+    fn test_skip_positional_field() {
+        let records = vec![Positional {
+            name: "skipped".to_string(),
+            note: None,
+            count: 3,
+        }];
+        let writer_builder = TableWriterBuilder::new()
+            .add_character_field("name".try_into().unwrap(), 10)
+            .add_integer_field("count".try_into().unwrap());
+        write_read_compare(&records, writer_builder);
+    }
+
+    #[derive(Clone, Debug, PartialEq, dbase::ReadableRecord, dbase::WritableRecord)]
+    #[dbase(by_name)]
+    struct Measure<T> {
+        label: String,
+        value: T,
+    }
+
+    #[test]
+    fn test_generic_record() {
+        let records = vec![Measure {
+            label: "width".to_string(),
+            value: 12.5f64,
+        }];
+        let writer_builder = TableWriterBuilder::new()
+            .add_character_field("label".try_into().unwrap(), 10)
+            .add_numeric_field("value".try_into().unwrap(), 10, 2);
+        write_read_compare(&records, writer_builder.clone());
+
+        let mut dst = Cursor::new(Vec::<u8>::new());
+        let writer = writer_builder.build_with_dest(&mut dst);
+        writer.write_records(&records).unwrap();
+        dst.set_position(0);
+        let records = Reader::new(dst).unwrap().read().unwrap();
+        let mut expected = Record::default();
+        expected.insert(
+            "label".to_string(),
+            FieldValue::Character(Some("width".to_string())),
+        );
+        expected.insert("value".to_string(), FieldValue::Numeric(Some(12.5)));
+        assert_eq!(records, vec![expected]);
+    }
+}
@@ -0,0 +1,55 @@
+#[cfg(feature = "rayon")]
+#[macro_use]
+extern crate dbase;
+
+#[cfg(feature = "rayon")]
+mod rayon_tests {
+    use std::convert::TryFrom;
+
+    use dbase::{FieldName, Reader, Record, TableWriterBuilder};
+
+    dbase_record! {
+        #[derive(Clone, Debug, PartialEq)]
+        struct Measure {
+            id: Option<f64>,
+            label: String,
+        }
+    }
+
+    #[test]
+    fn par_read_as_matches_read_as() {
+        let path = std::env::temp_dir().join("dbase_par_read_as.dbf");
+        let measures = (0..1000)
+            .map(|i| Measure {
+                id: Some(f64::from(i)),
+                label: format!("measure {}", i),
+            })
+            .collect::<Vec<_>>();
+
+        let writer = TableWriterBuilder::new()
+            .add_numeric_field(FieldName::try_from("id").unwrap(), 10, 0)
+            .add_character_field(FieldName::try_from("label").unwrap(), 20)
+            .build_with_file_dest(&path)
+            .unwrap();
+        writer.write_records(&measures).unwrap();
+
+        let read_measures = Reader::par_read_as::<Measure, _>(&path).unwrap();
+        assert_eq!(read_measures, measures);
+
+        let records = Reader::from_path(&path).unwrap().read().unwrap();
+        let par_records = Reader::par_read_as::<Record, _>(&path).unwrap();
+        assert_eq!(par_records, records);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn par_read_as_existing_file() {
+        let records = Reader::from_path("tests/data/stations.dbf")
+            .unwrap()
+            .read()
+            .unwrap();
+        let par_records = Reader::par_read_as::<Record, _>("tests/data/stations.dbf").unwrap();
+        assert_eq!(par_records, records);
+    }
+}
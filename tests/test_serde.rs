@@ -42,7 +42,7 @@ mod serde_tests {
         let records = vec![DeserializableRecord {
             name: "Holy Fawn".to_string(),
             price: 10.2,
-            date: dbase::Date::new(01, 01, 2012),
+            date: dbase::Date::new(1, 1, 2012),
             available: true,
             score: 9.87,
         }];
@@ -57,11 +57,38 @@ mod serde_tests {
         write_read_compare(&records, writer_builder);
     }
 
+    #[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+    struct Counters {
+        visits: u32,
+        total: i64,
+        level: u8,
+    }
+
+    #[test]
+    fn test_serde_integer_widths_roundtrip() {
+        let records = vec![Counters {
+            visits: 1_000_000,
+            total: 12_345_678_901,
+            level: 7,
+        }];
+
+        let writer_builder = TableWriterBuilder::new()
+            .add_integer_field(FieldName::try_from("visits").unwrap())
+            .add_numeric_field(FieldName::try_from("total").unwrap(), 15, 0)
+            .add_integer_field(FieldName::try_from("level").unwrap());
+
+        write_read_compare(&records, writer_builder);
+    }
+
     #[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
     struct DeserializableStation {
+        #[serde(rename = "NAME")]
         name: String,
+        #[serde(rename = "MARKER_COL")]
         marker_col: Option<String>,
+        #[serde(rename = "MARKER_SYM")]
         marker_symbol: String,
+        #[serde(rename = "LINE")]
         line: Option<String>,
     }
 
@@ -80,6 +107,84 @@ mod serde_tests {
         );
     }
 
+    #[test]
+    fn test_serde_fields_matched_by_name() {
+        #[derive(Deserialize, PartialEq, Debug)]
+        struct ShuffledStation {
+            line: String,
+            #[serde(rename = "marker-sym")]
+            symbol: String,
+            name: String,
+            #[serde(rename = "marker-col")]
+            color: String,
+        }
+
+        let mut reader = Reader::from_path("tests/data/stations.dbf").unwrap();
+        let records = reader.read_as::<ShuffledStation>().unwrap();
+        assert_eq!(
+            records[0],
+            ShuffledStation {
+                line: "blue".to_string(),
+                symbol: "rail-metro".to_string(),
+                // cspell:disable-next-line
+                name: "Van Dorn Street".to_string(),
+                color: "#0000ff".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_serde_extra_columns_are_ignored() {
+        #[derive(Deserialize, PartialEq, Debug)]
+        struct StationLine {
+            line: String,
+            name: String,
+        }
+
+        let mut reader = Reader::from_path("tests/data/stations.dbf").unwrap();
+        let records = reader.read_as::<StationLine>().unwrap();
+        assert_eq!(records.len(), 6);
+        assert_eq!(records[0].line, "blue");
+        // cspell:disable-next-line
+        assert_eq!(records[0].name, "Van Dorn Street");
+    }
+
+    #[test]
+    fn test_serde_missing_column() {
+        #[derive(Deserialize, Debug)]
+        #[allow(dead_code)]
+        struct StationWithZone {
+            name: String,
+            zone: String,
+        }
+
+        let mut reader = Reader::from_path("tests/data/stations.dbf").unwrap();
+        let error = reader.read_as::<StationWithZone>().unwrap_err();
+        match error.kind() {
+            ErrorKind::Message(message) => assert!(message.contains("`zone`"), "{}", message),
+            kind => panic!("The kind is not the expected one: {}", kind),
+        }
+    }
+
+    #[test]
+    fn test_serde_read_with_deletion_flag() {
+        let mut bytes = std::fs::read("tests/data/stations_optional.dbf").unwrap();
+        let header_size = usize::from(u16::from_le_bytes([bytes[8], bytes[9]]));
+        let record_size = usize::from(u16::from_le_bytes([bytes[10], bytes[11]]));
+        bytes[header_size + 3 * record_size] = b'*';
+
+        let mut reader = Reader::new(Cursor::new(bytes)).unwrap();
+        let records = reader.read_as_with_meta::<DeserializableStation>().unwrap();
+        let deleted = records
+            .iter()
+            .enumerate()
+            .filter(|(_, (is_deleted, _))| *is_deleted)
+            .map(|(i, _)| i)
+            .collect::<Vec<_>>();
+        assert_eq!(deleted, vec![3]);
+        assert_eq!(records[3].1.name, "Judiciary Sq");
+    }
+
     #[test]
     fn test_serde_optional_types() {
         #[derive(Serialize, Deserialize, PartialEq, Debug)]
@@ -102,6 +207,130 @@ mod serde_tests {
         write_read_compare(&records, writer_builder);
     }
 
+    #[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+    struct OptionalValues {
+        name: Option<String>,
+        population: Option<f64>,
+        area: Option<f32>,
+        capital: Option<bool>,
+        founded: Option<dbase::Date>,
+    }
+
+    #[test]
+    fn test_serde_optional_fields_roundtrip() {
+        let writer_builder = TableWriterBuilder::new()
+            .add_character_field(FieldName::try_from("name").unwrap(), 20)
+            .add_numeric_field(FieldName::try_from("population").unwrap(), 12, 0)
+            .add_float_field(FieldName::try_from("area").unwrap(), 10, 2)
+            .add_logical_field(FieldName::try_from("capital").unwrap())
+            .add_date_field(FieldName::try_from("founded").unwrap());
+
+        let records = vec![
+            OptionalValues {
+                name: Some("Lyon".to_string()),
+                population: Some(522_250.0),
+                area: Some(47.87),
+                capital: Some(false),
+                founded: Some(dbase::Date::new(1, 1, 1900)),
+            },
+            OptionalValues {
+                name: None,
+                population: None,
+                area: None,
+                capital: None,
+                founded: None,
+            },
+            OptionalValues {
+                name: Some("Paris".to_string()),
+                population: None,
+                area: Some(105.4),
+                capital: Some(true),
+                founded: None,
+            },
+        ];
+        write_read_compare(&records, writer_builder);
+    }
+
+    #[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Debug)]
+    #[serde(rename_all = "UPPERCASE")]
+    enum Status {
+        Active,
+        Suspended,
+        #[serde(rename = "gone")]
+        Closed,
+    }
+
+    #[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+    struct Membership {
+        status: Status,
+        previous: Option<Status>,
+    }
+
+    #[test]
+    fn test_serde_unit_enum_roundtrip() {
+        let writer_builder = TableWriterBuilder::new()
+            .add_character_field(FieldName::try_from("status").unwrap(), 10)
+            .add_character_field(FieldName::try_from("previous").unwrap(), 10);
+        let records = vec![
+            Membership {
+                status: Status::Suspended,
+                previous: Some(Status::Active),
+            },
+            Membership {
+                status: Status::Closed,
+                previous: None,
+            },
+        ];
+        write_read_compare(&records, writer_builder.clone());
+
+        // The variants are stored as their (renamed) name
+        let mut dst = Cursor::new(Vec::<u8>::new());
+        writer_builder
+            .build_with_dest(&mut dst)
+            .write_records(&records)
+            .unwrap();
+        dst.set_position(0);
+        let read_records = Reader::new(dst).unwrap().read().unwrap();
+        assert_eq!(
+            read_records[0].get("status"),
+            Some(&dbase::FieldValue::Character(Some("SUSPENDED".to_string())))
+        );
+        assert_eq!(
+            read_records[1].get("status"),
+            Some(&dbase::FieldValue::Character(Some("gone".to_string())))
+        );
+    }
+
+    #[test]
+    fn test_serde_unknown_enum_variant() {
+        let mut dst = Cursor::new(Vec::<u8>::new());
+        let mut writer = TableWriterBuilder::new()
+            .add_character_field(FieldName::try_from("status").unwrap(), 10)
+            .add_character_field(FieldName::try_from("previous").unwrap(), 10)
+            .build_with_dest(&mut dst);
+        let mut record = dbase::Record::default();
+        record.insert(
+            "status".to_string(),
+            dbase::FieldValue::Character(Some("Active".to_string())),
+        );
+        record.insert("previous".to_string(), dbase::FieldValue::Character(None));
+        writer.write_record(&record).unwrap();
+        writer.finish().unwrap();
+        dst.set_position(0);
+
+        let error = Reader::new(dst)
+            .unwrap()
+            .read_as::<Membership>()
+            .unwrap_err();
+        match error.kind() {
+            ErrorKind::Message(message) => {
+                assert!(message.contains("unknown variant `Active`"), "{}", message);
+                assert!(message.contains("`ACTIVE`"), "{}", message);
+            }
+            kind => panic!("The kind is not the expected one: {}", kind),
+        }
+    }
+
     #[test]
     fn test_serde_tuple() {
         let writer_builder = TableWriterBuilder::new()
@@ -125,14 +354,8 @@ mod serde_tests {
             .add_date_field(FieldName::try_from("date").unwrap());
 
         let records = vec![
-            Record {
-                0: true,
-                1: dbase::Date::new(12, 10, 2012),
-            },
-            Record {
-                0: false,
-                1: dbase::Date::new(12, 11, 2005),
-            },
+            Record(true, dbase::Date::new(12, 10, 2012)),
+            Record(false, dbase::Date::new(12, 11, 2005)),
         ];
         write_read_compare(&records, writer_builder);
     }
@@ -161,16 +384,13 @@ mod serde_tests {
 
         let writer = TableWriterBuilder::new()
             .add_logical_field(FieldName::try_from("yes").unwrap())
-            .add_character_field(FieldName::try_from("not present").unwrap(), 50)
+            .add_character_field(FieldName::try_from("notpresent").unwrap(), 50)
             .build_with_dest(Cursor::new(Vec::<u8>::new()));
 
         let error = writer
             .write_records(&records)
             .expect_err("We expected an Error");
-        match error.kind() {
-            ErrorKind::NotEnoughFields => assert!(true),
-            _ => assert!(false),
-        }
+        assert!(matches!(error.kind(), ErrorKind::NotEnoughFields));
     }
 
     #[test]
@@ -189,8 +409,8 @@ mod serde_tests {
             .expect_err("Expected an error");
 
         match error.kind() {
-            ErrorKind::TooManyFields => assert!(true),
-            kind => assert!(false, "The kind is not the expected one: {}", kind),
+            ErrorKind::TooManyFields => {}
+            kind => panic!("The kind is not the expected one: {}", kind),
         }
     }
 
@@ -212,14 +432,295 @@ mod serde_tests {
 
         let records = vec![Record {
             datetime: dbase::DateTime::new(
-                dbase::Date::new(12, 05, 2130),
+                dbase::Date::new(12, 5, 2130),
                 dbase::Time::new(15, 52, 12),
             ),
-            currency: 79841.156846,
+            currency: 79841.1568,
             double: 976114.1846,
             integer: -15315,
         }];
 
         write_read_compare(&records, writer_builder);
     }
+
+    #[test]
+    fn test_serde_read_as_map_of_field_values() {
+        use dbase::FieldValue;
+        use std::collections::HashMap;
+
+        for path in ["tests/data/stations.dbf", "tests/data/memo.dbf"] {
+            let expected = dbase::read(path).unwrap();
+            let records = Reader::from_path(path)
+                .unwrap()
+                .read_as::<HashMap<String, FieldValue>>()
+                .unwrap();
+            assert_eq!(records.len(), expected.len());
+            for (record, expected) in records.into_iter().zip(expected) {
+                assert_eq!(record, expected.into_iter().collect::<HashMap<_, _>>());
+            }
+        }
+    }
+
+    #[test]
+    fn test_serde_record_to_json() {
+        let record = dbase::read("tests/data/stations.dbf").unwrap().remove(0);
+        let json = serde_json::to_value(&record).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "name": "Van Dorn Street",
+                "marker-col": "#0000ff",
+                "marker-sym": "rail-metro",
+                "line": "blue",
+            })
+        );
+    }
+
+    #[test]
+    fn test_serde_field_values_to_json() {
+        use dbase::{Date, DateTime, FieldValue, Record, Time};
+
+        let mut record = Record::default();
+        record.insert("empty".to_string(), FieldValue::Character(None));
+        record.insert("price".to_string(), FieldValue::Numeric(Some(12.5)));
+        record.insert("count".to_string(), FieldValue::Integer(-3));
+        record.insert("paid".to_string(), FieldValue::Logical(Some(true)));
+        record.insert(
+            "day".to_string(),
+            FieldValue::Date(Some(Date::new(31, 1, 2024))),
+        );
+        record.insert(
+            "at".to_string(),
+            FieldValue::DateTime(DateTime::new(Date::new(31, 1, 2024), Time::new(8, 5, 0))),
+        );
+
+        let json = serde_json::to_string(&&record).unwrap();
+        assert_eq!(
+            json,
+            r#"{"empty":null,"price":12.5,"count":-3,"paid":true,"day":"2024-01-31","at":"2024-01-31T08:05:00"}"#
+        );
+    }
+
+    #[test]
+    fn test_serde_records_to_json_value_and_back() {
+        use dbase::RecordSeed;
+        use serde::de::DeserializeSeed;
+
+        let mut reader = Reader::from_path("tests/data/stations.dbf").unwrap();
+        let records = reader.read().unwrap();
+        let json = serde_json::Value::Array(
+            records
+                .iter()
+                .map(|record| serde_json::to_value(record).unwrap())
+                .collect(),
+        );
+
+        let read_back: Vec<dbase::Record> = json
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|value| RecordSeed::new(reader.fields()).deserialize(value).unwrap())
+            .collect();
+        assert_eq!(read_back, records);
+
+        let unknown_field = serde_json::json!({ "name": "Pentagon", "color": "blue" });
+        assert!(RecordSeed::new(reader.fields())
+            .deserialize(&unknown_field)
+            .is_err());
+    }
+
+    #[test]
+    fn test_serde_field_values_from_json() {
+        use dbase::{CurrencyValue, Date, DateTime, FieldType, FieldValue, FieldValueSeed, Time};
+        use serde::de::DeserializeSeed;
+
+        let expected = [
+            (FieldType::Character, "null", FieldValue::Character(None)),
+            (
+                FieldType::Character,
+                r#""Yoshi""#,
+                FieldValue::Character(Some("Yoshi".to_string())),
+            ),
+            (FieldType::Numeric, "12", FieldValue::Numeric(Some(12.0))),
+            (FieldType::Numeric, "null", FieldValue::Numeric(None)),
+            (FieldType::Float, "0.5", FieldValue::Float(Some(0.5))),
+            (
+                FieldType::Logical,
+                "false",
+                FieldValue::Logical(Some(false)),
+            ),
+            (FieldType::Integer, "-3", FieldValue::Integer(-3)),
+            (FieldType::Double, "2.5", FieldValue::Double(2.5)),
+            (
+                FieldType::Currency,
+                "12.3456",
+                FieldValue::Currency(CurrencyValue::new(123_456)),
+            ),
+            (
+                FieldType::Date,
+                r#""2024-01-31""#,
+                FieldValue::Date(Some(Date::new(31, 1, 2024))),
+            ),
+            (FieldType::Date, "null", FieldValue::Date(None)),
+            (
+                FieldType::DateTime,
+                r#""2024-01-31T08:05:00""#,
+                FieldValue::DateTime(DateTime::new(Date::new(31, 1, 2024), Time::new(8, 5, 0))),
+            ),
+            (
+                FieldType::Memo,
+                r#""A note""#,
+                FieldValue::Memo("A note".to_string()),
+            ),
+            (
+                FieldType::Memo,
+                "[1, 2, 255]",
+                FieldValue::BinaryMemo(vec![1, 2, 255]),
+            ),
+        ];
+        for (field_type, json, value) in expected {
+            let mut deserializer = serde_json::Deserializer::from_str(json);
+            assert_eq!(
+                FieldValueSeed::new(field_type)
+                    .deserialize(&mut deserializer)
+                    .unwrap(),
+                value,
+                "{}",
+                json
+            );
+            // Values are read back from their own JSON
+            let json = serde_json::to_string(&value).unwrap();
+            let mut deserializer = serde_json::Deserializer::from_str(&json);
+            assert_eq!(
+                FieldValueSeed::new(field_type)
+                    .deserialize(&mut deserializer)
+                    .unwrap(),
+                value,
+                "{}",
+                json
+            );
+        }
+
+        let mut deserializer = serde_json::Deserializer::from_str(r#""12""#);
+        assert!(FieldValueSeed::new(FieldType::Integer)
+            .deserialize(&mut deserializer)
+            .is_err());
+    }
+
+    #[test]
+    fn test_serde_dates_as_json() {
+        use dbase::{Date, DateTime, Time};
+
+        let date = Date::new(20, 7, 2019);
+        let json = serde_json::to_string(&date).unwrap();
+        assert_eq!(json, r#""2019-07-20""#);
+        assert_eq!(serde_json::from_str::<Date>(&json).unwrap(), date);
+
+        let date_time = DateTime::new(date, Time::new(12, 34, 56));
+        let json = serde_json::to_string(&date_time).unwrap();
+        assert_eq!(json, r#""2019-07-20T12:34:56""#);
+        assert_eq!(serde_json::from_str::<DateTime>(&json).unwrap(), date_time);
+
+        let midnight = DateTime::new(date, Time::new(0, 0, 0));
+        assert_eq!(
+            serde_json::from_str::<DateTime>(r#""2019-07-20""#).unwrap(),
+            midnight
+        );
+        assert_eq!(
+            serde_json::from_str::<DateTime>(&serde_json::to_string(&midnight).unwrap()).unwrap(),
+            midnight
+        );
+
+        assert!(serde_json::from_str::<Date>(r#""2019-02-30""#).is_err());
+        assert!(serde_json::from_str::<DateTime>(r#""2019-07-20T25:00:00""#).is_err());
+    }
+
+    #[test]
+    fn test_serde_dates_in_dbase_and_json() {
+        use dbase::{Date, DateTime, Time};
+
+        #[derive(Serialize, Deserialize, PartialEq, Debug)]
+        struct Event {
+            day: Date,
+            at: DateTime,
+        }
+
+        let records = vec![Event {
+            day: Date::new(20, 7, 2019),
+            at: DateTime::new(Date::new(21, 7, 2019), Time::new(8, 30, 0)),
+        }];
+
+        let json = serde_json::to_string(&records).unwrap();
+        assert_eq!(json, r#"[{"day":"2019-07-20","at":"2019-07-21T08:30:00"}]"#);
+        assert_eq!(serde_json::from_str::<Vec<Event>>(&json).unwrap(), records);
+
+        let writer_builder = TableWriterBuilder::new()
+            .add_date_field(FieldName::try_from("day").unwrap())
+            .add_datetime_field(FieldName::try_from("at").unwrap());
+        write_read_compare(&records, writer_builder);
+    }
+
+    #[test]
+    fn test_serde_mismatched_records_are_not_written() {
+        #[derive(Serialize, Deserialize, PartialEq, Debug)]
+        struct Complete {
+            name: String,
+            age: f64,
+        }
+
+        #[derive(Serialize)]
+        struct Missing {
+            name: String,
+        }
+
+        #[derive(Serialize)]
+        struct Extra {
+            name: String,
+            age: f64,
+            height: f64,
+        }
+
+        let mut writer = TableWriterBuilder::new()
+            .add_character_field(FieldName::try_from("name").unwrap(), 10)
+            .add_numeric_field(FieldName::try_from("age").unwrap(), 5, 0)
+            .build_with_dest(Cursor::new(Vec::<u8>::new()));
+
+        let records = vec![
+            Complete {
+                name: "Yoshi".to_string(),
+                age: 32.0,
+            },
+            Complete {
+                name: "Mario".to_string(),
+                age: 35.0,
+            },
+        ];
+        writer.write_record(&records[0]).unwrap();
+
+        let error = writer
+            .write_record(&Missing {
+                name: "Luigi".to_string(),
+            })
+            .unwrap_err();
+        assert!(matches!(error.kind(), ErrorKind::NotEnoughFields));
+        assert_eq!(
+            error.field().as_ref().map(|field| field.name()),
+            Some("age")
+        );
+
+        let error = writer
+            .write_record(&Extra {
+                name: "Peach".to_string(),
+                age: 24.0,
+                height: 1.7,
+            })
+            .unwrap_err();
+        assert!(matches!(error.kind(), ErrorKind::TooManyFields));
+
+        writer.write_record(&records[1]).unwrap();
+        let mut dst = writer.finish().unwrap();
+        dst.set_position(0);
+        let read_records = Reader::new(dst).unwrap().read_as::<Complete>().unwrap();
+        assert_eq!(read_records, records);
+    }
 }
@@ -0,0 +1,47 @@
+#[cfg(feature = "test-util")]
+mod testing_tests {
+    use std::convert::TryFrom;
+
+    use dbase::testing::round_trip;
+    use dbase::{Date, FieldInfo, FieldName, FieldType, FieldValue};
+
+    fn field(field_type: FieldType, length: u8) -> FieldInfo {
+        FieldInfo::new(FieldName::try_from("value").unwrap(), field_type, length).unwrap()
+    }
+
+    #[test]
+    fn round_trip_values() {
+        assert_eq!(
+            round_trip(&field(FieldType::Character, 10), &"Yoshi"),
+            FieldValue::Character(Some("Yoshi".to_string()))
+        );
+        assert_eq!(
+            round_trip(&field(FieldType::Integer, 4), &-42i64),
+            FieldValue::Integer(-42)
+        );
+        assert_eq!(
+            round_trip(&field(FieldType::Logical, 1), &Some(true)),
+            FieldValue::Logical(Some(true))
+        );
+        let date = Date::new(31, 1, 2024);
+        assert_eq!(
+            round_trip(&field(FieldType::Date, 8), &date),
+            FieldValue::Date(Some(date))
+        );
+    }
+
+    #[test]
+    fn round_trip_keeps_the_decimals_of_the_field() {
+        let info = field(FieldType::Numeric, 10).with_decimals(2).unwrap();
+        assert_eq!(
+            round_trip(&info, &19.987f64),
+            FieldValue::Numeric(Some(19.99))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Failed to write the value")]
+    fn round_trip_panics_on_incompatible_value() {
+        round_trip(&field(FieldType::Logical, 1), &"Yoshi");
+    }
+}
@@ -4,8 +4,9 @@ extern crate dbase;
 use std::io::{Cursor, Read, Seek, Write};
 
 use dbase::{
-    Date, DateTime, FieldIOError, FieldIterator, FieldName, FieldValue, FieldWriter,
-    ReadableRecord, Reader, Record, TableWriterBuilder, Time, WritableRecord,
+    Date, DateTime, EmptyDateStyle, FieldIOError, FieldIterator, FieldName, FieldType, FieldValue,
+    FieldWriter, ReadWarning, ReadWarningKind, ReadableRecord, Reader, Record, TableWriterBuilder,
+    Time, WritableRecord,
 };
 use std::convert::{TryFrom, TryInto};
 use std::fmt::Debug;
@@ -13,6 +14,7 @@ use std::fmt::Debug;
 const LINE_DBF: &str = "./tests/data/line.dbf";
 const NONE_FLOAT_DBF: &str = "./tests/data/contain_none_float.dbf";
 const NULL_PADDED_NUMERIC_DBF: &str = "./tests/data/contain_null_padded_numeric.dbf";
+const AUTOINCREMENT_DBF: &str = "./tests/data/autoincrement.dbf";
 
 fn write_read_compare<R: WritableRecord + ReadableRecord + Debug + PartialEq>(
     records: &Vec<R>,
@@ -174,13 +176,13 @@ fn from_scratch_fox_pro_record() {
     let mut record = Record::default();
     record.insert(String::from("integer"), FieldValue::Integer(17));
     record.insert(String::from("double"), FieldValue::Double(54621.154));
-    record.insert(String::from("currency"), FieldValue::Currency(4567.134));
+    record.insert(
+        String::from("currency"),
+        FieldValue::Currency(dbase::CurrencyValue::from_f64(4567.134)),
+    );
     record.insert(
         String::from("datetime"),
-        FieldValue::DateTime(DateTime::new(
-            Date::new(01, 06, 2006),
-            Time::new(12, 50, 20),
-        )),
+        FieldValue::DateTime(DateTime::new(Date::new(1, 6, 2006), Time::new(12, 50, 20))),
     );
 
     let records = vec![record];
@@ -206,15 +208,45 @@ fn from_scratch_fox_pro_struct_record() {
         .add_integer_field(FieldName::try_from("integer").unwrap());
 
     let records = vec![FoxProRecord {
-        datetime: DateTime::new(Date::new(12, 02, 1999), Time::new(21, 20, 35)),
+        datetime: DateTime::new(Date::new(12, 2, 1999), Time::new(21, 20, 35)),
         double: 8649.48851,
-        currency: 3489.9612314,
+        currency: 3489.9612,
         integer: 42069,
     }];
 
     write_read_compare(&records, writer_builder);
 }
 
+dbase_record! {
+    #[derive(Clone, Debug, PartialEq)]
+    struct TimeCrateRecord {
+        day: time::Date,
+        at: time::PrimitiveDateTime
+    }
+}
+
+#[test]
+fn from_scratch_time_crate_struct_record() {
+    let writer_builder = TableWriterBuilder::new()
+        .add_date_field(FieldName::try_from("day").unwrap())
+        .add_datetime_field(FieldName::try_from("at").unwrap());
+
+    let leap_day = time::Date::from_calendar_date(2024, time::Month::February, 29).unwrap();
+    let epoch = time::Date::from_calendar_date(1899, time::Month::December, 30).unwrap();
+    let records = vec![
+        TimeCrateRecord {
+            day: leap_day,
+            at: leap_day.with_hms(23, 59, 59).unwrap(),
+        },
+        TimeCrateRecord {
+            day: epoch,
+            at: epoch.midnight(),
+        },
+    ];
+
+    write_read_compare(&records, writer_builder);
+}
+
 dbase_record! {
     #[derive(Clone, Debug, PartialEq)]
     struct User {
@@ -250,8 +282,8 @@ fn the_classical_user_record_example() {
 
     let mut cursor = Cursor::new(Vec::<u8>::new());
     let writer = TableWriterBuilder::new()
-        .add_character_field("First Name".try_into().unwrap(), 50)
-        .add_character_field("Last Name".try_into().unwrap(), 50)
+        .add_character_field("First_Name".try_into().unwrap(), 50)
+        .add_character_field("Last_Name".try_into().unwrap(), 50)
         .build_with_dest(&mut cursor);
 
     writer.write_records(&users).unwrap();
@@ -262,3 +294,3474 @@ fn the_classical_user_record_example() {
 
     assert_eq!(read_records, users);
 }
+
+#[test]
+fn test_dbase_schema_macro() {
+    let builder = dbase_schema! {
+        encoding: "utf-8",
+        fields: {
+            NAME: Character(50),
+            POPULATION: Numeric(10, 0),
+            FOUNDED: Date,
+            ACTIVE: Logical,
+            SCORE: Float(8, 3),
+        }
+    };
+
+    let mut dst = Cursor::new(Vec::<u8>::new());
+    let mut record = Record::default();
+    record.insert(
+        "NAME".to_owned(),
+        FieldValue::Character(Some("Paris".to_owned())),
+    );
+    record.insert(
+        "POPULATION".to_owned(),
+        FieldValue::Numeric(Some(2161000.0)),
+    );
+    record.insert(
+        "FOUNDED".to_owned(),
+        FieldValue::Date(Some(Date::new(1, 1, 1900))),
+    );
+    record.insert("ACTIVE".to_owned(), FieldValue::Logical(Some(true)));
+    record.insert("SCORE".to_owned(), FieldValue::Float(Some(9.5)));
+    builder
+        .build_with_dest(&mut dst)
+        .write_records(&vec![record.clone()])
+        .unwrap();
+    dst.set_position(0);
+
+    let mut reader = Reader::new(dst).unwrap();
+    let names: Vec<&str> = reader.fields().iter().skip(1).map(|f| f.name()).collect();
+    assert_eq!(
+        names,
+        vec!["NAME", "POPULATION", "FOUNDED", "ACTIVE", "SCORE"]
+    );
+    assert_eq!(reader.fields()[1].length(), 50);
+    assert_eq!(reader.read().unwrap(), vec![record]);
+}
+
+#[test]
+fn test_dbase_schema_macro_fox_pro_types_without_encoding() {
+    let builder = dbase_schema! {
+        fields: {
+            ID: Integer,
+            AMOUNT: Double,
+            PRICE: Currency,
+            UPDATED: DateTime
+        }
+    };
+    let mut dst = Cursor::new(Vec::<u8>::new());
+    builder
+        .build_with_dest(&mut dst)
+        .write_records(&Vec::<Record>::new())
+        .unwrap();
+    dst.set_position(0);
+
+    let reader = Reader::new(dst).unwrap();
+    let types: Vec<FieldType> = reader
+        .fields()
+        .iter()
+        .skip(1)
+        .map(|f| f.field_type())
+        .collect();
+    assert_eq!(
+        types,
+        vec![
+            FieldType::Integer,
+            FieldType::Double,
+            FieldType::Currency,
+            FieldType::DateTime
+        ]
+    );
+}
+
+#[test]
+fn test_read_character_like_numerics() {
+    let values = [
+        "1234567890123456789",
+        "+000000000000000042",
+        "-999999999999999999",
+    ];
+    let mut dst = Cursor::new(Vec::<u8>::new());
+    let writer = TableWriterBuilder::new()
+        .add_character_field("id".try_into().unwrap(), 19)
+        .build_with_dest(&mut dst);
+    let records = values
+        .iter()
+        .map(|value| {
+            let mut record = Record::default();
+            record.insert(
+                "id".to_owned(),
+                FieldValue::Character(Some(value.to_string())),
+            );
+            record
+        })
+        .collect::<Vec<_>>();
+    writer.write_records(&records).unwrap();
+
+    // Turn the Character field into a Numeric one with 0 decimal places,
+    // the field type is the 12th byte of the first field descriptor
+    let mut bytes = dst.into_inner();
+    assert_eq!(bytes[32 + 11], b'C');
+    bytes[32 + 11] = b'N';
+
+    let mut reader = Reader::new(Cursor::new(bytes.clone())).unwrap();
+    assert_eq!(reader.fields()[1].field_type(), FieldType::Numeric);
+    let parsed = reader.read().unwrap();
+    assert_eq!(
+        parsed[0].get("id"),
+        Some(&FieldValue::Numeric(Some(1234567890123456789.0)))
+    );
+
+    let mut reader = Reader::new(Cursor::new(bytes)).unwrap();
+    reader.set_options(dbase::ReadingOptions::new().character_like_numerics(true));
+    let raw = reader.read().unwrap();
+    for (record, value) in raw.iter().zip(values.iter()) {
+        assert_eq!(
+            record.get("id"),
+            Some(&FieldValue::Character(Some(value.to_string())))
+        );
+    }
+}
+
+#[test]
+fn test_lenient_mode_on_unparseable_numeric() {
+    let mut dst = Cursor::new(Vec::<u8>::new());
+    let writer = TableWriterBuilder::new()
+        .add_character_field("value".try_into().unwrap(), 8)
+        .build_with_dest(&mut dst);
+    let records = ["12.5", "1x3", "-4"]
+        .iter()
+        .map(|value| {
+            let mut record = Record::default();
+            record.insert(
+                "value".to_owned(),
+                FieldValue::Character(Some(value.to_string())),
+            );
+            record
+        })
+        .collect::<Vec<_>>();
+    writer.write_records(&records).unwrap();
+
+    // Turn the Character field into a Numeric one
+    let mut bytes = dst.into_inner();
+    bytes[32 + 11] = b'N';
+
+    let mut reader = Reader::new(Cursor::new(bytes.clone())).unwrap();
+    let error = reader.read().unwrap_err();
+    assert_eq!(error.record_num(), 1);
+    assert!(matches!(error.kind(), dbase::ErrorKind::ParseFloatError(_)));
+
+    let mut reader = Reader::new(Cursor::new(bytes)).unwrap();
+    reader.set_lenient(true);
+    let read_records = reader.read().unwrap();
+    let values = read_records
+        .iter()
+        .map(|record| record.get("value").cloned())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        values,
+        vec![
+            Some(FieldValue::Numeric(Some(12.5))),
+            Some(FieldValue::Numeric(None)),
+            Some(FieldValue::Numeric(Some(-4.0))),
+        ]
+    );
+
+    let warnings = reader.take_warnings();
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].record_num(), 1);
+    assert_eq!(warnings[0].field().map(|info| info.name()), Some("value"));
+    assert_eq!(warnings[0].raw_bytes(), b"1x3     ");
+    assert_eq!(warnings[0].trimmed_bytes(), b"1x3");
+    assert!(reader.warnings().is_empty());
+}
+
+#[test]
+fn test_next_borrowed_matches_records() {
+    let mut reader = Reader::from_path("tests/data/stations.dbf").unwrap();
+    let records = reader.read().unwrap();
+
+    let mut reader = Reader::from_path("tests/data/stations.dbf").unwrap();
+    let field_names = reader
+        .fields()
+        .iter()
+        .skip(1)
+        .map(|info| info.name().to_owned())
+        .collect::<Vec<_>>();
+    let mut iter = reader.iter_records();
+    let mut num_views = 0;
+    while let Some(view) = iter.next_borrowed() {
+        let view = view.unwrap();
+        let record = &records[num_views];
+        assert_eq!(view.len(), field_names.len());
+        let names = view.iter().map(|(name, _)| name).collect::<Vec<_>>();
+        assert_eq!(names, field_names);
+        for (name, value) in view {
+            assert_eq!(record.get(name), Some(value));
+        }
+        num_views += 1;
+    }
+    assert_eq!(num_views, records.len());
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn test_read_autoincrement_fixture() {
+    let mut reader = Reader::from_path(AUTOINCREMENT_DBF).unwrap();
+    let id_info = &reader.fields()[1];
+    assert_eq!(id_info.name(), "ID");
+    assert_eq!(id_info.field_type(), FieldType::AutoIncrement);
+    assert_eq!(id_info.autoincrement_next_val(), 4);
+    assert_eq!(id_info.autoincrement_step(), 1);
+
+    let records = reader.read().unwrap();
+    assert_eq!(records.len(), 3);
+    for (i, (record, name)) in records.iter().zip(["alpha", "beta", "gamma"]).enumerate() {
+        assert_eq!(
+            record.get("ID"),
+            Some(&FieldValue::AutoIncrement(i as i32 + 1))
+        );
+        assert_eq!(
+            record.get("NAME"),
+            Some(&FieldValue::Character(Some(name.to_owned())))
+        );
+    }
+}
+
+dbase_record! {
+    #[derive(Clone, Debug, PartialEq)]
+    struct Ticket {
+        id: i32,
+        title: String,
+    }
+}
+
+#[test]
+fn test_write_autoincrement_field() {
+    let tickets = vec![
+        Ticket {
+            id: 1,
+            title: "first".to_owned(),
+        },
+        Ticket {
+            id: 2,
+            title: "second".to_owned(),
+        },
+    ];
+    let mut dst = Cursor::new(Vec::<u8>::new());
+    let writer = TableWriterBuilder::new()
+        .add_autoincrement_field("id".try_into().unwrap())
+        .add_character_field("title".try_into().unwrap(), 20)
+        .build_with_dest(&mut dst);
+    writer.write_records(&tickets).unwrap();
+    dst.set_position(0);
+
+    let mut reader = Reader::new(dst).unwrap();
+    assert_eq!(reader.fields()[1].field_type(), FieldType::AutoIncrement);
+    assert_eq!(reader.fields()[1].autoincrement_next_val(), 3);
+    assert_eq!(reader.fields()[1].autoincrement_step(), 1);
+    assert_eq!(reader.read_as::<Ticket>().unwrap(), tickets);
+}
+
+#[test]
+fn test_read_modify_write_records() {
+    let mut reader = Reader::from_path("tests/data/stations.dbf").unwrap();
+    let mut stations = reader.read().unwrap();
+    stations[0].insert(
+        "name".to_owned(),
+        FieldValue::Character(Some("Montparnasse".to_owned())),
+    );
+
+    let mut dst = Cursor::new(Vec::<u8>::new());
+    let writer = TableWriterBuilder::from_reader(reader).build_with_dest(&mut dst);
+    writer.write_records(&stations).unwrap();
+    dst.set_position(0);
+
+    let read_stations = Reader::new(dst).unwrap().read().unwrap();
+    assert_eq!(read_stations, stations);
+
+    {
+        let maps = stations
+            .iter()
+            .cloned()
+            .map(std::collections::HashMap::from)
+            .collect::<Vec<_>>();
+        let reader = Reader::from_path("tests/data/stations.dbf").unwrap();
+        let mut dst = Cursor::new(Vec::<u8>::new());
+        let writer = TableWriterBuilder::from_reader(reader).build_with_dest(&mut dst);
+        writer.write_records(&maps).unwrap();
+        dst.set_position(0);
+        assert_eq!(Reader::new(dst).unwrap().read().unwrap(), stations);
+    }
+}
+
+#[test]
+fn test_write_record_with_missing_field() {
+    let mut record = Record::default();
+    record.insert(
+        "first".to_owned(),
+        FieldValue::Character(Some("value".to_owned())),
+    );
+
+    let mut writer = TableWriterBuilder::new()
+        .add_character_field("first".try_into().unwrap(), 10)
+        .add_character_field("second".try_into().unwrap(), 10)
+        .build_with_dest(Cursor::new(Vec::<u8>::new()));
+    let error = writer.write_record(&record).unwrap_err();
+    assert_eq!(
+        error.field().as_ref().map(|info| info.name()),
+        Some("second")
+    );
+    match error.kind() {
+        dbase::ErrorKind::Message(message) => assert!(message.contains("'second'")),
+        _ => panic!("unexpected error kind: {:?}", error.kind()),
+    }
+
+    let map = std::collections::HashMap::from(record);
+    let mut writer = TableWriterBuilder::new()
+        .add_character_field("first".try_into().unwrap(), 10)
+        .add_character_field("second".try_into().unwrap(), 10)
+        .build_with_dest(Cursor::new(Vec::<u8>::new()));
+    let error = writer.write_record(&map).unwrap_err();
+    assert_eq!(
+        error.field().as_ref().map(|info| info.name()),
+        Some("second")
+    );
+    match error.kind() {
+        dbase::ErrorKind::Message(message) => assert!(message.contains("'second'")),
+        _ => panic!("unexpected error kind: {:?}", error.kind()),
+    }
+}
+
+#[test]
+fn test_table_diff() {
+    let path_a = std::path::Path::new("tests/data/stations.dbf");
+    let path_b = std::path::Path::new("table_diff_stations.dbf");
+    let mut reader = Reader::from_path(path_a).unwrap();
+    let mut records = reader.read().unwrap();
+    let old_name = records[2].get("name").cloned().unwrap();
+    records[2].insert("name".to_string(), "Renamed".into());
+    records.push(records[0].clone());
+    TableWriterBuilder::from_table_info(reader.into_table_info())
+        .build_with_file_dest(path_b)
+        .unwrap()
+        .write_records(&records)
+        .unwrap();
+
+    let diff = dbase::TableDiff::compute(path_a, path_b).unwrap();
+    std::fs::remove_file(path_b).unwrap();
+    assert!(!diff.is_empty());
+    assert!(diff.schema_diff.is_empty());
+    assert_eq!(diff.record_count_diff, 1);
+    assert_eq!(
+        diff.changed_records,
+        vec![(
+            2,
+            vec![dbase::FieldDiff {
+                field_name: "name".to_string(),
+                old: old_name.clone(),
+                new: "Renamed".into(),
+            }]
+        )]
+    );
+    assert_eq!(diff.added_records, vec![(6, records[0].clone())]);
+    assert!(diff.removed_records.is_empty());
+    assert_eq!(
+        diff.to_string(),
+        format!(
+            "Record count: +1\nRecord 2 changed: name: {} -> Character(Some(\"Renamed\"))\n\
+             Record 6 added\n",
+            old_name
+        )
+    );
+
+    std::fs::copy(path_a, path_b).unwrap();
+    let mut table = dbase::Table::open(path_b).unwrap();
+    let removed = table.remove_field("marker-sym").unwrap();
+    let added =
+        dbase::FieldInfo::new("visited".try_into().unwrap(), FieldType::Logical, 1).unwrap();
+    table
+        .add_field(added.clone(), FieldValue::Logical(Some(false)))
+        .unwrap();
+    table.save().unwrap();
+    let diff = dbase::TableDiff::compute(path_a, path_b).unwrap();
+    std::fs::remove_file(path_b).unwrap();
+    assert_eq!(diff.schema_diff.added, vec![added]);
+    assert_eq!(diff.schema_diff.removed, vec![removed]);
+    assert!(diff.schema_diff.changed.is_empty());
+    // The values of fields that are only in one of the tables are not compared
+    assert!(diff.changed_records.is_empty());
+    assert_eq!(
+        diff.to_string(),
+        "Field added: visited (Logical, length 1)\n\
+         Field removed: marker-sym (Character, length 254)\n"
+    );
+}
+
+#[test]
+fn test_diff_and_apply_patch() {
+    let old = dbase::read("tests/data/diff_old.dbf").unwrap();
+    let new = dbase::read("tests/data/diff_new.dbf").unwrap();
+    let name_of = |record: &Record| match record.get("NAME") {
+        Some(FieldValue::Character(Some(name))) => name.clone(),
+        value => panic!("unexpected name value: {:?}", value),
+    };
+
+    let diff = dbase::diff(&old, &new, "ID");
+    assert_eq!(
+        diff.added.iter().map(name_of).collect::<Vec<_>>(),
+        vec!["elderberry"]
+    );
+    assert_eq!(
+        diff.removed.iter().map(name_of).collect::<Vec<_>>(),
+        vec!["cherry"]
+    );
+    assert_eq!(
+        diff.modified
+            .iter()
+            .map(|(old, new)| (name_of(old), name_of(new)))
+            .collect::<Vec<_>>(),
+        vec![
+            ("banana".to_owned(), "banana".to_owned()),
+            ("date".to_owned(), "dates".to_owned())
+        ]
+    );
+    assert_eq!(
+        diff.modified[0].1.get("PRICE"),
+        Some(&FieldValue::Numeric(Some(0.3)))
+    );
+
+    assert_eq!(dbase::apply_patch(old.clone(), &diff, "ID"), new);
+    assert!(dbase::diff(&new, &new, "ID").is_empty());
+    assert_eq!(
+        dbase::apply_patch(old.clone(), &dbase::Diff::default(), "ID"),
+        old
+    );
+}
+
+#[derive(Debug, PartialEq, Clone)]
+struct Counts {
+    small: u8,
+    medium: i16,
+    large: i64,
+    unsigned: u32,
+}
+
+impl ReadableRecord for Counts {
+    fn read_using<T>(field_iterator: &mut FieldIterator<T>) -> Result<Self, FieldIOError>
+    where
+        T: Read + Seek,
+    {
+        Ok(Self {
+            small: field_iterator.read_next_field_as()?.value,
+            medium: field_iterator.read_next_field_as()?.value,
+            large: field_iterator.read_next_field_as()?.value,
+            unsigned: field_iterator.read_next_field_as()?.value,
+        })
+    }
+}
+
+impl WritableRecord for Counts {
+    fn write_using<'a, W: Write>(
+        &self,
+        field_writer: &mut FieldWriter<'a, W>,
+    ) -> Result<(), FieldIOError> {
+        field_writer.write_next_field_value(&self.small)?;
+        field_writer.write_next_field_value(&self.medium)?;
+        field_writer.write_next_field_value(&self.large)?;
+        field_writer.write_next_field_value(&self.unsigned)?;
+        Ok(())
+    }
+}
+
+fn counts_writer_builder() -> TableWriterBuilder {
+    TableWriterBuilder::new()
+        .add_integer_field("small".try_into().unwrap())
+        .add_integer_field("medium".try_into().unwrap())
+        .add_numeric_field("large".try_into().unwrap(), 20, 0)
+        .add_integer_field("unsigned".try_into().unwrap())
+}
+
+#[test]
+fn test_write_read_other_integer_widths() {
+    let records = vec![
+        Counts {
+            small: 255,
+            medium: -32768,
+            large: 9_007_199_254_740_992,
+            unsigned: i32::MAX as u32,
+        },
+        Counts {
+            small: 0,
+            medium: 12,
+            large: -42,
+            unsigned: 0,
+        },
+    ];
+    write_read_compare(&records, counts_writer_builder());
+}
+
+#[test]
+fn test_write_integer_overflow() {
+    let record = Counts {
+        small: 1,
+        medium: 1,
+        large: 1,
+        unsigned: u32::MAX,
+    };
+    let mut writer = counts_writer_builder().build_with_dest(Cursor::new(Vec::<u8>::new()));
+    let error = writer.write_record(&record).unwrap_err();
+    assert!(matches!(
+        error.kind(),
+        dbase::ErrorKind::BadConversion(dbase::FieldConversionError::IntegerOverflow)
+    ));
+    assert_eq!(
+        error.field().as_ref().map(|info| info.name()),
+        Some("unsigned")
+    );
+}
+
+dbase_record! {
+    #[derive(Debug, PartialEq, Clone)]
+    struct Sizes {
+        count: usize,
+        offset: isize,
+    }
+}
+
+fn sizes_writer_builder() -> TableWriterBuilder {
+    TableWriterBuilder::new()
+        .add_integer_field("count".try_into().unwrap())
+        .add_integer_field("offset".try_into().unwrap())
+}
+
+#[test]
+fn test_write_read_pointer_sized_integers() {
+    let records = vec![
+        Sizes {
+            count: i32::MAX as usize,
+            offset: i32::MIN as isize,
+        },
+        Sizes {
+            count: 0,
+            offset: 17,
+        },
+    ];
+    write_read_compare(&records, sizes_writer_builder());
+
+    let record = Sizes {
+        count: usize::MAX,
+        offset: 0,
+    };
+    let mut writer = sizes_writer_builder().build_with_dest(Cursor::new(Vec::<u8>::new()));
+    let error = writer.write_record(&record).unwrap_err();
+    assert!(matches!(
+        error.kind(),
+        dbase::ErrorKind::BadConversion(dbase::FieldConversionError::IntegerOverflow)
+    ));
+    assert_eq!(
+        error.field().as_ref().map(|info| info.name()),
+        Some("count")
+    );
+
+    assert!(matches!(
+        usize::try_from(FieldValue::Integer(-1)),
+        Err(dbase::FieldConversionError::IntegerOverflow)
+    ));
+    assert_eq!(
+        isize::try_from(FieldValue::Numeric(Some(-3.0))).unwrap(),
+        -3
+    );
+}
+
+#[test]
+fn test_read_numeric_as_integer() {
+    assert_eq!(i64::try_from(FieldValue::Numeric(Some(-3.0))).unwrap(), -3);
+    assert!(matches!(
+        i64::try_from(FieldValue::Numeric(Some(1.5))),
+        Err(dbase::FieldConversionError::FractionalPart)
+    ));
+    assert!(matches!(
+        u8::try_from(FieldValue::Numeric(Some(256.0))),
+        Err(dbase::FieldConversionError::IntegerOverflow)
+    ));
+    assert!(matches!(
+        u8::try_from(FieldValue::Integer(-1)),
+        Err(dbase::FieldConversionError::IntegerOverflow)
+    ));
+    assert!(matches!(
+        i64::try_from(FieldValue::Numeric(None)),
+        Err(dbase::FieldConversionError::NoneValue)
+    ));
+
+    let mut dst = Cursor::new(Vec::<u8>::new());
+    let mut writer = TableWriterBuilder::new()
+        .add_numeric_field("value".try_into().unwrap(), 10, 2)
+        .build_with_dest(&mut dst);
+    let mut record = Record::default();
+    record.insert("value".to_owned(), FieldValue::Numeric(Some(2.5)));
+    writer.write_record(&record).unwrap();
+    drop(writer);
+    dst.set_position(0);
+
+    let mut reader = Reader::new(dst).unwrap();
+    let mut records = reader.iter_records_as::<Counts>();
+    let error = records.next().unwrap().unwrap_err();
+    assert!(matches!(
+        error.kind(),
+        dbase::ErrorKind::BadConversion(dbase::FieldConversionError::FractionalPart)
+    ));
+}
+
+#[test]
+fn test_split_and_slice() {
+    let mut reader = Reader::from_path("tests/data/stations.dbf").unwrap();
+    let records = reader.read().unwrap();
+
+    for n in 1..=8 {
+        let ranges = reader.split(n).unwrap();
+        assert_eq!(ranges.len(), n.min(records.len()));
+        assert_eq!(ranges.first().unwrap().start, 0);
+        assert_eq!(ranges.last().unwrap().end, records.len());
+        assert!(ranges.windows(2).all(|w| w[0].end == w[1].start));
+
+        let handles = ranges
+            .into_iter()
+            .map(|range| {
+                let records = reader.slice::<Record>(range).unwrap();
+                std::thread::spawn(move || records.collect::<Result<Vec<_>, _>>().unwrap())
+            })
+            .collect::<Vec<_>>();
+        let sliced = handles
+            .into_iter()
+            .flat_map(|handle| handle.join().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(sliced, records);
+    }
+
+    assert!(reader.split(0).is_err());
+    assert!(reader
+        .slice::<Record>(dbase::RecordRange { start: 2, end: 7 })
+        .is_err());
+
+    let file = std::fs::File::open("tests/data/stations.dbf").unwrap();
+    let reader = Reader::new(std::io::BufReader::new(file)).unwrap();
+    assert!(reader
+        .slice::<Record>(dbase::RecordRange { start: 0, end: 1 })
+        .is_err());
+}
+
+#[test]
+fn test_logical_style() {
+    use dbase::LogicalStyle;
+
+    // None is the default style
+    let expected = [
+        (None, b"TF?"),
+        (Some(LogicalStyle::TrueFalseUpper), b"TF?"),
+        (Some(LogicalStyle::TrueFalseLower), b"tf?"),
+        (Some(LogicalStyle::YesNo), b"YN?"),
+    ];
+    for (style, chars) in expected {
+        let records = [Some(true), Some(false), None]
+            .iter()
+            .map(|value| {
+                let mut record = Record::default();
+                record.insert("flag".to_owned(), FieldValue::Logical(*value));
+                record
+            })
+            .collect::<Vec<_>>();
+        let mut dst = Cursor::new(Vec::<u8>::new());
+        let mut builder = TableWriterBuilder::new().add_logical_field("flag".try_into().unwrap());
+        if let Some(style) = style {
+            builder = builder.logical_style(style);
+        }
+        let writer = builder.build_with_dest(&mut dst);
+        writer.write_records(&records).unwrap();
+
+        let bytes = dst.get_ref();
+        let first_record = 32 + 32 + 1;
+        // each record is the deletion flag followed by the logical value
+        let written = (0..3)
+            .map(|i| bytes[first_record + i * 2 + 1])
+            .collect::<Vec<u8>>();
+        assert_eq!(&written, chars);
+
+        dst.set_position(0);
+        assert_eq!(Reader::new(dst).unwrap().read().unwrap(), records);
+    }
+}
+
+#[test]
+fn test_record_positional_access() {
+    let mut reader = Reader::from_path("./tests/data/stations.dbf").unwrap();
+    let field_names = reader
+        .fields()
+        .iter()
+        .map(|info| info.name().to_owned())
+        .filter(|name| name != "DeletionFlag")
+        .collect::<Vec<String>>();
+    let mut record = reader.read().unwrap().remove(0);
+
+    assert_eq!(record.len(), field_names.len());
+    let names = record.iter().map(|(name, _)| name).collect::<Vec<&str>>();
+    assert_eq!(names, field_names);
+    for (i, (name, value)) in record.iter().enumerate() {
+        assert_eq!(record.get_at(i), Some((name, value)));
+        assert_eq!(record.get(name), Some(value));
+    }
+    assert_eq!(record.get_at(field_names.len()), None);
+
+    let (name, value) = record.get_at_mut(0).unwrap();
+    let name = name.to_owned();
+    *value = FieldValue::Character(None);
+    assert_eq!(record.get(&name), Some(&FieldValue::Character(None)));
+}
+
+#[derive(Debug)]
+struct StationByName {
+    line: String,
+    name: String,
+    marker_col: String,
+}
+
+impl ReadableRecord for StationByName {
+    fn read_using<T>(field_iterator: &mut FieldIterator<T>) -> Result<Self, FieldIOError>
+    where
+        T: Read + Seek,
+    {
+        let line = field_iterator.read_field_by_name("line")?.value;
+        let name = field_iterator.read_field_by_name("name")?.value;
+        // reading continues after the "name" field
+        let marker_col = field_iterator.read_next_field_as()?.value;
+        Ok(Self {
+            line,
+            name,
+            marker_col,
+        })
+    }
+}
+
+#[test]
+fn test_read_fields_by_name() {
+    let records = dbase::read("tests/data/stations.dbf").unwrap();
+    let stations = Reader::from_path("tests/data/stations.dbf")
+        .unwrap()
+        .read_as::<StationByName>()
+        .unwrap();
+    assert_eq!(stations.len(), records.len());
+    for (station, record) in stations.iter().zip(&records) {
+        assert_eq!(record.get_as::<String>("line").unwrap(), station.line);
+        assert_eq!(record.get_as::<String>("name").unwrap(), station.name);
+        assert_eq!(
+            record.get_as::<String>("marker-col").unwrap(),
+            station.marker_col
+        );
+    }
+
+    assert!(matches!(
+        records[0].get_as::<String>("not-a-field"),
+        Err(dbase::FieldConversionError::MissingField(name)) if name == "not-a-field"
+    ));
+}
+
+#[test]
+fn test_records_iterate_in_the_order_of_the_fields_of_the_file() {
+    for path in ["tests/data/stations.dbf", NULL_FLAGS_DBF, LINE_DBF] {
+        let mut reader = Reader::from_path(path).unwrap();
+        // Skips the deletion flag & the `_NullFlags` column
+        let field_names: Vec<String> = reader
+            .fields()
+            .iter()
+            .skip(1)
+            .filter(|field| field.field_type() != FieldType::NullFlags)
+            .map(|field| field.name().to_string())
+            .collect();
+        for record in reader.read().unwrap() {
+            let names: Vec<&str> = record.iter().map(|(name, _)| name).collect();
+            assert_eq!(names, field_names, "{}", path);
+            let names: Vec<String> = record.into_iter().map(|(name, _)| name).collect();
+            assert_eq!(names, field_names, "{}", path);
+        }
+    }
+}
+
+#[test]
+fn test_editing_a_read_record_does_not_change_the_others() {
+    let mut records = Reader::from_path("tests/data/stations.dbf")
+        .unwrap()
+        .read()
+        .unwrap();
+    let names = records[1]
+        .field_names()
+        .map(str::to_owned)
+        .collect::<Vec<_>>();
+
+    records[0].remove("line");
+    records[0].insert("zone".to_owned(), FieldValue::Numeric(Some(1.0)));
+    assert_eq!(
+        records[0].get("zone"),
+        Some(&FieldValue::Numeric(Some(1.0)))
+    );
+    assert!(!records[0].contains("line"));
+
+    assert_eq!(records[1].field_names().collect::<Vec<_>>(), names);
+    assert!(records[1].get("zone").is_none());
+    assert_eq!(
+        records[1].get("line"),
+        Some(&FieldValue::Character(Some("blue".to_owned())))
+    );
+}
+
+#[test]
+fn test_record_iteration_follows_field_order() {
+    let names = ["zeta", "alpha", "mu", "beta"];
+    let mut record = Record::default();
+    for (i, name) in names.iter().enumerate() {
+        record.insert(name.to_string(), FieldValue::Numeric(Some(i as f64)));
+    }
+    for (_, value) in &mut record {
+        if let FieldValue::Numeric(Some(v)) = value {
+            *v *= 2.0;
+        }
+    }
+    let borrowed = (&record)
+        .into_iter()
+        .map(|(name, _)| name)
+        .collect::<Vec<&str>>();
+    assert_eq!(borrowed, names);
+
+    let writer_builder = names
+        .iter()
+        .fold(TableWriterBuilder::new(), |builder, name| {
+            builder.add_numeric_field(FieldName::try_from(*name).unwrap(), 10, 2)
+        });
+    let mut dst = Cursor::new(Vec::<u8>::new());
+    writer_builder
+        .build_with_dest(&mut dst)
+        .write_records(std::slice::from_ref(&record))
+        .unwrap();
+    dst.set_position(0);
+    let read_record = Reader::new(dst).unwrap().read().unwrap().remove(0);
+    let owned = read_record
+        .into_iter()
+        .collect::<Vec<(String, FieldValue)>>();
+    let expected = names
+        .iter()
+        .enumerate()
+        .map(|(i, name)| (name.to_string(), FieldValue::Numeric(Some(i as f64 * 2.0))))
+        .collect::<Vec<(String, FieldValue)>>();
+    assert_eq!(owned, expected);
+}
+
+#[test]
+fn test_cloned_writer_builder() {
+    let builder =
+        TableWriterBuilder::new().add_character_field(FieldName::try_from("name").unwrap(), 20);
+    let extended = builder
+        .clone()
+        .add_numeric_field(FieldName::try_from("count").unwrap(), 10, 0);
+
+    let paths = [
+        std::env::temp_dir().join("dbase_cloned_builder_a.dbf"),
+        std::env::temp_dir().join("dbase_cloned_builder_b.dbf"),
+    ];
+    let extended_path = std::env::temp_dir().join("dbase_cloned_builder_extended.dbf");
+    let writers = builder.build_with_file_dest_multiple(&paths).unwrap();
+    assert_eq!(writers.len(), 2);
+    let mut record = Record::default();
+    record.insert(
+        "name".to_owned(),
+        FieldValue::Character(Some("a".to_owned())),
+    );
+    for writer in writers {
+        writer.write_records(std::slice::from_ref(&record)).unwrap();
+    }
+    record.insert("count".to_owned(), FieldValue::Numeric(Some(1.0)));
+    extended
+        .build_with_file_dest(&extended_path)
+        .unwrap()
+        .write_records(std::slice::from_ref(&record))
+        .unwrap();
+
+    let field_names = |path: &std::path::Path| {
+        Reader::from_path(path)
+            .unwrap()
+            .fields()
+            .iter()
+            .map(|info| info.name().to_owned())
+            .filter(|name| name != "DeletionFlag")
+            .collect::<Vec<String>>()
+    };
+    assert_eq!(field_names(&paths[0]), ["name"]);
+    assert_eq!(field_names(&paths[1]), ["name"]);
+    assert_eq!(field_names(&extended_path), ["name", "count"]);
+
+    for path in paths.iter().chain(std::iter::once(&extended_path)) {
+        std::fs::remove_file(path).unwrap();
+    }
+}
+
+#[test]
+fn test_finish_returns_complete_file() {
+    let mut writer = TableWriterBuilder::new()
+        .add_character_field(FieldName::try_from("name").unwrap(), 10)
+        .build_with_dest(Cursor::new(Vec::<u8>::new()));
+    let records = ["a", "b", "c"]
+        .iter()
+        .map(|name| {
+            let mut record = Record::default();
+            record.insert(
+                "name".to_owned(),
+                FieldValue::Character(Some(name.to_string())),
+            );
+            record
+        })
+        .collect::<Vec<Record>>();
+    for record in &records {
+        writer.write_record(record).unwrap();
+    }
+
+    let mut dst = writer.finish().unwrap();
+    // header, 1 field descriptor, terminator, records of 1 + 10 bytes, end of file marker
+    assert_eq!(dst.get_ref().len(), 32 + 32 + 1 + 3 * 11 + 1);
+    assert_eq!(dst.get_ref().last(), Some(&0x1A));
+
+    dst.set_position(0);
+    let mut reader = Reader::new(dst).unwrap();
+    assert_eq!(reader.header().num_records, 3);
+    assert_eq!(reader.read().unwrap(), records);
+}
+
+const NULL_FLAGS_DBF: &str = "./tests/data/null_flags.dbf";
+
+fn null_flags_records() -> Vec<Record> {
+    let values = [
+        (Some("alpha"), Some(1.0), "abc"),
+        (None, Some(2.0), "def"),
+        // the stored value is ignored, the field is flagged as null
+        (Some("gamma"), None, "ghi"),
+        (None, None, "jkl"),
+    ];
+    values
+        .iter()
+        .map(|(name, count, code)| {
+            let mut record = Record::default();
+            record.insert(
+                "NAME".to_owned(),
+                FieldValue::Character(name.map(str::to_owned)),
+            );
+            record.insert("COUNT".to_owned(), FieldValue::Numeric(*count));
+            record.insert(
+                "CODE".to_owned(),
+                FieldValue::Character(Some(code.to_string())),
+            );
+            record
+        })
+        .collect()
+}
+
+#[test]
+fn test_read_null_flags() {
+    let mut reader = Reader::from_path(NULL_FLAGS_DBF).unwrap();
+    let nullable = reader
+        .fields()
+        .iter()
+        .filter(|info| info.is_nullable())
+        .map(|info| info.name())
+        .collect::<Vec<&str>>();
+    assert_eq!(nullable, ["NAME", "COUNT"]);
+
+    let mut expected = null_flags_records();
+    // Without the null flag, the stored value is read
+    expected[2].insert("COUNT".to_owned(), FieldValue::Numeric(Some(33.0)));
+    let records = reader.read().unwrap();
+    assert_eq!(records.len(), 4);
+    assert!(records
+        .iter()
+        .all(|record| record.get("_NullFlags").is_none()));
+    assert_eq!(records[0], expected[0]);
+    assert_eq!(records[1], expected[1]);
+    assert_eq!(records[3], expected[3]);
+    assert_eq!(records[2].get("COUNT"), Some(&FieldValue::Numeric(None)));
+}
+
+#[test]
+fn test_write_null_flags() {
+    let mut reader = Reader::from_path(NULL_FLAGS_DBF).unwrap();
+    let records = reader.read().unwrap();
+    assert_eq!(records, null_flags_records());
+
+    let mut dst = Cursor::new(Vec::<u8>::new());
+    TableWriterBuilder::from_reader(reader)
+        .build_with_dest(&mut dst)
+        .write_records(&records)
+        .unwrap();
+
+    let bytes = dst.get_ref();
+    // header, 4 fields descriptors, terminator & backlink
+    let first_record = 32 + 4 * 32 + 1 + 263;
+    let record_size = 1 + 10 + 5 + 3 + 1;
+    let null_flags = (1..=4)
+        .map(|i| bytes[first_record + i * record_size - 1])
+        .collect::<Vec<u8>>();
+    assert_eq!(null_flags, [0b00, 0b01, 0b10, 0b11]);
+
+    dst.set_position(0);
+    assert_eq!(Reader::new(dst).unwrap().read().unwrap(), records);
+}
+
+struct NullableBinaryValues {
+    count: Option<i32>,
+    updated: Option<DateTime>,
+}
+
+impl WritableRecord for NullableBinaryValues {
+    fn write_using<'a, W: Write>(
+        &self,
+        field_writer: &mut FieldWriter<'a, W>,
+    ) -> Result<(), FieldIOError> {
+        field_writer.write_next_field_value(&self.count)?;
+        field_writer.write_next_field_value(&self.updated)
+    }
+}
+
+#[test]
+fn test_write_nullable_integer_and_date_time() {
+    let nullable = dbase::FieldFlags::default().nullable(true);
+    let fields = vec![
+        dbase::FieldInfo::new("count".try_into().unwrap(), FieldType::Integer, 4)
+            .unwrap()
+            .with_flags(nullable),
+        dbase::FieldInfo::new("updated".try_into().unwrap(), FieldType::DateTime, 8)
+            .unwrap()
+            .with_flags(nullable),
+        dbase::FieldInfo::new("_NullFlags".try_into().unwrap(), FieldType::NullFlags, 1).unwrap(),
+    ];
+    let date_time = DateTime::new(Date::new(14, 3, 2024), Time::new(9, 30, 0));
+    let records = [
+        NullableBinaryValues {
+            count: Some(42),
+            updated: Some(date_time),
+        },
+        NullableBinaryValues {
+            count: None,
+            updated: None,
+        },
+    ];
+    let mut dst = Cursor::new(Vec::<u8>::new());
+    TableWriterBuilder::from_fields(fields)
+        .build_with_dest(&mut dst)
+        .write_records(&records)
+        .unwrap();
+
+    dst.set_position(0);
+    let mut reader = Reader::new(&mut dst).unwrap();
+    let read = reader.read().unwrap();
+    assert_eq!(read[0].get("count"), Some(&FieldValue::Integer(42)));
+    assert_eq!(
+        read[0].get("updated"),
+        Some(&FieldValue::DateTime(date_time))
+    );
+    let table_info = reader.into_table_info();
+
+    // deletion flag, 4 zero bytes, 8 zero bytes & both null flags set
+    let second_record = table_info.header_size() as usize + table_info.record_size() as usize;
+    let bytes = &dst.get_ref()[second_record..second_record + 14];
+    assert_eq!(&bytes[1..13], &[0u8; 12]);
+    assert_eq!(bytes[13], 0b11);
+}
+
+#[test]
+fn test_empty_date_style() {
+    let expected: [(EmptyDateStyle, &[u8]); 2] = [
+        (EmptyDateStyle::Spaces, b"        "),
+        (EmptyDateStyle::Zeros, b"00000000"),
+    ];
+    for (style, empty_date) in expected {
+        let mut record = Record::default();
+        record.insert("returned".to_owned(), FieldValue::Date(None));
+        let mut dst = Cursor::new(Vec::<u8>::new());
+        TableWriterBuilder::new()
+            .add_date_field("returned".try_into().unwrap())
+            .empty_date_style(style)
+            .build_with_dest(&mut dst)
+            .write_records(std::slice::from_ref(&record))
+            .unwrap();
+
+        let first_record = 32 + 32 + 1;
+        // skip the deletion flag
+        let written = &dst.get_ref()[first_record + 1..first_record + 9];
+        assert_eq!(written, empty_date);
+
+        dst.set_position(0);
+        assert_eq!(Reader::new(dst).unwrap().read().unwrap(), vec![record]);
+    }
+}
+
+#[test]
+fn test_reader_progress_callback() {
+    use std::sync::{Arc, Mutex};
+
+    let calls = Arc::new(Mutex::new(Vec::new()));
+    let mut reader = Reader::from_path("tests/data/stations.dbf").unwrap();
+    let progress = Arc::clone(&calls);
+    reader.set_progress_callback(move |processed, total| {
+        progress.lock().unwrap().push((processed, total));
+    });
+    reader.set_progress_interval(4);
+    let records = reader.read().unwrap();
+
+    assert_eq!(records.len(), 6);
+    assert_eq!(*calls.lock().unwrap(), vec![(4, 6), (6, 6)]);
+}
+
+#[test]
+fn test_writer_progress_callback_with_unknown_total() {
+    use std::sync::{Arc, Mutex};
+
+    let calls = Arc::new(Mutex::new(Vec::new()));
+    let mut writer = TableWriterBuilder::new()
+        .add_numeric_field(FieldName::try_from("count").unwrap(), 10, 0)
+        .build_with_dest(Cursor::new(Vec::<u8>::new()));
+    let progress = Arc::clone(&calls);
+    writer.set_progress_callback(move |processed, total| {
+        progress.lock().unwrap().push((processed, total));
+    });
+    writer.set_progress_interval(2);
+
+    let records = (0..5)
+        .map(|i| {
+            let mut record = Record::default();
+            record.insert("count".to_string(), FieldValue::Numeric(Some(f64::from(i))));
+            record
+        })
+        .collect::<Vec<_>>();
+    writer
+        .write_records(records.iter().filter(|_| true))
+        .unwrap();
+
+    assert_eq!(*calls.lock().unwrap(), vec![(2, 0), (4, 0), (5, 0)]);
+}
+
+#[test]
+fn test_read_vfp_file_with_backlink() {
+    let mut reader = Reader::from_path("tests/data/vfp_backlink.dbf").unwrap();
+    let records = reader.read().unwrap();
+
+    assert_eq!(records.len(), 2);
+    assert_eq!(
+        records[0].get("NAME"),
+        Some(&FieldValue::Character(Some("Alice".to_string())))
+    );
+    assert_eq!(records[0].get("AGE"), Some(&FieldValue::Integer(30)));
+    assert_eq!(
+        records[1].get("NAME"),
+        Some(&FieldValue::Character(Some("Bob".to_string())))
+    );
+    assert_eq!(records[1].get("AGE"), Some(&FieldValue::Integer(42)));
+}
+
+#[test]
+fn test_read_file_with_data_after_header_terminator() {
+    const EXTRA_DATA_SIZE: u16 = 40;
+
+    let mut bytes = std::fs::read("tests/data/stations.dbf").unwrap();
+    let header_size = u16::from_le_bytes([bytes[8], bytes[9]]);
+    let terminator_pos = usize::from(header_size) - 1;
+    assert_eq!(bytes[terminator_pos], 0x0D);
+    bytes.splice(
+        terminator_pos + 1..terminator_pos + 1,
+        std::iter::repeat_n(0xAB, usize::from(EXTRA_DATA_SIZE)),
+    );
+    bytes[8..10].copy_from_slice(&(header_size + EXTRA_DATA_SIZE).to_le_bytes());
+
+    let mut reader = Reader::new(Cursor::new(bytes)).unwrap();
+    let records = reader.read().unwrap();
+    let expected = Reader::from_path("tests/data/stations.dbf")
+        .unwrap()
+        .read()
+        .unwrap();
+    assert_eq!(records, expected);
+}
+
+#[test]
+fn test_write_too_long_character_value() {
+    use dbase::{ErrorKind, TruncationPolicy};
+
+    // 'é' is encoded on 2 bytes, the 4 bytes limit falls in the middle of it
+    let mut record = Record::default();
+    record.insert(
+        "name".to_string(),
+        FieldValue::Character(Some("café".to_string())),
+    );
+    let builder =
+        TableWriterBuilder::new().add_character_field(FieldName::try_from("name").unwrap(), 4);
+
+    let error = builder
+        .clone()
+        .build_with_dest(Cursor::new(Vec::<u8>::new()))
+        .write_records([&record])
+        .unwrap_err();
+    match error.kind() {
+        ErrorKind::ValueTooLong { field, len, max } => {
+            assert_eq!(field, "name");
+            assert_eq!(*len, 5);
+            assert_eq!(*max, 4);
+        }
+        kind => panic!("Unexpected error kind: {:?}", kind),
+    }
+
+    let mut dst = Cursor::new(Vec::<u8>::new());
+    builder
+        .truncation_policy(TruncationPolicy::Truncate)
+        .build_with_dest(&mut dst)
+        .write_records([&record])
+        .unwrap();
+    dst.set_position(0);
+
+    let records = Reader::new(dst).unwrap().read().unwrap();
+    assert_eq!(
+        records[0].get("name"),
+        Some(&FieldValue::Character(Some("caf".to_string())))
+    );
+}
+
+#[test]
+fn test_memo_block_size() {
+    let mut reader = Reader::from_path("tests/data/memo.dbf").unwrap();
+    assert_eq!(reader.memo_block_size(), Some(64));
+    let records = reader.read().unwrap();
+    assert_eq!(
+        records[1].get("NOTES"),
+        Some(&FieldValue::Memo("Another note".to_string()))
+    );
+
+    let writer = TableWriterBuilder::from_reader(reader).build_with_dest(Cursor::new(Vec::new()));
+    assert_eq!(writer.memo_block_size(), 64);
+
+    let writer = TableWriterBuilder::new()
+        .memo_block_size(1024)
+        .build_with_dest(Cursor::new(Vec::new()));
+    assert_eq!(writer.memo_block_size(), 1024);
+}
+
+#[test]
+fn test_file_version_and_memo_type() {
+    let reader = Reader::from_path("tests/data/memo.dbf").unwrap();
+    let version = reader.version();
+    assert_eq!(
+        version,
+        dbase::Version::DBase3 {
+            supports_memo: true
+        }
+    );
+    assert_eq!(
+        version.supported_memo_type(),
+        Some(dbase::MemoFileType::DbaseMemo)
+    );
+    assert_eq!(reader.memo_file_type(), version.supported_memo_type());
+
+    let reader = Reader::from_path("tests/data/stations.dbf").unwrap();
+    assert_eq!(reader.version().supported_memo_type(), None);
+    assert_eq!(reader.memo_file_type(), None);
+}
+
+#[test]
+fn test_read_memo_from_separate_source() {
+    let dbf = Cursor::new(std::fs::read("tests/data/memo.dbf").unwrap());
+    let dbt = Cursor::new(std::fs::read("tests/data/memo.dbt").unwrap());
+    let mut reader = Reader::new_with_memo(dbf, dbt, dbase::MemoFileType::DbaseMemo).unwrap();
+    assert_eq!(reader.memo_block_size(), Some(64));
+    let records = reader.read().unwrap();
+    let expected = Reader::from_path("tests/data/memo.dbf")
+        .unwrap()
+        .read()
+        .unwrap();
+    assert_eq!(records, expected);
+
+    // Without the memo file, Memo fields cannot be read
+    let dbf = Cursor::new(std::fs::read("tests/data/memo.dbf").unwrap());
+    assert!(Reader::new(dbf).unwrap().read().is_err());
+}
+
+#[test]
+fn test_memo_reader() {
+    let memo = Cursor::new(std::fs::read("tests/data/memo.dbt").unwrap());
+    let mut memo_reader = dbase::MemoReader::new(dbase::MemoFileType::DbaseMemo, memo).unwrap();
+    assert_eq!(memo_reader.block_size(), 64);
+    assert_eq!(memo_reader.next_block_index(), 3);
+    assert_eq!(memo_reader.read_at(2).unwrap(), b"Another note");
+    assert_eq!(memo_reader.read_at(1).unwrap(), b"A short note");
+}
+
+#[test]
+fn test_read_binary_memos() {
+    // The first memo starts with a byte that is not valid UTF-8
+    let dbf = std::fs::read("tests/data/memo.dbf").unwrap();
+    let mut dbt = std::fs::read("tests/data/memo.dbt").unwrap();
+    dbt[64] = 0xFF;
+    let binary_memo = FieldValue::BinaryMemo(b"\xFF short note".to_vec());
+
+    let mut reader = Reader::new_with_memo(
+        Cursor::new(dbf.clone()),
+        Cursor::new(dbt.clone()),
+        dbase::MemoFileType::DbaseMemo,
+    )
+    .unwrap();
+    let records = reader.read().unwrap();
+    assert_eq!(
+        records[0].get("NOTES"),
+        Some(&FieldValue::Memo("\u{FFFD} short note".to_string()))
+    );
+
+    let mut reader = Reader::new_with_memo(
+        Cursor::new(dbf.clone()),
+        Cursor::new(dbt.clone()),
+        dbase::MemoFileType::DbaseMemo,
+    )
+    .unwrap();
+    reader.set_options(dbase::ReadingOptions::new().binary_memos(true));
+    let records = reader.read().unwrap();
+    assert_eq!(records[0].get("NOTES"), Some(&binary_memo));
+    assert_eq!(
+        records[1].get("NOTES"),
+        Some(&FieldValue::BinaryMemo(b"Another note".to_vec()))
+    );
+
+    // Memo fields flagged as binary are always read as bytes
+    let mut flagged_dbf = dbf;
+    flagged_dbf[64 + 18] = 0x04;
+    let mut reader = Reader::new_with_memo(
+        Cursor::new(flagged_dbf),
+        Cursor::new(dbt),
+        dbase::MemoFileType::DbaseMemo,
+    )
+    .unwrap();
+    assert!(reader.fields()[2].flags().is_binary());
+    let mut records = reader.read().unwrap();
+    assert_eq!(records[0].get("NOTES"), Some(&binary_memo));
+    let bytes: Vec<u8> = records[0].remove("NOTES").unwrap().try_into().unwrap();
+    assert_eq!(bytes, b"\xFF short note");
+}
+
+#[test]
+#[should_panic]
+fn test_memo_block_size_cannot_be_zero() {
+    let _ = TableWriterBuilder::new().memo_block_size(0);
+}
+
+#[test]
+fn test_numeric_overflow_asterisks_are_read_as_none() {
+    use dbase::NumericOverflowPolicy;
+
+    let records = [Some(12.5), Some(-123.25)]
+        .iter()
+        .map(|value| {
+            let mut record = Record::default();
+            record.insert("price".to_string(), FieldValue::Numeric(*value));
+            record
+        })
+        .collect::<Vec<_>>();
+    let builder =
+        TableWriterBuilder::new().add_numeric_field(FieldName::try_from("price").unwrap(), 6, 2);
+
+    let error = builder
+        .clone()
+        .build_with_dest(Cursor::new(Vec::<u8>::new()))
+        .write_records(&records)
+        .unwrap_err();
+    assert_eq!(error.record_num(), 1);
+    assert!(matches!(
+        error.kind(),
+        dbase::ErrorKind::ValueDoesNotFit { .. }
+    ));
+
+    let mut dst = Cursor::new(Vec::<u8>::new());
+    builder
+        .numeric_overflow_policy(NumericOverflowPolicy::Asterisks)
+        .build_with_dest(&mut dst)
+        .write_records(&records)
+        .unwrap();
+    dst.set_position(0);
+    let read_records = Reader::new(dst).unwrap().read().unwrap();
+    assert_eq!(
+        read_records[0].get("price"),
+        Some(&FieldValue::Numeric(Some(12.5)))
+    );
+    assert_eq!(
+        read_records[1].get("price"),
+        Some(&FieldValue::Numeric(None))
+    );
+}
+
+#[test]
+fn test_iter_records_range() {
+    let all_records = Reader::from_path("tests/data/stations.dbf")
+        .unwrap()
+        .read()
+        .unwrap();
+
+    let mut reader = Reader::from_path("tests/data/stations.dbf").unwrap();
+    let records = reader
+        .iter_records_range(1, 3)
+        .unwrap()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(records, all_records[1..3]);
+
+    // Records can be read again, in any order
+    let records = reader
+        .iter_records_range(0, 1)
+        .unwrap()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(records, all_records[..1]);
+
+    // Bounds are clamped
+    let records = reader
+        .iter_records_range(4, 100)
+        .unwrap()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(records, all_records[4..]);
+    assert_eq!(reader.iter_records_range(10, 100).unwrap().count(), 0);
+    assert_eq!(reader.iter_records_range(3, 2).unwrap().count(), 0);
+}
+
+#[test]
+fn test_filter_records() {
+    let all_records = dbase::read("tests/data/stations.dbf").unwrap();
+    let is_red = |record: &Record| {
+        matches!(
+            record.get("marker-col"),
+            Some(FieldValue::Character(Some(color))) if color == "#ff0000"
+        )
+    };
+
+    let mut reader = Reader::from_path("tests/data/stations.dbf").unwrap();
+    let records = reader.filter(is_red);
+    assert_eq!(records.size_hint(), (0, Some(6)));
+    let records = records.collect::<Result<Vec<_>, _>>().unwrap();
+    let expected = all_records
+        .iter()
+        .filter(|record| is_red(record))
+        .cloned()
+        .collect::<Vec<_>>();
+    assert!(!expected.is_empty());
+    assert_eq!(records, expected);
+
+    let mut reader = Reader::from_path("tests/data/stations.dbf").unwrap();
+    let stations = reader
+        .filter_as::<StationByName, _>(|station| station.line == "blue")
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(stations.len(), 4);
+    assert!(stations.iter().all(|station| station.line == "blue"));
+}
+
+#[test]
+fn test_numeric_alignment_golden_record() {
+    use dbase::NumericAlignment;
+
+    let mut record = Record::default();
+    record.insert(
+        "name".to_string(),
+        FieldValue::Character(Some("ab".to_string())),
+    );
+    record.insert("price".to_string(), FieldValue::Numeric(Some(-3.5)));
+    record.insert("ratio".to_string(), FieldValue::Float(Some(0.25)));
+    record.insert("empty".to_string(), FieldValue::Numeric(None));
+    let builder = TableWriterBuilder::new()
+        .add_character_field(FieldName::try_from("name").unwrap(), 4)
+        .add_numeric_field(FieldName::try_from("price").unwrap(), 8, 2)
+        .add_float_field(FieldName::try_from("ratio").unwrap(), 6, 3)
+        .add_numeric_field(FieldName::try_from("empty").unwrap(), 3, 0);
+
+    let expected: [(Option<NumericAlignment>, &[u8]); 3] = [
+        (None, b" ab     -3.50 0.250   "),
+        (Some(NumericAlignment::Right), b" ab     -3.50 0.250   "),
+        (Some(NumericAlignment::Left), b" ab  -3.50   0.250    "),
+    ];
+    for (alignment, expected_record) in expected {
+        let mut builder = builder.clone();
+        if let Some(alignment) = alignment {
+            builder = builder.numeric_alignment(alignment);
+        }
+        let mut writer = builder.build_with_dest(Cursor::new(Vec::<u8>::new()));
+        writer.write_record(&record).unwrap();
+        let bytes = writer.finish().unwrap().into_inner();
+
+        let header_size = usize::from(u16::from_le_bytes([bytes[8], bytes[9]]));
+        let record_size = usize::from(u16::from_le_bytes([bytes[10], bytes[11]]));
+        assert_eq!(record_size, expected_record.len());
+        assert_eq!(
+            &bytes[header_size..header_size + record_size],
+            expected_record
+        );
+
+        let read_records = Reader::new(Cursor::new(bytes)).unwrap().read().unwrap();
+        assert_eq!(read_records, vec![record.clone()]);
+    }
+}
+
+#[test]
+fn test_numeric_rounding() {
+    use dbase::RoundingMode;
+
+    let builder = TableWriterBuilder::new()
+        .add_numeric_field(FieldName::try_from("count").unwrap(), 2, 0)
+        .add_float_field(FieldName::try_from("ratio").unwrap(), 5, 2);
+    let expected: [(Option<RoundingMode>, f64, f32, &[u8]); 5] = [
+        (None, 2.5, 0.125, b"  2 0.12"),
+        (Some(RoundingMode::HalfToEven), 2.5, 0.125, b"  2 0.12"),
+        (
+            Some(RoundingMode::HalfAwayFromZero),
+            2.5,
+            0.125,
+            b"  3 0.13",
+        ),
+        (
+            Some(RoundingMode::HalfAwayFromZero),
+            -2.5,
+            -0.125,
+            b" -3-0.13",
+        ),
+        (
+            Some(RoundingMode::HalfAwayFromZero),
+            9.5,
+            9.995,
+            b" 1010.00",
+        ),
+    ];
+    for (rounding, count, ratio, expected_record) in expected {
+        let mut builder = builder.clone();
+        if let Some(rounding) = rounding {
+            builder = builder.numeric_rounding(rounding);
+        }
+        let mut record = Record::default();
+        record.insert("count".to_string(), FieldValue::Numeric(Some(count)));
+        record.insert("ratio".to_string(), FieldValue::Float(Some(ratio)));
+        let mut writer = builder.build_with_dest(Cursor::new(Vec::<u8>::new()));
+        writer.write_record(&record).unwrap();
+        let bytes = writer.finish().unwrap().into_inner();
+
+        let header_size = usize::from(u16::from_le_bytes([bytes[8], bytes[9]]));
+        assert_eq!(
+            &bytes[header_size..header_size + expected_record.len()],
+            expected_record,
+            "{:?} {}",
+            rounding,
+            count
+        );
+    }
+}
+
+#[test]
+fn test_update_record_at() {
+    let mut reader = Reader::from_path("tests/data/stations.dbf").unwrap();
+    let records = reader.read().unwrap();
+    let mut writer =
+        TableWriterBuilder::from_reader(reader).build_with_dest(Cursor::new(Vec::<u8>::new()));
+    for record in &records[..3] {
+        writer.write_record(record).unwrap();
+    }
+
+    let mut updated = records[1].clone();
+    updated.insert(
+        "name".to_string(),
+        FieldValue::Character(Some("Updated".to_string())),
+    );
+    writer.update_record_at(1, &updated).unwrap();
+    assert!(writer.update_record_at(3, &updated).is_err());
+    // Appending continues after the last record
+    writer.write_record(&records[3]).unwrap();
+
+    let mut cursor = writer.finish().unwrap();
+    cursor.set_position(0);
+    let read_records = Reader::new(cursor).unwrap().read().unwrap();
+    assert_eq!(read_records.len(), 4);
+    assert_eq!(read_records[0], records[0]);
+    assert_eq!(read_records[1], updated);
+    assert_eq!(read_records[2], records[2]);
+    assert_eq!(read_records[3], records[3]);
+}
+
+#[test]
+fn test_streaming_write_to_file() {
+    let path = std::env::temp_dir().join("dbase_streaming_write.dbf");
+    let mut writer = TableWriterBuilder::new()
+        .add_integer_field("id".try_into().unwrap())
+        .add_character_field("label".try_into().unwrap(), 10)
+        .build_with_file_dest(&path)
+        .unwrap();
+
+    // The number of records is not known in advance
+    let mut ids = (0..).take_while(|id| *id < 1500);
+    for id in &mut ids {
+        let mut record = Record::default();
+        record.insert("id".to_owned(), FieldValue::Integer(id));
+        record.insert(
+            "label".to_owned(),
+            FieldValue::Character(Some(format!("row {}", id))),
+        );
+        writer.write_record(&record).unwrap();
+    }
+    assert_eq!(writer.num_records_written(), 1500);
+    writer.finish().unwrap();
+
+    let bytes = std::fs::read(&path).unwrap();
+    assert_eq!(
+        u32::from_le_bytes([bytes[4], bytes[5], bytes[6], bytes[7]]),
+        1500
+    );
+    let records = Reader::from_path(&path).unwrap().read().unwrap();
+    assert_eq!(records.len(), 1500);
+    assert_eq!(records[1499].get("id"), Some(&FieldValue::Integer(1499)));
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_table_schema_changes_roundtrip() {
+    let path = std::env::temp_dir().join("dbase_table_schema_changes.dbf");
+    std::fs::copy("tests/data/stations.dbf", &path).unwrap();
+
+    let mut table = dbase::Table::open(&path).unwrap();
+    assert_eq!(table.records().len(), 6);
+    let removed = table.remove_field("marker-col").unwrap();
+    assert_eq!(removed.name(), "marker-col");
+    table
+        .add_field(
+            dbase::FieldInfo::new("zone".try_into().unwrap(), FieldType::Integer, 4).unwrap(),
+            FieldValue::Integer(1),
+        )
+        .unwrap();
+    table.records_mut()[2].insert("zone".to_owned(), FieldValue::Integer(3));
+    table.save().unwrap();
+    assert!(!path.with_extension("dbf.tmp").exists());
+
+    let table = dbase::Table::open(&path).unwrap();
+    let names = table.fields().map(|info| info.name()).collect::<Vec<_>>();
+    assert_eq!(names, vec!["name", "marker-sym", "line", "zone"]);
+    let records = table.records();
+    assert_eq!(records.len(), 6);
+    assert_eq!(records[0].get("marker-col"), None);
+    assert_eq!(records[0].get("zone"), Some(&FieldValue::Integer(1)));
+    assert_eq!(records[2].get("zone"), Some(&FieldValue::Integer(3)));
+    // cspell:disable-next-line
+    assert_eq!(
+        records[0].get_as::<String>("name").unwrap(),
+        "Van Dorn Street"
+    );
+
+    let copy_path = std::env::temp_dir().join("dbase_table_schema_changes_copy.dbf");
+    table.save_as(&copy_path).unwrap();
+    assert_eq!(dbase::read(&copy_path).unwrap(), table.records());
+    assert_eq!(table.path(), path.as_path());
+
+    std::fs::remove_file(&path).unwrap();
+    std::fs::remove_file(&copy_path).unwrap();
+}
+
+#[test]
+fn test_table_invalid_schema_changes() {
+    let path = std::env::temp_dir().join("dbase_table_invalid_changes.dbf");
+    std::fs::copy("tests/data/stations.dbf", &path).unwrap();
+
+    let mut table = dbase::Table::open(&path).unwrap();
+    let error = table
+        .add_field(
+            dbase::FieldInfo::new("LINE".try_into().unwrap(), FieldType::Character, 10).unwrap(),
+            FieldValue::Character(None),
+        )
+        .unwrap_err();
+    assert!(matches!(error.kind(), dbase::ErrorKind::Message(_)));
+    let error = table
+        .add_field(
+            dbase::FieldInfo::new("zone".try_into().unwrap(), FieldType::Integer, 4).unwrap(),
+            FieldValue::Numeric(Some(1.0)),
+        )
+        .unwrap_err();
+    assert!(matches!(error.kind(), dbase::ErrorKind::IncompatibleType));
+    assert!(table.remove_field("zone").is_err());
+    assert_eq!(table.fields().count(), 4);
+    assert_eq!(table.records()[0].len(), 4);
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_table_with_memo_fields_cannot_be_saved() {
+    let path = std::env::temp_dir().join("dbase_table_with_memo.dbf");
+    let mut table = dbase::Table::open("tests/data/memo.dbf").unwrap();
+    let error = table.save_as(&path).unwrap_err();
+    assert!(matches!(error.kind(), dbase::ErrorKind::Message(_)));
+    assert_eq!(
+        error.field().as_ref().map(|info| info.field_type()),
+        Some(FieldType::Memo)
+    );
+    assert!(!path.exists());
+
+    // Without its Memo fields, the table is saved
+    let memo_fields = table
+        .fields()
+        .filter(|info| info.field_type() == FieldType::Memo)
+        .map(|info| info.name().to_owned())
+        .collect::<Vec<_>>();
+    for name in memo_fields {
+        table.remove_field(&name).unwrap();
+    }
+    table.save_as(&path).unwrap();
+    assert_eq!(
+        Reader::from_path(&path).unwrap().read().unwrap(),
+        table.records()
+    );
+    std::fs::remove_file(&path).unwrap();
+}
+
+/// stations_optional.dbf with `extra` bytes after its end of file marker,
+/// and `num_records` in its header
+fn stations_with_trailing_bytes(num_records: u32, extra: &[u8]) -> Vec<u8> {
+    let mut bytes = std::fs::read("tests/data/stations_optional.dbf").unwrap();
+    bytes[4..8].copy_from_slice(&num_records.to_le_bytes());
+    bytes.extend_from_slice(extra);
+    bytes
+}
+
+#[test]
+fn test_read_ignores_bytes_after_records() {
+    let expected = Reader::from_path("tests/data/stations_optional.dbf")
+        .unwrap()
+        .read()
+        .unwrap();
+    let record_size = Reader::from_path("tests/data/stations_optional.dbf")
+        .unwrap()
+        .header()
+        .size_of_record as usize;
+    // Junk that would be a valid record, not deleted
+    let mut junk = vec![b' '; record_size];
+    junk[1..8].copy_from_slice(b"garbage");
+
+    let bytes = stations_with_trailing_bytes(6, &junk);
+    let records = Reader::new(Cursor::new(bytes)).unwrap().read().unwrap();
+    assert_eq!(records, expected);
+
+    // The header claims one more record than there is before the end of file marker
+    let bytes = stations_with_trailing_bytes(7, &junk);
+    let mut reader = Reader::new(Cursor::new(bytes.clone())).unwrap();
+    assert_eq!(reader.read().unwrap(), expected);
+    let mut reader = Reader::new(Cursor::new(bytes)).unwrap();
+    let mut num_raw_records = 0;
+    let mut raw_records = reader.iter_raw_records();
+    while let Some(record) = raw_records.next_raw() {
+        record.unwrap();
+        num_raw_records += 1;
+    }
+    assert_eq!(num_raw_records, 6);
+}
+
+/// Writes the given names in the fields, one per field
+struct Names(Vec<&'static str>);
+
+impl WritableRecord for Names {
+    fn write_using<'a, W: Write>(
+        &self,
+        field_writer: &mut FieldWriter<'a, W>,
+    ) -> Result<(), FieldIOError> {
+        for name in &self.0 {
+            field_writer.write_next_field_value(name)?;
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn test_mismatched_records_are_not_written() {
+    let mut writer = TableWriterBuilder::new()
+        .add_character_field(FieldName::try_from("first").unwrap(), 10)
+        .add_character_field(FieldName::try_from("last").unwrap(), 10)
+        .build_with_dest(Cursor::new(Vec::<u8>::new()));
+
+    writer.write_record(&Names(vec!["Yoshi", "Green"])).unwrap();
+    let error = writer.write_record(&Names(vec!["Luigi"])).unwrap_err();
+    assert!(matches!(error.kind(), dbase::ErrorKind::NotEnoughFields));
+    assert_eq!(error.record_num(), 1);
+    assert_eq!(
+        error.field().as_ref().map(|field| field.name()),
+        Some("last")
+    );
+    let error = writer
+        .write_record(&Names(vec!["Peach", "Toadstool", "Princess"]))
+        .unwrap_err();
+    assert!(matches!(error.kind(), dbase::ErrorKind::TooManyFields));
+    writer.write_record(&Names(vec!["Mario", "Red"])).unwrap();
+
+    let mut dst = writer.finish().unwrap();
+    dst.set_position(0);
+    let records = Reader::new(dst).unwrap().read().unwrap();
+    assert_eq!(records.len(), 2);
+    assert_eq!(
+        records[1].get("first"),
+        Some(&FieldValue::Character(Some("Mario".to_string())))
+    );
+}
+
+#[test]
+fn test_group_records_by_field_value() {
+    let records = dbase::read("tests/data/stations.dbf").unwrap();
+    let mut by_line: std::collections::HashMap<FieldValue, Vec<Record>> =
+        std::collections::HashMap::new();
+    for record in &records {
+        by_line
+            .entry(record.get("line").unwrap().clone())
+            .or_default()
+            .push(record.clone());
+    }
+
+    assert_eq!(by_line.values().map(Vec::len).sum::<usize>(), records.len());
+    for (line, group) in &by_line {
+        let expected = records
+            .iter()
+            .filter(|record| record.get("line") == Some(line))
+            .count();
+        assert_eq!(group.len(), expected);
+    }
+    let blue = FieldValue::Character(Some("blue".to_string()));
+    assert!(by_line[&blue].len() > 1);
+}
+
+#[test]
+fn test_duplicate_field_names_are_rejected() {
+    let builder = TableWriterBuilder::new()
+        .add_character_field(FieldName::try_from("name").unwrap(), 10)
+        .add_numeric_field(FieldName::try_from("NAME").unwrap(), 10, 0);
+
+    let path = "duplicate_field_names.dbf";
+    let error = match builder.clone().build_with_file_dest(path) {
+        Ok(_) => panic!("The writer should not be built"),
+        Err(error) => error,
+    };
+    assert!(matches!(error.kind(), dbase::ErrorKind::DuplicateFieldName(name) if name == "NAME"));
+    assert!(!std::path::Path::new(path).exists());
+
+    let mut writer = builder.build_with_dest(Cursor::new(Vec::<u8>::new()));
+    let error = writer.write_record(&Names(vec!["Yoshi"])).unwrap_err();
+    assert!(matches!(
+        error.kind(),
+        dbase::ErrorKind::DuplicateFieldName(_)
+    ));
+    assert!(writer.finish().is_err());
+}
+
+#[test]
+fn test_invalid_builder_fields_are_rejected() {
+    let builders = [
+        TableWriterBuilder::new().add_character_field(FieldName::try_from("name").unwrap(), 0),
+        TableWriterBuilder::new().add_numeric_field(FieldName::try_from("price").unwrap(), 3, 10),
+    ];
+    for builder in builders {
+        let path = "invalid_builder_fields.dbf";
+        let error = match builder.clone().build_with_file_dest(path) {
+            Ok(_) => panic!("The writer should not be built"),
+            Err(error) => error,
+        };
+        assert!(matches!(
+            error.kind(),
+            dbase::ErrorKind::InvalidFieldInfo(_)
+        ));
+        assert!(!std::path::Path::new(path).exists());
+
+        let writer = builder.build_with_dest(Cursor::new(Vec::<u8>::new()));
+        let error = match writer.finish() {
+            Ok(_) => panic!("The header should not be written"),
+            Err(error) => error,
+        };
+        assert!(matches!(
+            error.kind(),
+            dbase::ErrorKind::InvalidFieldInfo(_)
+        ));
+    }
+}
+
+#[test]
+fn test_writer_from_computed_fields() {
+    let fields = ["zone", "area", "code"].iter().map(|name| {
+        dbase::FieldInfo::new_with_precision(
+            FieldName::try_from(*name).unwrap(),
+            FieldType::Numeric,
+            8,
+            2,
+        )
+        .unwrap()
+    });
+    let mut dst = Cursor::new(Vec::<u8>::new());
+    let mut writer = TableWriterBuilder::from_fields(fields).build_with_dest(&mut dst);
+    let record = Record::from_iter([
+        ("zone".to_string(), FieldValue::Numeric(Some(1.0))),
+        ("area".to_string(), FieldValue::Numeric(Some(12.5))),
+        ("code".to_string(), FieldValue::Numeric(None)),
+    ]);
+    writer.write_record(&record).unwrap();
+    writer.finish().unwrap();
+
+    dst.set_position(0);
+    let mut reader = Reader::new(&mut dst).unwrap();
+    // Skips the deletion flag
+    let field_names = reader
+        .fields()
+        .iter()
+        .skip(1)
+        .map(|info| info.name().to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(field_names, vec!["zone", "area", "code"]);
+    assert_eq!(reader.read().unwrap(), vec![record]);
+
+    // Fields taken from a reader keep their order, the deletion flag is ignored
+    let builder = TableWriterBuilder::from_fields(reader.fields().iter().cloned());
+    let mut writer = builder.build_with_dest(Cursor::new(Vec::<u8>::new()));
+    writer
+        .write_record(&Record::from_iter([
+            ("zone".to_string(), FieldValue::Numeric(Some(2.0))),
+            ("area".to_string(), FieldValue::Numeric(None)),
+            ("code".to_string(), FieldValue::Numeric(None)),
+        ]))
+        .unwrap();
+}
+
+#[test]
+fn test_write_records_checks_names_and_types() {
+    let builder = TableWriterBuilder::new()
+        .add_character_field(FieldName::try_from("name").unwrap(), 10)
+        .add_numeric_field(FieldName::try_from("zone").unwrap(), 4, 0);
+    let mut record = Record::default();
+    record.insert(
+        "name".to_string(),
+        FieldValue::Character(Some("Pentagon".to_string())),
+    );
+
+    // Missing key
+    let mut writer = builder
+        .clone()
+        .build_with_dest(Cursor::new(Vec::<u8>::new()));
+    let error = writer.write_record(&record).unwrap_err();
+    assert!(matches!(error.kind(), dbase::ErrorKind::Message(message) if message.contains("zone")));
+    assert_eq!(
+        error.field().as_ref().map(|field| field.name()),
+        Some("zone")
+    );
+
+    // Wrong type
+    record.insert("zone".to_string(), FieldValue::Integer(1));
+    let error = writer.write_record(&record).unwrap_err();
+    assert!(matches!(error.kind(), dbase::ErrorKind::IncompatibleType));
+    assert_eq!(
+        error.field().as_ref().map(|field| field.name()),
+        Some("zone")
+    );
+
+    // Extra key
+    record.insert("zone".to_string(), FieldValue::Numeric(Some(1.0)));
+    record.insert("Zone".to_string(), FieldValue::Numeric(Some(2.0)));
+    writer.write_record(&record).unwrap();
+    let mut dst = writer.finish().unwrap();
+    dst.set_position(0);
+    let records = Reader::new(dst).unwrap().read().unwrap();
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].len(), 2);
+    assert_eq!(
+        records[0].get("zone"),
+        Some(&FieldValue::Numeric(Some(1.0)))
+    );
+    assert_eq!(records[0].get("Zone"), None);
+}
+
+#[test]
+fn test_failed_values_leave_no_partial_record() {
+    let mut writer = TableWriterBuilder::new()
+        .add_character_field(FieldName::try_from("first").unwrap(), 10)
+        .add_character_field(FieldName::try_from("last").unwrap(), 5)
+        .build_with_dest(Cursor::new(Vec::<u8>::new()));
+
+    // The first record fails after the header was reserved
+    let error = writer
+        .write_record(&Names(vec!["Bowser", "Koopa King"]))
+        .unwrap_err();
+    assert!(matches!(
+        error.kind(),
+        dbase::ErrorKind::ValueTooLong { .. }
+    ));
+    assert_eq!(error.record_num(), 0);
+    writer.write_record(&Names(vec!["Yoshi", "Green"])).unwrap();
+    let error = writer
+        .write_record(&Names(vec!["Wario", "Yellow"]))
+        .unwrap_err();
+    assert!(matches!(
+        error.kind(),
+        dbase::ErrorKind::ValueTooLong { .. }
+    ));
+    assert_eq!(error.record_num(), 1);
+    writer.write_record(&Names(vec!["Mario", "Red"])).unwrap();
+
+    let bytes = writer.finish().unwrap().into_inner();
+    let mut reader = Reader::new(Cursor::new(bytes.clone())).unwrap();
+    let records = reader.read().unwrap();
+    assert!(reader.warnings().is_empty());
+    let names: Vec<_> = records
+        .iter()
+        .map(|record| record.get_as::<String>("first").unwrap())
+        .collect();
+    assert_eq!(names, vec!["Yoshi", "Mario"]);
+
+    let table_info = reader.into_table_info();
+    let expected_size =
+        table_info.header_size() as usize + 2 * table_info.record_size() as usize + 1;
+    assert_eq!(bytes.len(), expected_size);
+}
+
+#[test]
+fn test_table_info_sizes() {
+    for path in [
+        "tests/data/stations_optional.dbf",
+        "tests/data/vfp_backlink.dbf",
+        "tests/data/null_flags.dbf",
+    ] {
+        let reader = Reader::from_path(path).unwrap();
+        let num_records = reader.header().num_records as usize;
+        let table_info = reader.into_table_info();
+        let record_size: usize = table_info
+            .fields()
+            .iter()
+            .map(|field| field.length() as usize)
+            .sum();
+        assert_eq!(table_info.record_size() as usize, record_size, "{}", path);
+
+        // The records are followed by the end of file marker
+        let bytes = std::fs::read(path).unwrap();
+        let end_of_records = table_info.header_size() as usize + num_records * record_size;
+        assert_eq!(bytes.len(), end_of_records + 1, "{}", path);
+        assert_eq!(bytes[end_of_records], 0x1A, "{}", path);
+    }
+}
+
+#[test]
+fn test_record_iterator_len() {
+    let mut reader = Reader::from_path("tests/data/stations_optional.dbf").unwrap();
+    let mut records = reader.iter_records();
+    assert_eq!(records.len(), 6);
+    let mut lengths = vec![];
+    while records.next().transpose().unwrap().is_some() {
+        lengths.push(records.len());
+    }
+    assert_eq!(lengths, vec![5, 4, 3, 2, 1, 0]);
+
+    // The header claims one record more than there is
+    let mut reader = Reader::new(Cursor::new(stations_with_trailing_bytes(7, &[]))).unwrap();
+    reader.set_lenient(true);
+    let mut records = reader.iter_records();
+    assert_eq!(records.len(), 7);
+    assert_eq!(records.by_ref().take(6).count(), 6);
+    assert_eq!(records.len(), 1);
+    assert!(records.next().is_none());
+    assert_eq!(records.len(), 0);
+}
+
+#[test]
+fn test_record_count_mismatch() {
+    let header_size = 161;
+    let record_size = 1017;
+
+    // The header claims one record less than there is
+    let bytes = stations_with_trailing_bytes(5, &[]);
+    let error = Reader::new(Cursor::new(bytes.clone()))
+        .unwrap()
+        .read()
+        .unwrap_err();
+    assert!(matches!(
+        error.kind(),
+        dbase::ErrorKind::RecordCountMismatch {
+            declared: 5,
+            actual: 6
+        }
+    ));
+    let mut reader = Reader::new(Cursor::new(bytes)).unwrap();
+    reader.set_lenient(true);
+    assert_eq!(reader.read().unwrap().len(), 5);
+    let kinds: Vec<_> = reader.warnings().iter().map(ReadWarning::kind).collect();
+    assert_eq!(
+        kinds,
+        vec![ReadWarningKind::RecordCountMismatch {
+            declared: 5,
+            actual: 6
+        }]
+    );
+
+    // The header claims one record more than there is
+    let bytes = stations_with_trailing_bytes(7, &[]);
+    let file_size = bytes.len() as u64;
+    let mut reader = Reader::new(Cursor::new(bytes)).unwrap();
+    reader.set_lenient(true);
+    assert_eq!(reader.read().unwrap().len(), 6);
+    let kinds: Vec<_> = reader.warnings().iter().map(ReadWarning::kind).collect();
+    assert_eq!(
+        kinds,
+        vec![
+            ReadWarningKind::RecordCountMismatch {
+                declared: 7,
+                actual: 6
+            },
+            ReadWarningKind::TruncatedFile {
+                expected_size: header_size + 7 * record_size,
+                actual_size: file_size,
+            }
+        ]
+    );
+
+    // The file ends in the middle of a record
+    let mut bytes = stations_with_trailing_bytes(6, &[]);
+    bytes.truncate((header_size + 3 * record_size + 10) as usize);
+    let mut reader = Reader::new(Cursor::new(bytes.clone())).unwrap();
+    assert_eq!(reader.read().unwrap().len(), 3);
+    assert!(reader.warnings().is_empty());
+    let mut reader = Reader::new(Cursor::new(bytes.clone())).unwrap();
+    reader.set_lenient(true);
+    assert_eq!(reader.read().unwrap().len(), 3);
+    let kinds: Vec<_> = reader.warnings().iter().map(ReadWarning::kind).collect();
+    assert_eq!(
+        kinds,
+        vec![
+            ReadWarningKind::RecordCountMismatch {
+                declared: 6,
+                actual: 3
+            },
+            ReadWarningKind::TruncatedFile {
+                expected_size: header_size + 6 * record_size,
+                actual_size: bytes.len() as u64,
+            }
+        ]
+    );
+
+    // Record sized trailing bytes without an end of file marker
+    let mut bytes = stations_with_trailing_bytes(6, &[]);
+    bytes.pop();
+    bytes.extend(vec![b' '; record_size as usize]);
+    let mut reader = Reader::new(Cursor::new(bytes.clone())).unwrap();
+    let mut records = reader.iter_records();
+    assert_eq!(records.by_ref().take(6).filter(Result::is_ok).count(), 6);
+    assert_eq!(records.len(), 0);
+    let error = records.next().unwrap().unwrap_err();
+    assert!(matches!(
+        error.kind(),
+        dbase::ErrorKind::RecordCountMismatch {
+            declared: 6,
+            actual: 7
+        }
+    ));
+    assert!(records.next().is_none());
+    let mut reader = Reader::new(Cursor::new(bytes)).unwrap();
+    reader.set_lenient(true);
+    let mut records = reader.iter_records();
+    assert_eq!(records.by_ref().count(), 6);
+    assert!(records.next().is_none());
+    let kinds: Vec<_> = reader.warnings().iter().map(ReadWarning::kind).collect();
+    assert_eq!(
+        kinds,
+        vec![ReadWarningKind::RecordCountMismatch {
+            declared: 6,
+            actual: 7
+        }]
+    );
+
+    // Files that match their header have no warnings
+    let mut reader = Reader::from_path("tests/data/stations_optional.dbf").unwrap();
+    reader.set_lenient(true);
+    reader.read().unwrap();
+    assert!(reader.warnings().is_empty());
+}
+
+#[test]
+fn test_update_field_at_in_existing_file() {
+    let path = std::env::temp_dir().join("dbase_update_field_at.dbf");
+    std::fs::copy(NULL_FLAGS_DBF, &path).unwrap();
+    let file_size = std::fs::metadata(&path).unwrap().len();
+
+    let reader = Reader::from_path(&path).unwrap();
+    let mut writer = TableWriterBuilder::from_reader(reader)
+        .build_with_file_dest_readwrite(&path)
+        .unwrap();
+    writer
+        .update_field_at(1, "NAME", FieldValue::Character(Some("beta".to_string())))
+        .unwrap();
+    writer
+        .update_field_at(0, "COUNT", FieldValue::Numeric(None))
+        .unwrap();
+    assert!(writer
+        .update_field_at(0, "MISSING", FieldValue::Numeric(None))
+        .is_err());
+    assert!(writer
+        .update_field_at(4, "COUNT", FieldValue::Numeric(None))
+        .is_err());
+    writer.finish().unwrap();
+    assert_eq!(std::fs::metadata(&path).unwrap().len(), file_size);
+
+    let mut expected = null_flags_records();
+    expected[0].insert("COUNT".to_owned(), FieldValue::Numeric(None));
+    expected[1].insert(
+        "NAME".to_owned(),
+        FieldValue::Character(Some("beta".to_string())),
+    );
+    let records = Reader::from_path(&path).unwrap().read().unwrap();
+    assert_eq!(records[0], expected[0]);
+    assert_eq!(records[1], expected[1]);
+    assert_eq!(records[3], expected[3]);
+    assert_eq!(records[2].get("COUNT"), Some(&FieldValue::Numeric(None)));
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_readwrite_dest_requires_same_fields() {
+    let path = std::env::temp_dir().join("dbase_readwrite_other_fields.dbf");
+    std::fs::copy(NULL_FLAGS_DBF, &path).unwrap();
+    let result = TableWriterBuilder::new()
+        .add_character_field(FieldName::try_from("NAME").unwrap(), 10)
+        .build_with_file_dest_readwrite(&path);
+    assert!(result.is_err());
+    assert_eq!(
+        std::fs::read(&path).unwrap(),
+        std::fs::read(NULL_FLAGS_DBF).unwrap()
+    );
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_finish_returns_destination_with_updated_header() {
+    let mut reader = Reader::from_path("tests/data/stations.dbf").unwrap();
+    let records = reader.read().unwrap();
+    let mut writer =
+        TableWriterBuilder::from_reader(reader).build_with_dest(Cursor::new(Vec::<u8>::new()));
+    assert_eq!(writer.num_records_written(), 0);
+    for record in &records {
+        writer.write_record(record).unwrap();
+    }
+    assert_eq!(writer.num_records_written(), records.len());
+
+    let bytes = writer.finish().unwrap().into_inner();
+    assert_eq!(bytes.last(), Some(&0x1A));
+    let mut reader = Reader::new(Cursor::new(bytes)).unwrap();
+    let today = time::OffsetDateTime::now_utc().date();
+    assert_eq!(reader.header().num_records as usize, records.len());
+    assert_eq!(reader.header().last_update, Date::from(today));
+    assert_eq!(reader.read().unwrap(), records);
+}
+
+#[test]
+fn test_dropped_writer_is_finalized() {
+    let mut cursor = Cursor::new(Vec::<u8>::new());
+    let mut record = Record::default();
+    record.insert(
+        "name".to_string(),
+        FieldValue::Character(Some("Yoshi".to_string())),
+    );
+    {
+        let mut writer = TableWriterBuilder::new()
+            .add_character_field(FieldName::try_from("name").unwrap(), 10)
+            .build_with_dest(&mut cursor);
+        writer.write_record(&record).unwrap();
+        writer.write_record(&record).unwrap();
+    }
+
+    assert_eq!(cursor.get_ref().last(), Some(&0x1A));
+    cursor.set_position(0);
+    let mut reader = Reader::new(cursor).unwrap();
+    assert_eq!(reader.header().num_records, 2);
+    assert_eq!(reader.read().unwrap(), vec![record.clone(), record]);
+}
+
+#[test]
+fn test_read_with_deletion_flag() {
+    let mut bytes = std::fs::read("tests/data/stations_optional.dbf").unwrap();
+    let header_size = usize::from(u16::from_le_bytes([bytes[8], bytes[9]]));
+    let record_size = usize::from(u16::from_le_bytes([bytes[10], bytes[11]]));
+    bytes[header_size] = b'*';
+    bytes[header_size + 2 * record_size] = b'*';
+
+    let expected = Reader::from_path("tests/data/stations_optional.dbf")
+        .unwrap()
+        .read()
+        .unwrap();
+    let mut reader = Reader::new(Cursor::new(bytes)).unwrap();
+    let records = reader.read_as_with_meta::<Record>().unwrap();
+    assert_eq!(records.len(), expected.len());
+    for (i, ((is_deleted, record), expected)) in records.into_iter().zip(expected).enumerate() {
+        assert_eq!(is_deleted, i == 0 || i == 2);
+        assert_eq!(record, expected);
+    }
+}
+
+#[test]
+fn test_write_records_from_lazy_iterator() {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    /// Counts the records alive at the same time
+    struct Generated {
+        id: u32,
+        alive: Rc<Cell<usize>>,
+    }
+
+    impl WritableRecord for Generated {
+        fn write_using<'a, W: Write>(
+            &self,
+            field_writer: &mut FieldWriter<'a, W>,
+        ) -> Result<(), FieldIOError> {
+            field_writer.write_next_field_value(&(self.id as i32))
+        }
+    }
+
+    impl Drop for Generated {
+        fn drop(&mut self) {
+            self.alive.set(self.alive.get() - 1);
+        }
+    }
+
+    const NUM_RECORDS: u32 = 100_000;
+    let alive = Rc::new(Cell::new(0));
+    let max_alive = Rc::new(Cell::new(0));
+    let records = {
+        let alive = Rc::clone(&alive);
+        let max_alive = Rc::clone(&max_alive);
+        (0..NUM_RECORDS).map(move |id| {
+            alive.set(alive.get() + 1);
+            max_alive.set(max_alive.get().max(alive.get()));
+            Generated {
+                id,
+                alive: Rc::clone(&alive),
+            }
+        })
+    };
+
+    let mut cursor = Cursor::new(Vec::<u8>::new());
+    TableWriterBuilder::new()
+        .add_integer_field(FieldName::try_from("id").unwrap())
+        .build_with_dest(&mut cursor)
+        .write_records_from_iter(records)
+        .unwrap();
+    assert_eq!(max_alive.get(), 1);
+    assert_eq!(alive.get(), 0);
+
+    let bytes = cursor.into_inner();
+    let header_size = usize::from(u16::from_le_bytes([bytes[8], bytes[9]]));
+    assert_eq!(bytes.len(), header_size + NUM_RECORDS as usize * 5 + 1);
+    let mut reader = Reader::new(Cursor::new(bytes)).unwrap();
+    assert_eq!(reader.header().num_records, NUM_RECORDS);
+    let last = reader.iter_records().last().unwrap().unwrap();
+    assert_eq!(
+        last.get("id"),
+        Some(&FieldValue::Integer(NUM_RECORDS as i32 - 1))
+    );
+}
+
+const CP1252_DBF: &str = "./tests/data/cp1252.dbf";
+
+#[test]
+fn test_read_encoding_from_cpg_file() {
+    let mut reader = Reader::from_path(CP1252_DBF).unwrap();
+    assert_eq!(reader.encoding(), encoding_rs::WINDOWS_1252);
+    let records = reader.read().unwrap();
+    let names = records
+        .iter()
+        .map(|record| (record.get("NAME").cloned(), record.get("CITY").cloned()))
+        .collect::<Vec<_>>();
+    let character = |s: &str| Some(FieldValue::Character(Some(s.to_string())));
+    assert_eq!(
+        names,
+        vec![
+            (character("Café"), character("Zürich")),
+            (character("Müller"), character("Besançon")),
+        ]
+    );
+
+    // An explicit label takes precedence over the .cpg file
+    let reader = Reader::from_path_with_label(CP1252_DBF, "iso-8859-2").unwrap();
+    assert_eq!(reader.encoding(), encoding_rs::ISO_8859_2);
+}
+
+#[test]
+fn test_read_unknown_encoding_in_cpg_file() {
+    let dir = std::env::temp_dir().join("dbase_unknown_cpg");
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("unknown.dbf");
+    std::fs::copy(CP1252_DBF, &path).unwrap();
+    std::fs::write(dir.join("unknown.cpg"), "not-an-encoding\n").unwrap();
+
+    // Outside of lenient mode, the unknown label is an error
+    let error = Reader::from_path(&path).unwrap_err();
+    assert!(matches!(error.kind(), dbase::ErrorKind::InvalidEncoding));
+    let options = dbase::ReadingOptions::new();
+    let error = Reader::from_path_with_options(&path, options).unwrap_err();
+    assert!(matches!(error.kind(), dbase::ErrorKind::InvalidEncoding));
+
+    // In lenient mode, the file has no code page mark, it is decoded as UTF-8
+    let options = dbase::ReadingOptions::new().lenient(true);
+    let mut reader = Reader::from_path_with_options(&path, options).unwrap();
+    assert_eq!(reader.encoding(), encoding_rs::UTF_8);
+    assert_eq!(reader.warnings().len(), 1);
+    assert!(reader.warnings()[0].field().is_none());
+    assert_eq!(reader.warnings()[0].raw_bytes(), b"not-an-encoding\n");
+    assert_eq!(reader.read().unwrap().len(), 2);
+
+    // The code page mark of the header is used instead of the label
+    let mut bytes = std::fs::read(CP1252_DBF).unwrap();
+    bytes[29] = 0x57;
+    std::fs::write(&path, bytes).unwrap();
+    let reader = Reader::from_path_with_options(&path, options).unwrap();
+    assert_eq!(reader.encoding(), encoding_rs::WINDOWS_1252);
+    assert_eq!(reader.warnings().len(), 1);
+    assert!(Reader::from_path(&path).is_err());
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_read_esri_code_page_labels_in_cpg_file() {
+    let dir = std::env::temp_dir().join("dbase_esri_cpg");
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("esri.dbf");
+    std::fs::copy(CP1252_DBF, &path).unwrap();
+
+    for (label, encoding) in [
+        ("1252", encoding_rs::WINDOWS_1252),
+        ("ANSI 1252", encoding_rs::WINDOWS_1252),
+        ("88591", encoding_rs::WINDOWS_1252),
+        ("88592", encoding_rs::ISO_8859_2),
+        ("OEM 866", encoding_rs::IBM866),
+        ("932", encoding_rs::SHIFT_JIS),
+        ("UTF-8", encoding_rs::UTF_8),
+    ] {
+        std::fs::write(dir.join("esri.cpg"), label).unwrap();
+        let reader = Reader::from_path(&path).unwrap();
+        assert_eq!(reader.encoding(), encoding, "{}", label);
+        assert!(reader.warnings().is_empty(), "{}", label);
+    }
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_get_field_case_insensitive() {
+    let records = Reader::from_path("tests/data/stations.dbf")
+        .unwrap()
+        .read()
+        .unwrap();
+    let name = records[0].get("name");
+    assert!(name.is_some());
+    assert_eq!(records[0].get("Name"), None);
+    assert_eq!(records[0].get_case_insensitive("Name"), name);
+    assert_eq!(
+        records[0].get_case_insensitive("MARKER-COL"),
+        records[0].get("marker-col")
+    );
+    assert_eq!(records[0].get_case_insensitive("missing"), None);
+
+    let mut record = Record::default();
+    record.insert("NAME".to_string(), FieldValue::Numeric(Some(1.0)));
+    record.insert("name".to_string(), FieldValue::Numeric(Some(2.0)));
+    assert_eq!(
+        record.get_case_insensitive("name"),
+        Some(&FieldValue::Numeric(Some(2.0)))
+    );
+    assert_eq!(
+        record.get_case_insensitive("Name"),
+        Some(&FieldValue::Numeric(Some(1.0)))
+    );
+}
+
+#[test]
+fn test_currency_is_stored_as_scaled_integer() {
+    let mut record = Record::default();
+    record.insert(
+        "price".to_string(),
+        FieldValue::Currency(dbase::CurrencyValue::from_f64(-12.3456)),
+    );
+    let mut writer = TableWriterBuilder::new()
+        .add_currency_field(FieldName::try_from("price").unwrap())
+        .build_with_dest(Cursor::new(Vec::<u8>::new()));
+    writer.write_record(&record).unwrap();
+    let mut bytes = writer.finish().unwrap().into_inner();
+
+    let header_size = usize::from(u16::from_le_bytes([bytes[8], bytes[9]]));
+    assert_eq!(
+        bytes[header_size + 1..header_size + 9],
+        (-123_456i64).to_le_bytes()
+    );
+    assert_eq!(
+        Reader::new(Cursor::new(bytes.clone()))
+            .unwrap()
+            .read()
+            .unwrap(),
+        vec![record]
+    );
+
+    // 1 cent, as written by FoxPro
+    bytes[header_size + 1..header_size + 9].copy_from_slice(&100i64.to_le_bytes());
+    let records = Reader::new(Cursor::new(bytes)).unwrap().read().unwrap();
+    assert_eq!(
+        records[0].get("price"),
+        Some(&FieldValue::Currency(dbase::CurrencyValue::new(100)))
+    );
+}
+
+#[test]
+fn test_currency_values_are_exact() {
+    // Too large to be represented exactly by a f64
+    let amount = dbase::CurrencyValue::new(i64::MAX - 1);
+    let mut writer = TableWriterBuilder::new()
+        .add_currency_field(FieldName::try_from("amount").unwrap())
+        .build_with_dest(Cursor::new(Vec::<u8>::new()));
+    let mut record = Record::default();
+    record.insert("amount".to_string(), FieldValue::Currency(amount));
+    writer.write_record(&record).unwrap();
+    let bytes = writer.finish().unwrap().into_inner();
+
+    let records = Reader::new(Cursor::new(bytes)).unwrap().read().unwrap();
+    assert_eq!(
+        records[0].get("amount"),
+        Some(&FieldValue::Currency(amount))
+    );
+    assert_eq!(amount.to_string(), "922337203685477.5806");
+    assert_eq!(
+        dbase::CurrencyValue::try_from(records[0].get("amount").unwrap().clone()).unwrap(),
+        amount
+    );
+    assert_eq!(dbase::CurrencyValue::from_f64(-0.00005).raw(), -1);
+    assert_eq!(dbase::CurrencyValue::new(-5).to_string(), "-0.0005");
+}
+
+#[test]
+fn test_write_with_schema_of_table_info() {
+    let mut reader = Reader::from_path(CP1252_DBF).unwrap();
+    let records = reader.read().unwrap();
+    let table_info = reader.into_table_info();
+    assert_eq!(table_info.encoding(), encoding_rs::WINDOWS_1252);
+
+    let mut new_record = Record::default();
+    new_record.insert(
+        "NAME".to_string(),
+        FieldValue::Character(Some("Gaëlle".to_string())),
+    );
+    new_record.insert(
+        "CITY".to_string(),
+        FieldValue::Character(Some("Orléans".to_string())),
+    );
+    let mut from_fields = TableWriterBuilder::from_fields(table_info.fields().to_vec());
+    from_fields.set_encoding(table_info.encoding());
+    for builder in [
+        TableWriterBuilder::from_table_info(&table_info),
+        from_fields,
+    ] {
+        let mut writer = builder.build_with_dest(Cursor::new(Vec::<u8>::new()));
+        writer.write_record(&records[0]).unwrap();
+        writer.write_record(&new_record).unwrap();
+        let bytes = writer.finish().unwrap().into_inner();
+        assert!(bytes
+            .windows(7)
+            .any(|window| window == b"Orl\xe9ans".as_slice()));
+
+        let mut reader = Reader::new_with_label(Cursor::new(bytes), "windows-1252").unwrap();
+        assert_eq!(
+            reader.read().unwrap(),
+            vec![records[0].clone(), new_record.clone()]
+        );
+    }
+
+    let table_info = Reader::from_path("tests/data/memo.dbf")
+        .unwrap()
+        .into_table_info();
+    let writer =
+        TableWriterBuilder::from_table_info(table_info).build_with_dest(Cursor::new(Vec::new()));
+    assert_eq!(writer.memo_block_size(), 64);
+}
+
+#[test]
+fn test_copy_as_is() {
+    let dest = std::env::temp_dir().join("dbase_copy_as_is.dbf");
+    let num_records = dbase::copy("tests/data/memo.dbf", &dest, dbase::CopyOptions::new()).unwrap();
+    assert_eq!(num_records, 2);
+    assert_eq!(
+        std::fs::read(&dest).unwrap(),
+        std::fs::read("tests/data/memo.dbf").unwrap()
+    );
+    assert_eq!(
+        std::fs::read(dest.with_extension("dbt")).unwrap(),
+        std::fs::read("tests/data/memo.dbt").unwrap()
+    );
+    std::fs::remove_file(dest.with_extension("dbt")).unwrap();
+    std::fs::remove_file(dest).unwrap();
+}
+
+#[test]
+fn test_copy_memo_fields_with_options() {
+    let dest = std::env::temp_dir().join("dbase_copy_memo_fields.dbf");
+    let options = dbase::CopyOptions::new().predicate(|_| true);
+    let error = dbase::copy("tests/data/memo.dbf", &dest, options).unwrap_err();
+    assert!(matches!(error.kind(), dbase::ErrorKind::Message(_)));
+    assert_eq!(
+        error.field().as_ref().map(|info| info.name()),
+        Some("NOTES")
+    );
+    assert!(!dest.exists());
+
+    // Without the Memo field, the table is copied
+    let options = dbase::CopyOptions::new()
+        .field_order(vec!["TITLE".to_string()])
+        .predicate(|_| true);
+    let num_records = dbase::copy("tests/data/memo.dbf", &dest, options).unwrap();
+    assert_eq!(num_records, 2);
+    let records = Reader::from_path(&dest).unwrap().read().unwrap();
+    assert_eq!(records[0].field_names().collect::<Vec<_>>(), ["TITLE"]);
+    std::fs::remove_file(&dest).unwrap();
+}
+
+#[test]
+fn test_copy_with_options() {
+    use std::collections::HashMap;
+
+    let dest = std::env::temp_dir().join("dbase_copy_with_options.dbf");
+    let options = dbase::CopyOptions::new()
+        .field_order(vec!["CITY".to_string(), "NAME".to_string()])
+        .rename_fields(HashMap::from([("CITY".to_string(), "TOWN".to_string())]))
+        .encoding("utf-8")
+        .predicate(|record| {
+            record.get("NAME") != Some(&FieldValue::Character(Some("Café".to_string())))
+        });
+    let num_records = dbase::copy(CP1252_DBF, &dest, options).unwrap();
+    assert_eq!(num_records, 1);
+
+    let mut reader = Reader::from_path(&dest).unwrap();
+    assert_eq!(reader.encoding(), encoding_rs::UTF_8);
+    let names = reader
+        .fields()
+        .iter()
+        .map(|info| info.name())
+        .collect::<Vec<&str>>();
+    assert_eq!(names, ["DeletionFlag", "TOWN", "NAME"]);
+    let records = reader.read().unwrap();
+    assert_eq!(records.len(), 1);
+    assert_eq!(
+        records[0].get_at(0),
+        Some(("TOWN", &FieldValue::Character(Some("Besançon".to_string()))))
+    );
+    assert_eq!(
+        records[0].get("NAME"),
+        Some(&FieldValue::Character(Some("Müller".to_string())))
+    );
+    std::fs::remove_file(&dest).unwrap();
+
+    let options = dbase::CopyOptions::new().field_order(vec!["MISSING".to_string()]);
+    assert!(dbase::copy(CP1252_DBF, &dest, options).is_err());
+}
+
+#[test]
+fn test_version_depends_on_field_types() {
+    let write = |builder: TableWriterBuilder, record: &Record| {
+        let mut writer = builder.build_with_dest(Cursor::new(Vec::<u8>::new()));
+        writer.write_record(record).unwrap();
+        let bytes = writer.finish().unwrap().into_inner();
+        let records = Reader::new(Cursor::new(bytes.clone()))
+            .unwrap()
+            .read()
+            .unwrap();
+        assert_eq!(&records, std::slice::from_ref(record));
+        bytes[0]
+    };
+
+    let mut record = Record::default();
+    record.insert(
+        "name".to_string(),
+        FieldValue::Character(Some("Paris".to_string())),
+    );
+    let builder =
+        TableWriterBuilder::new().add_character_field(FieldName::try_from("name").unwrap(), 10);
+    assert_eq!(write(builder.clone(), &record), 0x03);
+
+    record.insert("count".to_string(), FieldValue::Integer(17));
+    record.insert(
+        "price".to_string(),
+        FieldValue::Currency(dbase::CurrencyValue::from_f64(1.5)),
+    );
+    let builder = builder
+        .add_integer_field(FieldName::try_from("count").unwrap())
+        .add_currency_field(FieldName::try_from("price").unwrap());
+    assert_eq!(write(builder.clone(), &record), 0x30);
+
+    let builder = builder.with_version(dbase::Version::FoxPro2 {
+        supports_memo: false,
+    });
+    assert_eq!(write(builder, &record), 0xfb);
+}
+
+fn stats_test_file() -> Vec<u8> {
+    let values = [
+        (
+            Some("Alice"),
+            Some(10.5),
+            Some(true),
+            Some(Date::new(1, 2, 2000)),
+        ),
+        (Some("Bob"), Some(-2.0), Some(false), None),
+        (None, None, None, Some(Date::new(3, 4, 1990))),
+        (
+            Some("Alice"),
+            Some(20.0),
+            Some(true),
+            Some(Date::new(5, 6, 2010)),
+        ),
+        // Marked as deleted below
+        (
+            Some("Zoe"),
+            Some(1000.0),
+            Some(false),
+            Some(Date::new(1, 1, 2020)),
+        ),
+    ];
+    let mut writer = TableWriterBuilder::new()
+        .add_character_field(FieldName::try_from("name").unwrap(), 10)
+        .add_numeric_field(FieldName::try_from("amount").unwrap(), 10, 2)
+        .add_logical_field(FieldName::try_from("active").unwrap())
+        .add_date_field(FieldName::try_from("born").unwrap())
+        .build_with_dest(Cursor::new(Vec::<u8>::new()));
+    for (name, amount, active, born) in values {
+        let mut record = Record::default();
+        record.insert(
+            "name".to_string(),
+            FieldValue::Character(name.map(str::to_string)),
+        );
+        record.insert("amount".to_string(), FieldValue::Numeric(amount));
+        record.insert("active".to_string(), FieldValue::Logical(active));
+        record.insert("born".to_string(), FieldValue::Date(born));
+        writer.write_record(&record).unwrap();
+    }
+    let mut bytes = writer.finish().unwrap().into_inner();
+    let header_size = usize::from(u16::from_le_bytes([bytes[8], bytes[9]]));
+    let record_size = usize::from(u16::from_le_bytes([bytes[10], bytes[11]]));
+    bytes[header_size + 4 * record_size] = b'*';
+    bytes
+}
+
+#[test]
+fn test_column_stats() {
+    let stats = |field_name| {
+        dbase::column_stats(
+            Reader::new(Cursor::new(stats_test_file())).unwrap(),
+            field_name,
+        )
+        .unwrap()
+    };
+
+    let amount = stats("amount");
+    assert_eq!((amount.count, amount.null_count), (3, 1));
+    assert_eq!(amount.min, Some(FieldValue::Numeric(Some(-2.0))));
+    assert_eq!(amount.max, Some(FieldValue::Numeric(Some(20.0))));
+    assert_eq!(amount.sum, 28.5);
+    assert_eq!(amount.mean, 9.5);
+
+    let name = stats("name");
+    assert_eq!((name.count, name.null_count), (3, 1));
+    assert_eq!((name.min_length, name.max_length), (3, 5));
+    assert_eq!(name.distinct_count, 2);
+    assert_eq!(
+        name.max,
+        Some(FieldValue::Character(Some("Bob".to_string())))
+    );
+    assert!(name.mean.is_nan());
+
+    let active = stats("active");
+    assert_eq!((active.true_count, active.false_count), (2, 1));
+    assert_eq!(active.null_count, 1);
+
+    let born = stats("born");
+    assert_eq!((born.count, born.null_count), (3, 1));
+    assert_eq!(
+        born.min,
+        Some(FieldValue::Date(Some(Date::new(3, 4, 1990))))
+    );
+    assert_eq!(
+        born.max,
+        Some(FieldValue::Date(Some(Date::new(5, 6, 2010))))
+    );
+
+    assert!(dbase::column_stats(
+        Reader::new(Cursor::new(stats_test_file())).unwrap(),
+        "missing"
+    )
+    .is_err());
+}
+
+#[test]
+fn test_column_histogram() {
+    let histogram = |field_name, bucket_count| {
+        dbase::column_histogram(
+            Reader::new(Cursor::new(stats_test_file())).unwrap(),
+            field_name,
+            bucket_count,
+        )
+    };
+    // Values -2, 10.5 & 20, buckets of width 5.5
+    assert_eq!(histogram("amount", 4).unwrap(), vec![1, 0, 1, 1]);
+    assert_eq!(histogram("amount", 1).unwrap(), vec![3]);
+    assert!(histogram("amount", 0).is_err());
+    assert!(histogram("name", 2).is_err());
+}
+
+#[test]
+fn test_iter_raw_records() {
+    let mut bytes = std::fs::read("tests/data/stations_optional.dbf").unwrap();
+    let header_size = usize::from(u16::from_le_bytes([bytes[8], bytes[9]]));
+    bytes[header_size] = b'*';
+
+    let expected = Reader::from_path("tests/data/stations_optional.dbf")
+        .unwrap()
+        .read()
+        .unwrap();
+    let mut reader = Reader::new(Cursor::new(bytes)).unwrap();
+    let mut records = reader.iter_raw_records();
+    let mut num_records = 0;
+    while let Some(record) = records.next_raw() {
+        let record = record.unwrap();
+        let expected = &expected[num_records];
+        assert_eq!(record.is_deleted(), num_records == 0);
+        assert_eq!(record.len(), expected.len());
+        for (index, (_, value)) in expected.iter().enumerate() {
+            let text = String::from_utf8_lossy(record.field_bytes(index).unwrap());
+            match value {
+                FieldValue::Character(Some(expected_text)) => {
+                    assert_eq!(text.trim(), expected_text)
+                }
+                FieldValue::Character(None) => assert_eq!(text.trim(), ""),
+                _ => {}
+            }
+        }
+        assert_eq!(record.field_bytes(record.len()), None);
+        num_records += 1;
+    }
+    assert_eq!(num_records, expected.len());
+
+    let mut reader = Reader::from_path(NULL_FLAGS_DBF).unwrap();
+    let num_fields = reader.read().unwrap()[0].len();
+    let mut reader = Reader::from_path(NULL_FLAGS_DBF).unwrap();
+    // The _NullFlags column is not one of the fields
+    assert_eq!(
+        reader.iter_raw_records().next_raw().unwrap().unwrap().len(),
+        num_fields
+    );
+}
+
+#[test]
+fn test_iter_raw_records_after_iter_records() {
+    let raw_name = |record: dbase::RawRecordView| {
+        String::from_utf8_lossy(record.field_bytes(0).unwrap())
+            .trim()
+            .to_string()
+    };
+    let expected = Reader::from_path("tests/data/stations.dbf")
+        .unwrap()
+        .read()
+        .unwrap();
+    let expected_name = |index: usize| match expected[index].get("name") {
+        Some(FieldValue::Character(Some(name))) => name.clone(),
+        value => panic!("unexpected name: {:?}", value),
+    };
+
+    // The raw records follow the ones already read
+    let mut reader = Reader::from_path("tests/data/stations.dbf").unwrap();
+    let first_two = reader
+        .iter_records()
+        .take(2)
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(first_two, expected[..2]);
+    let mut raw_records = reader.iter_raw_records();
+    let mut names = Vec::new();
+    while let Some(record) = raw_records.next_raw() {
+        names.push(raw_name(record.unwrap()));
+    }
+    assert_eq!(
+        names,
+        (2..expected.len()).map(expected_name).collect::<Vec<_>>()
+    );
+
+    // No records remain once they were all read, until the reader is rewound
+    let mut reader = Reader::from_path("tests/data/stations.dbf").unwrap();
+    reader.read().unwrap();
+    assert!(reader.iter_raw_records().next_raw().is_none());
+    reader.rewind().unwrap();
+    let mut raw_records = reader.iter_raw_records();
+    let first = raw_name(raw_records.next_raw().unwrap().unwrap());
+    assert_eq!(first, expected_name(0));
+    // The decoded records follow the raw ones
+    assert_eq!(reader.iter_records().next().unwrap().unwrap(), expected[1]);
+}
+
+#[test]
+fn test_sort_records() {
+    let name = |record: &Record| match record.get("NAME") {
+        Some(FieldValue::Character(Some(name))) => name.clone(),
+        value => panic!("unexpected name {:?}", value),
+    };
+    let mut reader = Reader::from_path("tests/data/stations_optional.dbf").unwrap();
+    let mut records = reader.read().unwrap();
+    let mut expected_names = records.iter().map(name).collect::<Vec<_>>();
+    expected_names.sort();
+
+    dbase::sort_records_by(&mut records, "NAME").unwrap();
+    let mut dst = Cursor::new(Vec::<u8>::new());
+    TableWriterBuilder::from_reader(reader)
+        .build_with_dest(&mut dst)
+        .write_records(&records)
+        .unwrap();
+    dst.set_position(0);
+    let sorted_records = Reader::new(dst).unwrap().read().unwrap();
+    assert_eq!(sorted_records, records);
+    assert_eq!(
+        sorted_records.iter().map(name).collect::<Vec<_>>(),
+        expected_names
+    );
+
+    dbase::sort_records_by_descending(&mut records, "NAME").unwrap();
+    expected_names.reverse();
+    assert_eq!(records.iter().map(name).collect::<Vec<_>>(), expected_names);
+
+    dbase::sort_records_by_multiple(
+        &mut records,
+        &[
+            ("LINE", dbase::SortOrder::Ascending),
+            ("NAME", dbase::SortOrder::Descending),
+        ],
+    )
+    .unwrap();
+    for pair in records.windows(2) {
+        let (line_a, line_b) = (pair[0].get("LINE"), pair[1].get("LINE"));
+        assert!(line_a < line_b || (line_a == line_b && name(&pair[0]) > name(&pair[1])));
+    }
+
+    assert_eq!(
+        dbase::sort_records_by(&mut records, "MISSING"),
+        Err(dbase::SortError::FieldNotFound("MISSING".to_string()))
+    );
+    records[0].insert("NAME".to_string(), FieldValue::Integer(1));
+    assert_eq!(
+        dbase::sort_records_by(&mut records, "NAME"),
+        Err(dbase::SortError::IncomparableValues("NAME".to_string()))
+    );
+}
+
+dbase_record! {
+    #[derive(Clone, Debug, PartialEq)]
+    struct RenamedStation {
+        line: Option<String> => "LINE",
+        station_name: String => "NAME",
+        symbol: String => "MARKER_SYM",
+    }
+}
+
+#[test]
+fn test_dbase_record_with_renamed_fields() {
+    let mut reader = Reader::from_path("tests/data/stations_optional.dbf").unwrap();
+    let stations = reader.read_as::<RenamedStation>().unwrap();
+    assert_eq!(
+        stations[3],
+        RenamedStation {
+            line: Some("blue".to_string()),
+            station_name: "Judiciary Sq".to_string(),
+            symbol: "rail-metro".to_string(),
+        }
+    );
+
+    let writer_builder = TableWriterBuilder::new()
+        .add_character_field("LINE".try_into().unwrap(), 10)
+        .add_character_field("NAME".try_into().unwrap(), 50)
+        .add_character_field("MARKER_SYM".try_into().unwrap(), 20);
+    write_read_compare(&stations, writer_builder);
+
+    // The fields are written in order, to columns with their name
+    let mut writer = TableWriterBuilder::new()
+        .add_character_field("NAME".try_into().unwrap(), 50)
+        .add_character_field("LINE".try_into().unwrap(), 10)
+        .add_character_field("MARKER_SYM".try_into().unwrap(), 20)
+        .build_with_dest(Cursor::new(Vec::<u8>::new()));
+    let error = writer.write_record(&stations[0]).unwrap_err();
+    assert!(matches!(error.kind(), dbase::ErrorKind::Message(_)));
+}
+
+mod public_records {
+    dbase_record! {
+        /// A station of the metro
+        #[derive(Clone, Debug, PartialEq)]
+        pub struct Station {
+            /// Name of the station
+            pub name: String,
+            pub marker_col: String,
+            pub(crate) marker_sym: String,
+            /// Color of the line
+            pub line: String,
+        }
+    }
+}
+
+#[test]
+fn test_dbase_record_with_visibility_and_attributes() {
+    let mut reader = Reader::from_path("tests/data/stations.dbf").unwrap();
+    let stations = reader.read_as::<public_records::Station>().unwrap();
+    // cspell:disable-next-line
+    assert_eq!(stations[0].name, "Van Dorn Street");
+    assert_eq!(stations[0].marker_sym, "rail-metro");
+    assert_eq!(stations[0].line, "blue");
+}
+
+#[test]
+fn test_read_in_chunks() {
+    let mut writer = TableWriterBuilder::new()
+        .add_integer_field("id".try_into().unwrap())
+        .build_with_dest(Cursor::new(Vec::<u8>::new()));
+    let records = (0..20)
+        .map(|i| Record::from_iter([("id".to_string(), FieldValue::Integer(i))]))
+        .collect::<Vec<_>>();
+    for record in &records {
+        writer.write_record(record).unwrap();
+    }
+    let mut dst = writer.finish().unwrap();
+    dst.set_position(0);
+
+    let mut reader = Reader::new(dst).unwrap();
+    let chunks = reader.iter_chunks(7);
+    assert_eq!(chunks.size_hint(), (3, Some(3)));
+    let chunks = chunks.collect::<Result<Vec<_>, _>>().unwrap();
+    let sizes = chunks.iter().map(Vec::len).collect::<Vec<_>>();
+    assert_eq!(sizes, vec![7, 7, 6]);
+    assert_eq!(chunks.concat(), records);
+    assert!(reader.read_chunk(7).unwrap().is_empty());
+
+    reader.seek(18).unwrap();
+    assert_eq!(reader.read_chunk(7).unwrap(), records[18..]);
+}
+
+#[test]
+fn test_field_values_from_rust_values() {
+    let date = Date::new(31, 1, 2024);
+    let date_time = DateTime::new(date, Time::new(8, 5, 0));
+    let name = FieldValue::Character(Some("name".to_string()));
+    assert_eq!(FieldValue::from("name"), name);
+    assert_eq!(FieldValue::from("name".to_string()), name);
+    assert_eq!(FieldValue::from(Some("name")), name);
+    assert_eq!(FieldValue::from(None::<&str>), FieldValue::Character(None));
+    assert_eq!(
+        FieldValue::from(None::<String>),
+        FieldValue::Character(None)
+    );
+    assert_eq!(FieldValue::from(1.5f64), FieldValue::Numeric(Some(1.5)));
+    assert_eq!(FieldValue::from(None::<f64>), FieldValue::Numeric(None));
+    assert_eq!(FieldValue::from(1.5f32), FieldValue::Float(Some(1.5)));
+    assert_eq!(FieldValue::from(None::<f32>), FieldValue::Float(None));
+    assert_eq!(FieldValue::from(7), FieldValue::Integer(7));
+    assert_eq!(FieldValue::from(true), FieldValue::Logical(Some(true)));
+    assert_eq!(FieldValue::from(None::<bool>), FieldValue::Logical(None));
+    assert_eq!(FieldValue::from(date), FieldValue::Date(Some(date)));
+    assert_eq!(FieldValue::from(None::<Date>), FieldValue::Date(None));
+    assert_eq!(FieldValue::from(date_time), FieldValue::DateTime(date_time));
+
+    let mut record = Record::new();
+    record.insert("name".into(), "Paris".into());
+    record.insert("area".into(), 105.25.into());
+    record.insert("ratio".into(), 0.5f32.into());
+    record.insert("count".into(), 12.into());
+    record.insert("capital".into(), true.into());
+    record.insert("founded".into(), date.into());
+    record.insert("updated".into(), date_time.into());
+
+    let mut writer = TableWriterBuilder::new()
+        .add_character_field("name".try_into().unwrap(), 10)
+        .add_numeric_field("area".try_into().unwrap(), 10, 2)
+        .add_float_field("ratio".try_into().unwrap(), 10, 2)
+        .add_integer_field("count".try_into().unwrap())
+        .add_logical_field("capital".try_into().unwrap())
+        .add_date_field("founded".try_into().unwrap())
+        .add_datetime_field("updated".try_into().unwrap())
+        .build_with_dest(Cursor::new(Vec::<u8>::new()));
+    writer.write_record(&record).unwrap();
+    let mut dst = writer.finish().unwrap();
+    dst.set_position(0);
+
+    let records = Reader::new(dst).unwrap().read().unwrap();
+    assert_eq!(records, vec![record]);
+}
+
+#[test]
+fn test_write_records_infers_the_fields() {
+    let path = "inferred_fields.dbf";
+    let date = Date::new(31, 1, 2024);
+    let record = |name: Option<&str>, number: f64| {
+        Record::from_iter([
+            ("name".to_string(), FieldValue::from(name)),
+            ("numeric".to_string(), FieldValue::Numeric(Some(number))),
+            ("float".to_string(), FieldValue::Float(None)),
+            ("logical".to_string(), FieldValue::Logical(Some(true))),
+            ("date".to_string(), FieldValue::Date(Some(date))),
+            ("integer".to_string(), FieldValue::Integer(-7)),
+            ("auto".to_string(), FieldValue::AutoIncrement(3)),
+            (
+                "currency".to_string(),
+                FieldValue::Currency(dbase::CurrencyValue::new(12_3400)),
+            ),
+            (
+                "date_time".to_string(),
+                FieldValue::DateTime(DateTime::new(date, Time::new(8, 5, 0))),
+            ),
+            ("double".to_string(), FieldValue::Double(0.25)),
+        ])
+    };
+    let records = vec![record(None, 1.5), record(Some("Lyon"), -2.0)];
+    dbase::write_records(path, &records).unwrap();
+
+    let mut reader = Reader::from_path(path).unwrap();
+    let read = reader.read().unwrap();
+    std::fs::remove_file(path).unwrap();
+    assert_eq!(read, records);
+    let fields = reader
+        .fields()
+        .iter()
+        .skip(1)
+        .map(|info| {
+            (
+                info.name().to_string(),
+                info.field_type(),
+                info.length(),
+                info.num_decimal_places(),
+            )
+        })
+        .collect::<Vec<_>>();
+    let expected = [
+        ("name", FieldType::Character, 4, 0),
+        ("numeric", FieldType::Numeric, 4, 1),
+        ("float", FieldType::Float, 1, 0),
+        ("logical", FieldType::Logical, 1, 0),
+        ("date", FieldType::Date, 8, 0),
+        ("integer", FieldType::Integer, 4, 0),
+        ("auto", FieldType::AutoIncrement, 4, 0),
+        ("currency", FieldType::Currency, 8, 0),
+        ("date_time", FieldType::DateTime, 8, 0),
+        ("double", FieldType::Double, 8, 0),
+    ]
+    .map(|(name, field_type, length, decimals)| (name.to_string(), field_type, length, decimals));
+    assert_eq!(fields, expected);
+}
+
+#[test]
+fn test_write_records_rejects_inconsistent_records() {
+    let path = "inconsistent_records.dbf";
+    let error = dbase::write_records(path, &[]).unwrap_err();
+    assert!(matches!(error.kind(), dbase::ErrorKind::Message(_)));
+
+    let first = Record::from_iter([("name".to_string(), "Paris".into())]);
+    let records = [
+        first.clone(),
+        Record::from_iter([("name".to_string(), 1.5.into())]),
+    ];
+    let error = dbase::write_records(path, &records).unwrap_err();
+    assert!(matches!(error.kind(), dbase::ErrorKind::IncompatibleType));
+    assert_eq!(error.record_num(), 1);
+    assert_eq!(error.field().as_ref().map(|info| info.name()), Some("name"));
+
+    let records = [
+        first.clone(),
+        Record::from_iter([("town".to_string(), "Lyon".into())]),
+    ];
+    let error = dbase::write_records(path, &records).unwrap_err();
+    assert_eq!(error.record_num(), 1);
+    assert_eq!(error.field().as_ref().map(|info| info.name()), Some("name"));
+
+    let mut longer = first.clone();
+    longer.insert("area".to_string(), 105.4.into());
+    let error = dbase::write_records(path, &[first, longer]).unwrap_err();
+    assert_eq!(error.record_num(), 1);
+
+    let memo = Record::from_iter([("notes".to_string(), FieldValue::Memo(String::new()))]);
+    assert!(dbase::write_records(path, &[memo]).is_err());
+    assert!(!std::path::Path::new(path).exists());
+
+    let records = [
+        Record::from_iter([("name".to_string(), "Paris".into())]),
+        Record::from_iter([("name".to_string(), "a".repeat(300).into())]),
+    ];
+    let error = dbase::write_records(path, &records).unwrap_err();
+    assert!(matches!(
+        error.kind(),
+        dbase::ErrorKind::ValueTooLong {
+            len: 300,
+            max: 254,
+            ..
+        }
+    ));
+    assert_eq!(error.record_num(), 1);
+    assert!(!std::path::Path::new(path).exists());
+
+    let records = [Record::from_iter([("area".to_string(), 1e300.into())])];
+    let error = dbase::write_records(path, &records).unwrap_err();
+    assert!(matches!(
+        error.kind(),
+        dbase::ErrorKind::ValueTooLong { max: 254, .. }
+    ));
+    assert!(!std::path::Path::new(path).exists());
+}
+
+#[test]
+fn test_write_records_infers_the_length_of_numbers() {
+    let path = "inferred_numbers.dbf";
+    let records = [1e13, -0.001, 123.5]
+        .map(|area| Record::from_iter([("area".to_string(), area.into())]))
+        .to_vec();
+    dbase::write_records(path, &records).unwrap();
+
+    let mut reader = Reader::from_path(path).unwrap();
+    let read = reader.read().unwrap();
+    std::fs::remove_file(path).unwrap();
+    assert_eq!(read, records);
+    let area = reader.fields().last().unwrap();
+    assert_eq!(area.length(), 18);
+    assert_eq!(area.num_decimal_places(), 3);
+}
+
+#[test]
+fn test_encoding_detection() {
+    let dir = std::env::temp_dir().join("dbase_encoding_detection");
+    std::fs::create_dir_all(&dir).unwrap();
+    let expected = Reader::from_path(CP1252_DBF).unwrap().read().unwrap();
+
+    // The .cpg file gives the encoding
+    let path = dir.join("with_cpg.dbf");
+    std::fs::copy(CP1252_DBF, &path).unwrap();
+    std::fs::write(dir.join("with_cpg.cpg"), "ISO-8859-2").unwrap();
+    let (_, encoding) = Reader::from_path_with_encoding_detection(&path).unwrap();
+    assert_eq!(encoding, encoding_rs::ISO_8859_2);
+
+    // The code page mark takes precedence over the .cpg file
+    let mut bytes = std::fs::read(CP1252_DBF).unwrap();
+    bytes[29] = 0x57;
+    std::fs::write(&path, &bytes).unwrap();
+    let (_, encoding) = Reader::from_path_with_encoding_detection(&path).unwrap();
+    assert_eq!(encoding, encoding_rs::WINDOWS_1252);
+
+    // Without either, the values are not valid UTF-8
+    let path = dir.join("without_cpg.dbf");
+    std::fs::copy(CP1252_DBF, &path).unwrap();
+    let (mut reader, encoding) = Reader::from_path_with_encoding_detection(&path).unwrap();
+    assert_eq!(encoding, encoding_rs::WINDOWS_1252);
+    assert_eq!(reader.read().unwrap(), expected);
+
+    let (mut reader, encoding) =
+        Reader::from_path_with_encoding_detection("tests/data/stations.dbf").unwrap();
+    assert_eq!(encoding, encoding_rs::UTF_8);
+    assert_eq!(reader.read().unwrap().len(), 6);
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+dbase_record! {
+    #[derive(Clone, Debug, PartialEq)]
+    struct SwappedFloats {
+        n_f32: f32,
+        f_f64: f64,
+        n_opt_f32: Option<f32>,
+        f_opt_f64: Option<f64>
+    }
+}
+
+#[test]
+fn test_read_numeric_and_float_fields_as_f32_and_f64() {
+    let numeric = |v| FieldValue::Numeric(Some(v));
+    let float = |v| FieldValue::Float(Some(v));
+    assert_eq!(f32::try_from(numeric(1.25)).unwrap(), 1.25f32);
+    assert_eq!(f32::try_from(float(1.25)).unwrap(), 1.25f32);
+    assert_eq!(f64::try_from(numeric(1.25)).unwrap(), 1.25);
+    assert_eq!(f64::try_from(float(1.25)).unwrap(), 1.25);
+    assert_eq!(Option::<f32>::try_from(numeric(0.1)).unwrap(), Some(0.1f32));
+    assert_eq!(
+        Option::<f32>::try_from(FieldValue::Numeric(None)).unwrap(),
+        None
+    );
+    assert_eq!(Option::<f64>::try_from(float(0.5)).unwrap(), Some(0.5));
+    assert_eq!(
+        Option::<f64>::try_from(FieldValue::Float(None)).unwrap(),
+        None
+    );
+    assert!(matches!(
+        f32::try_from(FieldValue::Numeric(None)),
+        Err(dbase::FieldConversionError::NoneValue)
+    ));
+    assert!(f32::try_from(FieldValue::Character(None)).is_err());
+    assert!(Option::<f64>::try_from(FieldValue::Double(1.0)).is_err());
+
+    let mut writer = TableWriterBuilder::new()
+        .add_numeric_field("n_f32".try_into().unwrap(), 12, 4)
+        .add_float_field("f_f64".try_into().unwrap(), 12, 4)
+        .add_numeric_field("n_opt_f32".try_into().unwrap(), 12, 4)
+        .add_float_field("f_opt_f64".try_into().unwrap(), 12, 4)
+        .build_with_dest(Cursor::new(Vec::<u8>::new()));
+    let record = Record::from_iter([
+        ("n_f32".to_string(), numeric(-12.5)),
+        ("f_f64".to_string(), float(3.25)),
+        ("n_opt_f32".to_string(), FieldValue::Numeric(None)),
+        ("f_opt_f64".to_string(), float(0.75)),
+    ]);
+    writer.write_record(&record).unwrap();
+    let mut dst = writer.finish().unwrap();
+    dst.set_position(0);
+
+    let records = Reader::new(dst)
+        .unwrap()
+        .read_as::<SwappedFloats>()
+        .unwrap();
+    assert_eq!(
+        records,
+        vec![SwappedFloats {
+            n_f32: -12.5,
+            f_f64: 3.25,
+            n_opt_f32: None,
+            f_opt_f64: Some(0.75),
+        }]
+    );
+}
+
+#[test]
+fn test_read_with_info_round_trip() {
+    let path = "read_with_info_stations.dbf";
+    let (records, table_info) = dbase::read_with_info("tests/data/stations.dbf").unwrap();
+    assert_eq!(table_info.num_records(), 6);
+    assert_eq!(table_info.encoding(), encoding_rs::UTF_8);
+    TableWriterBuilder::from_table_info(table_info.clone())
+        .build_with_file_dest(path)
+        .unwrap()
+        .write_records(&records)
+        .unwrap();
+
+    let (read, read_info) = dbase::read_with_info(path).unwrap();
+    std::fs::remove_file(path).unwrap();
+    assert_eq!(read, records);
+    assert_eq!(read_info.fields(), table_info.fields());
+    assert_eq!(read_info.num_records(), table_info.num_records());
+
+    let (records, table_info) =
+        dbase::read_with_label_and_info("tests/data/shift_jis.dbf", "shift_jis").unwrap();
+    assert_eq!(records.len(), 4);
+    assert_eq!(table_info.encoding(), encoding_rs::SHIFT_JIS);
+}
+
+#[test]
+fn test_read_field_of_length_zero() {
+    let mut bytes = std::fs::read("tests/data/stations_optional.dbf").unwrap();
+    // Length of the first field descriptor
+    bytes[32 + 16] = 0;
+    let error = match Reader::new(Cursor::new(bytes)) {
+        Ok(_) => panic!("A field of length 0 should not be read"),
+        Err(error) => error,
+    };
+    assert!(matches!(
+        error.kind(),
+        dbase::ErrorKind::InvalidFieldInfo(dbase::FieldInfoError::ZeroLength)
+    ));
+}
+
+#[test]
+fn test_rewind_to_iterate_again() {
+    let mut reader = Reader::from_path("tests/data/stations.dbf").unwrap();
+    let first = reader
+        .iter_records()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(first.len(), 6);
+    assert!(reader.iter_records().next().is_none());
+
+    reader.rewind().unwrap();
+    let second = reader
+        .iter_records()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(second, first);
+
+    // Also after a partial iteration
+    reader.rewind().unwrap();
+    reader.iter_records().take(2).for_each(drop);
+    reader.rewind().unwrap();
+    assert_eq!(reader.read().unwrap(), first);
+}
+
+#[test]
+fn test_malformed_headers_return_errors() {
+    let error = match Reader::new(Cursor::new(b"DBF".to_vec())) {
+        Ok(_) => panic!("A truncated header should not be read"),
+        Err(error) => error,
+    };
+    assert!(matches!(error.kind(), dbase::ErrorKind::InvalidHeader));
+
+    // Every prefix of a valid file, then random short slices:
+    // reading them must return errors, not panic
+    let stations = std::fs::read("tests/data/stations.dbf").unwrap();
+    let mut inputs = (0..stations.len().min(400))
+        .map(|len| stations[..len].to_vec())
+        .collect::<Vec<_>>();
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    let mut next_random = move || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    };
+    for _ in 0..500 {
+        let len = (next_random() % 300) as usize;
+        let mut bytes = stations[..len.min(stations.len())].to_vec();
+        for _ in 0..(next_random() % 8) {
+            if !bytes.is_empty() {
+                let index = (next_random() as usize) % bytes.len();
+                bytes[index] = next_random() as u8;
+            }
+        }
+        inputs.push(bytes);
+        inputs.push((0..len).map(|_| next_random() as u8).collect());
+    }
+
+    for bytes in inputs {
+        if let Ok(mut reader) = Reader::new(Cursor::new(bytes)) {
+            let _ = reader.read();
+        }
+    }
+}
+
+#[test]
+fn test_skip_records() {
+    let all_records = dbase::read("tests/data/stations.dbf").unwrap();
+
+    let mut reader = Reader::from_path("tests/data/stations.dbf").unwrap();
+    reader.skip_records(2).unwrap();
+    reader.skip_records(1).unwrap();
+    let records = reader.read().unwrap();
+    assert_eq!(records, &all_records[3..]);
+    assert!(reader.warnings().is_empty());
+
+    reader.rewind().unwrap();
+    let error = reader.skip_records(7).unwrap_err();
+    assert!(matches!(error.kind(), dbase::ErrorKind::Message(_)));
+    reader.skip_records(6).unwrap();
+    assert!(reader.iter_records().next().is_none());
+
+    reader.rewind().unwrap();
+    let records = reader
+        .iter_records()
+        .skip(4)
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(records, &all_records[4..]);
+
+    reader.rewind().unwrap();
+    let mut records = reader.iter_records();
+    assert_eq!(records.nth(1).unwrap().unwrap(), all_records[1]);
+    assert_eq!(records.len(), 4);
+    assert!(records.nth(10).is_none());
+}